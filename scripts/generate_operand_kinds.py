@@ -0,0 +1,148 @@
+# Generate `spirq/src/operand.rs` from the SPIR-V grammar in
+# `assets/spirv/spirv.core.grammar.json`. Run from the repository root after
+# `Update-SpirvHeader.ps1`, then `cargo fmt`.
+import json
+
+GRAMMAR_PATH = "assets/spirv/spirv.core.grammar.json"
+OUT_PATH = "spirq/src/operand.rs"
+
+# Operand kinds that are treated as a whole by the operand walker. Enums with
+# parameters are enumerated below.
+SIMPLE_KINDS = {
+    "IdResultType": "IdResultType",
+    "IdResult": "IdResult",
+    "IdRef": "Id",
+    "IdScope": "Id",
+    "IdMemorySemantics": "Id",
+    "LiteralString": "String",
+    "LiteralContextDependentNumber": "ContextDependentNumber",
+    "LiteralSpecConstantOpInteger": "SpecConstantOpInteger",
+    "PairLiteralIntegerIdRef": "PairWordId",
+    "PairIdRefLiteralInteger": "PairIdWord",
+    "PairIdRefIdRef": "PairIdId",
+}
+QUANTIFIERS = {
+    None: "One",
+    "?": "Optional",
+    "*": "Variadic",
+}
+
+
+def main():
+    with open(GRAMMAR_PATH) as f:
+        grammar = json.load(f)
+
+    param_enums = {}
+    for operand_kind in grammar["operand_kinds"]:
+        enumerants = {}
+        for enumerant in operand_kind.get("enumerants", []):
+            params = enumerant.get("parameters", [])
+            value = enumerant["value"]
+            if isinstance(value, str):
+                value = int(value, 0)
+            if params and value not in enumerants:
+                enumerants[value] = params
+        if enumerants:
+            param_enums[operand_kind["kind"]] = (operand_kind["category"], enumerants)
+
+    def kind_name(kind):
+        if kind in SIMPLE_KINDS:
+            return SIMPLE_KINDS[kind]
+        if kind in param_enums:
+            return kind
+        return "Word"
+
+    def operand_list(operands):
+        items = []
+        for operand in operands:
+            kind = kind_name(operand["kind"])
+            quantifier = QUANTIFIERS[operand.get("quantifier")]
+            items.append(f"({kind}, {quantifier})")
+        return "&[" + ", ".join(items) + "]"
+
+    out = []
+    out.append("//! Operand kinds of SPIR-V instructions, as is specified in the SPIR-V")
+    out.append("//! grammar. Generated by `scripts/generate_operand_kinds.py` from")
+    out.append(
+        f"//! `{GRAMMAR_PATH}` (SPIR-V {grammar['major_version']}.{grammar['minor_version']}"
+        f" revision {grammar['revision']})."
+    )
+    out.append("//! Don't edit by hand.")
+    out.append("")
+    out.append("/// Kind of an operand, as far as the words it takes and the IDs in it are")
+    out.append("/// concerned. Enums without parameters are simply [`OperandKind::Word`]s.")
+    out.append("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    out.append("pub(crate) enum OperandKind {")
+    out.append("    IdResultType,")
+    out.append("    IdResult,")
+    out.append("    /// Any other ID, e.g., `IdRef`, `IdScope` and `IdMemorySemantics`.")
+    out.append("    Id,")
+    out.append("    /// A single literal word, or an enumerant without parameters.")
+    out.append("    Word,")
+    out.append("    /// A nul-terminated UTF-8 string padded to words.")
+    out.append("    String,")
+    out.append("    /// A literal number taking the rest of the instruction, whose width")
+    out.append("    /// depends on the result type, e.g., the value of `OpConstant`.")
+    out.append("    ContextDependentNumber,")
+    out.append("    /// The opcode of `OpSpecConstantOp`, followed by the operands of the")
+    out.append("    /// opcode except the result type and the result ID.")
+    out.append("    SpecConstantOpInteger,")
+    out.append("    /// A literal and an ID, e.g., a case of `OpSwitch`. The literal is as")
+    out.append("    /// wide as the selector.")
+    out.append("    PairWordId,")
+    out.append("    PairIdWord,")
+    out.append("    PairIdId,")
+    for kind, (category, _) in sorted(param_enums.items()):
+        if category == "BitEnum":
+            out.append(f"    /// `{kind}` bit mask followed by the parameters of each set bit in")
+            out.append("    /// ascending order.")
+        else:
+            out.append(f"    /// `{kind}` enumerant followed by its parameters.")
+        out.append(f"    {kind},")
+    out.append("}")
+    out.append("")
+    out.append("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    out.append("pub(crate) enum Quantifier {")
+    out.append("    One,")
+    out.append("    Optional,")
+    out.append("    Variadic,")
+    out.append("}")
+    out.append("")
+    out.append("use OperandKind::*;")
+    out.append("use Quantifier::*;")
+    out.append("")
+    out.append("/// Operands of the instruction of `opcode`. Returns `None` if the opcode is")
+    out.append("/// not known by the grammar.")
+    out.append("pub(crate) fn operand_kinds(opcode: u32) -> Option<&'static [(OperandKind, Quantifier)]> {")
+    out.append("    let out: &'static [(OperandKind, Quantifier)] = match opcode {")
+    seen = set()
+    for instr in grammar["instructions"]:
+        opcode = instr["opcode"]
+        if opcode in seen:
+            continue
+        seen.add(opcode)
+        out.append(f"        // {instr['opname']}")
+        out.append(f"        {opcode} => {operand_list(instr.get('operands', []))},")
+    out.append("        _ => return None,")
+    out.append("    };")
+    out.append("    Some(out)")
+    out.append("}")
+    out.append("")
+    out.append("/// Parameters following the enum operand of `kind` and `value`. For bit")
+    out.append("/// enums, `value` is a single bit.")
+    out.append("pub(crate) fn enum_parameters(kind: OperandKind, value: u32) -> &'static [(OperandKind, Quantifier)] {")
+    out.append("    match (kind, value) {")
+    for kind, (_, enumerants) in sorted(param_enums.items()):
+        for value, params in sorted(enumerants.items()):
+            out.append(f"        ({kind}, {value:#x}) => {operand_list(params)},")
+    out.append("        _ => &[],")
+    out.append("    }")
+    out.append("}")
+    out.append("")
+
+    with open(OUT_PATH, "w") as f:
+        f.write("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -1,11 +1,9 @@
 use clap::Parser;
-use serde_json::json;
 use spirq::prelude::*;
-use spirq::ty;
 use std::{
     borrow::Borrow,
     fs::File,
-    io::{stderr, Write},
+    io::{self, stderr, stdout, BufWriter, Write},
     path::{Path, PathBuf},
     process::exit,
 };
@@ -71,20 +69,14 @@ struct Args {
 }
 
 fn read_spirv_bianry(path: &str) -> SpirvBinary {
-    let spv = match std::fs::read(&path) {
+    match SpirvBinary::from_file(path) {
         Ok(x) => x,
         Err(e) => {
             writeln!(stderr(), "{}", e.to_string()).unwrap();
             writeln!(stderr(), "cannot read from SPIR-V binary: {}", path).unwrap();
             exit(-1);
         }
-    };
-    if spv.len() % 4 != 0 {
-        // Misaligned input.
-        writeln!(stderr(), "spirv binary must align to 4 bytes: {}", path).unwrap();
-        exit(-1);
     }
-    SpirvBinary::from(spv)
 }
 
 fn compile_shader_source(
@@ -408,141 +400,6 @@ fn get_spirv_bianry(path: &str, args: &Args) -> SpirvBinary {
     read_spirv_bianry(path)
 }
 
-fn member2json(member: &ty::StructMember) -> serde_json::Value {
-    json!({
-        "Name": member.name,
-        "Offset": member.offset,
-        "MemberType": ty2json(&member.ty)
-    })
-}
-fn ty2json(ty: &Type) -> serde_json::Value {
-    match ty {
-        Type::Matrix(x) => json!({
-            "Kind": "Matrix",
-            "AxisOrder": x.axis_order.map(|x| format!("{:?}", x)),
-            "VectorType": x.vector_ty.to_string(),
-            "Count": x.nvector,
-            "Stride": x.stride,
-        }),
-        Type::Array(x) => json!({
-            "Kind": "Array",
-            "ElementType": ty2json(&*x.element_ty),
-            "Count": x.nelement,
-            "Stride": x.stride
-        }),
-        Type::Struct(x) => json!({
-            "Kind": "Struct",
-            "Members": x.members.iter().map(member2json).collect::<Vec<_>>()
-        }),
-        Type::DevicePointer(x) => json!({
-            "Kind": "Pointer",
-            "TargetType": ty2json(&*x.pointee_ty)
-        }),
-        _ => json!(ty.to_string()),
-    }
-}
-fn entry_point2json(entry_point: &EntryPoint) -> serde_json::Value {
-    let mut inputs = Vec::new();
-    let mut outputs = Vec::new();
-    let mut descs = Vec::new();
-    let mut push_consts = Vec::new();
-    let mut spec_consts = Vec::new();
-    for var in entry_point.vars.iter() {
-        use Variable::*;
-        match var {
-            Input { name, location, ty } => {
-                let j = json!({
-                    "Name": name.as_ref(),
-                    "Location": location.loc(),
-                    "Component": location.comp(),
-                    "Type": ty2json(&ty),
-                });
-                inputs.push(j);
-            }
-            Output { name, location, ty } => {
-                let j = json!({
-                    "Name": name.as_ref(),
-                    "Location": location.loc(),
-                    "Component": location.comp(),
-                    "Type": ty2json(&ty),
-                });
-                outputs.push(j);
-            }
-            Descriptor {
-                name,
-                desc_bind,
-                desc_ty,
-                ty,
-                nbind,
-            } => {
-                let j = json!({
-                    "Name": name.as_ref(),
-                    "Set": desc_bind.set(),
-                    "Binding": desc_bind.bind(),
-                    "DescriptorType": format!("{desc_ty:?}"),
-                    "Type": ty2json(&ty),
-                    "Count": nbind,
-                });
-                descs.push(j);
-            }
-            PushConstant { name, ty } => {
-                let j = json!({
-                    "Name": name.as_ref(),
-                    "Type": ty2json(&ty),
-                });
-                push_consts.push(j);
-            }
-            SpecConstant { name, spec_id, ty } => {
-                let j = json!({
-                    "Name": name.as_ref(),
-                    "SpecId": spec_id,
-                    "Type": ty2json(&ty),
-                });
-                spec_consts.push(j);
-            }
-        }
-    }
-
-    let mut exec_modes = Vec::new();
-    for exec_mode in entry_point.exec_modes.iter() {
-        let operands = exec_mode
-            .operands
-            .iter()
-            .map(|operand| {
-                let value = match operand.value {
-                    ConstantValue::Bool(x) => x.to_string(),
-                    ConstantValue::S32(x) => x.to_string(),
-                    ConstantValue::U32(x) => x.to_string(),
-                    ConstantValue::F32(x) => x.to_string(),
-                    _ => todo!(),
-                };
-                json!({
-                    "Value": value,
-                    "SpecId": operand.spec_id,
-                })
-            })
-            .collect::<Vec<_>>();
-        let j = json!({
-            "ExecutionMode": format!("{:?}", exec_mode.exec_mode),
-            "Operands": operands,
-        });
-        exec_modes.push(j);
-    }
-
-    json!({
-        "EntryPoint": entry_point.name,
-        "ExecutionModel": format!("{:?}", entry_point.exec_model),
-        "ExecutionModes": exec_modes,
-        "Variables": {
-            "Inputs": inputs,
-            "Outputs": outputs,
-            "Descriptors": descs,
-            "PushConstants": push_consts,
-            "SpecConstants": spec_consts
-        },
-    })
-}
-
 fn main() {
     let args = Args::parse();
 
@@ -565,11 +422,8 @@ fn main() {
     };
 
     for entry_point in entry_points {
-        let j = entry_point2json(&entry_point);
-        let json = serde_json::to_string_pretty(&j).unwrap();
-
         if let Some(ref out_path) = args.out_path {
-            let mut f = match File::create(out_path) {
+            let f = match File::create(out_path) {
                 Ok(x) => x,
                 Err(e) => {
                     writeln!(stderr(), "{e}").unwrap();
@@ -577,13 +431,22 @@ fn main() {
                     exit(-1);
                 }
             };
-            if let Err(e) = writeln!(f, "{json}") {
+            // Stream the JSON to the file, so that huge reflection results
+            // are never held completely in memory.
+            let mut f = BufWriter::new(f);
+            let res = spirq::json::write_json(&entry_point, &mut f)
+                .map_err(io::Error::from)
+                .and_then(|_| writeln!(f))
+                .and_then(|_| f.flush());
+            if let Err(e) = res {
                 writeln!(stderr(), "{e}").unwrap();
                 writeln!(stderr(), "cannot write to output file: {out_path}").unwrap();
                 exit(-1);
             };
         } else {
-            println!("{}", serde_json::to_string_pretty(&j).unwrap());
+            let mut out = stdout().lock();
+            spirq::json::write_json(&entry_point, &mut out).unwrap();
+            writeln!(out).unwrap();
         }
     }
 }
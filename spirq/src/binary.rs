@@ -0,0 +1,366 @@
+//! Loading SPIR-V binaries from files and byte streams.
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{
+    error::{anyhow, Result},
+    operand::{enum_parameters, operand_kinds, OperandKind, Quantifier},
+    parse::{Instrs, SpirvBinary, SpirvHeader},
+    reflect::checked_instrs,
+    spirv,
+};
+
+/// Fallible constructors of [`SpirvBinary`] from files, streams and bytes.
+pub trait SpirvBinaryExt: Sized {
+    /// Read a SPIR-V binary from the file at `path`.
+    fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self>;
+    /// Read a SPIR-V binary from `r` till the end of stream. The endianness
+    /// is detected from the magic number.
+    fn from_reader<R: Read>(r: R) -> io::Result<Self>;
+    /// Interpret `bytes` as a SPIR-V binary. Unlike `From<&[u8]>` which gives
+    /// an empty binary on failure, an error is returned if the length is not
+    /// a multiple of 4, the header is truncated, or the magic number is
+    /// missing in either endianness.
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self>;
+    /// Iterate over the instructions like [`SpirvBinary::instrs`], but fail
+    /// upfront if the header is missing, any instruction is truncated or has
+    /// an unknown opcode, so that iteration and operand reads never panic on
+    /// untrusted binaries.
+    fn checked_instrs(&self) -> Result<Instrs<'_>>;
+    /// A copy of the binary without debug instructions, i.e., `OpName`,
+    /// `OpMemberName`, `OpSource`, `OpSourceContinued`, `OpSourceExtension`,
+    /// `OpLine`, `OpNoLine`, `OpModuleProcessed` and `OpString`, so that
+    /// binaries can be compared regardless of debug information. `OpString`s
+    /// still referenced by extended instructions are kept. IDs are not
+    /// renumbered so the bound in the header remains valid.
+    fn strip_debug_info(&self) -> Result<Self>;
+    /// A copy of the binary with result IDs renumbered densely from 1 in the
+    /// order of their first appearance, and the ID bound in the header
+    /// shrunk accordingly. All references to the IDs are updated so the
+    /// binary reflects the same as before. An error is returned for opcodes
+    /// unknown to the bundled SPIR-V grammar, and extended instructions of
+    /// sets other than `GLSL.std.450`, `NonSemantic.*` and `SPV_AMD_*` whose
+    /// operands might be literals.
+    fn remap_ids(&self) -> Result<Self>;
+    /// The module header. Unlike [`SpirvBinary::header`], an error is
+    /// returned rather than a panic if the binary is shorter than the 5-word
+    /// header, and the magic number is validated. Binaries read by
+    /// [`SpirvBinaryExt::try_from_bytes`] are already in native endianness;
+    /// an error is returned for byte-swapped words.
+    fn checked_header(&self) -> Result<SpirvHeader>;
+}
+
+/// Accessors of the fields packed in a [`SpirvHeader`].
+pub trait SpirvHeaderExt {
+    /// SPIR-V version as `(major, minor)`.
+    fn version_tuple(&self) -> (u8, u8);
+    /// Registered generator tool ID in the high 16 bits of the generator
+    /// magic number, e.g., 8 for glslang.
+    fn generator_tool(&self) -> u16;
+    /// Tool-specific version in the low 16 bits of the generator magic
+    /// number.
+    fn generator_version(&self) -> u16;
+}
+impl SpirvHeaderExt for SpirvHeader {
+    fn version_tuple(&self) -> (u8, u8) {
+        ((self.version >> 16) as u8, (self.version >> 8) as u8)
+    }
+    fn generator_tool(&self) -> u16 {
+        (self.generator >> 16) as u16
+    }
+    fn generator_version(&self) -> u16 {
+        self.generator as u16
+    }
+}
+fn is_debug_op(op: spirv::Op) -> bool {
+    use spirv::Op;
+    matches!(
+        op,
+        Op::Name
+            | Op::MemberName
+            | Op::Source
+            | Op::SourceContinued
+            | Op::SourceExtension
+            | Op::Line
+            | Op::NoLine
+            | Op::ModuleProcessed
+            | Op::String
+    )
+}
+/// Collects the offsets of IDs in an instruction by walking its operands as
+/// is specified by the grammar.
+struct OperandWalker<'a> {
+    words: &'a [u32],
+    pos: usize,
+    /// Number of words of the literal in `OpSwitch` cases.
+    literal_nword: usize,
+    id_offsets: Vec<usize>,
+}
+impl<'a> OperandWalker<'a> {
+    fn new(words: &'a [u32]) -> Self {
+        OperandWalker {
+            words,
+            pos: 1,
+            literal_nword: 1,
+            id_offsets: Vec::new(),
+        }
+    }
+    fn walk(&mut self, operands: &[(OperandKind, Quantifier)]) -> Result<()> {
+        for &(kind, quantifier) in operands {
+            match quantifier {
+                Quantifier::One => self.walk_one(kind)?,
+                Quantifier::Optional => {
+                    if self.pos < self.words.len() {
+                        self.walk_one(kind)?;
+                    }
+                }
+                Quantifier::Variadic => {
+                    while self.pos < self.words.len() {
+                        self.walk_one(kind)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    fn take(&mut self) -> Result<u32> {
+        let x = *self
+            .words
+            .get(self.pos)
+            .ok_or(anyhow!("instruction operands are truncated"))?;
+        self.pos += 1;
+        Ok(x)
+    }
+    fn take_id(&mut self) -> Result<()> {
+        self.id_offsets.push(self.pos);
+        self.take().map(|_| ())
+    }
+    fn walk_one(&mut self, kind: OperandKind) -> Result<()> {
+        use OperandKind::*;
+        match kind {
+            IdResultType | IdResult | Id => self.take_id()?,
+            Word => {
+                self.take()?;
+            }
+            String => while !self.take()?.to_le_bytes().contains(&0) {},
+            ContextDependentNumber => self.pos = self.words.len(),
+            SpecConstantOpInteger => {
+                let opcode = self.take()?;
+                let operands = operand_kinds(opcode)
+                    .ok_or(anyhow!("unknown opcode {} in OpSpecConstantOp", opcode))?;
+                let operands = operands
+                    .iter()
+                    .skip_while(|(kind, _)| matches!(kind, IdResultType | IdResult))
+                    .copied()
+                    .collect::<Vec<_>>();
+                self.walk(&operands)?;
+            }
+            PairWordId => {
+                for _ in 0..self.literal_nword {
+                    self.take()?;
+                }
+                self.take_id()?;
+            }
+            PairIdWord => {
+                self.take_id()?;
+                self.take()?;
+            }
+            PairIdId => {
+                self.take_id()?;
+                self.take_id()?;
+            }
+            ImageOperands | LoopControl | MemoryAccess => {
+                let mask = self.take()?;
+                for i in 0..32 {
+                    let bit = 1 << i;
+                    if mask & bit != 0 {
+                        self.walk(enum_parameters(kind, bit))?;
+                    }
+                }
+            }
+            Decoration | ExecutionMode => {
+                let value = self.take()?;
+                self.walk(enum_parameters(kind, value))?;
+            }
+        }
+        Ok(())
+    }
+}
+fn is_id_only_ext_instr_set(name: &str) -> bool {
+    name == "GLSL.std.450" || name.starts_with("NonSemantic.") || name.starts_with("SPV_AMD_")
+}
+fn parse_str(words: &[u32]) -> String {
+    let bytes = words
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .take_while(|x| *x != 0)
+        .collect::<Vec<_>>();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+impl SpirvBinaryExt for SpirvBinary {
+    fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let f = File::open(path)?;
+        SpirvBinary::from_reader(f)
+    }
+    fn from_reader<R: Read>(mut r: R) -> io::Result<Self> {
+        let mut buf = Vec::new();
+        r.read_to_end(&mut buf)?;
+        SpirvBinary::try_from_bytes(&buf)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+    }
+    fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() % 4 != 0 {
+            return Err(anyhow!(
+                "spirv binary must align to 4 bytes, but got {} bytes",
+                bytes.len()
+            ));
+        }
+        if bytes.len() < 5 * 4 {
+            return Err(anyhow!(
+                "spirv header is truncated, expected at least 5 words but got {}",
+                bytes.len() / 4
+            ));
+        }
+        let magic = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        if u32::from_le_bytes(magic) != spirv::MAGIC_NUMBER
+            && u32::from_be_bytes(magic) != spirv::MAGIC_NUMBER
+        {
+            return Err(anyhow!(
+                "invalid spirv magic number {:#010x}",
+                u32::from_le_bytes(magic)
+            ));
+        }
+        Ok(SpirvBinary::from(bytes))
+    }
+    fn checked_instrs(&self) -> Result<Instrs<'_>> {
+        checked_instrs(self.words())
+    }
+    fn strip_debug_info(&self) -> Result<Self> {
+        const HEADER_LEN: usize = 5;
+        // Strings can be operands of extended instructions, e.g., in
+        // `NonSemantic.Shader.DebugInfo.100`.
+        let mut referenced_ids = HashSet::new();
+        let mut instrs = self.checked_instrs()?;
+        while let Some(instr) = instrs.next()? {
+            if instr.op() == spirv::Op::ExtInst {
+                referenced_ids.extend(instr.operands().skip(4));
+            }
+        }
+
+        let mut words = self.words()[..HEADER_LEN].to_vec();
+        let mut instrs = self.checked_instrs()?;
+        while let Some(instr) = instrs.next()? {
+            let op = instr.op();
+            if is_debug_op(op) {
+                let is_referenced_str = op == spirv::Op::String
+                    && instr
+                        .operands()
+                        .next()
+                        .is_some_and(|x| referenced_ids.contains(&x));
+                if !is_referenced_str {
+                    continue;
+                }
+            }
+            words.extend_from_slice(instr.as_ref());
+        }
+        Ok(SpirvBinary::from(words))
+    }
+    fn remap_ids(&self) -> Result<Self> {
+        const HEADER_LEN: usize = 5;
+        self.checked_header()?;
+        // Bookkeeping by old IDs, to know the width of `OpSwitch` literals and
+        // whether extended instructions only take IDs.
+        let mut int_widths = HashMap::new();
+        let mut result_tys = HashMap::new();
+        let mut ext_instr_set_names = HashMap::new();
+
+        let mut id_map: HashMap<u32, u32> = HashMap::new();
+        let mut words = self.words()[..HEADER_LEN].to_vec();
+        let mut instrs = self.checked_instrs()?;
+        while let Some(instr) = instrs.next()? {
+            let op = instr.op();
+            let instr_words = instr.as_ref();
+            let opcode = op as u32;
+            let operands = operand_kinds(opcode)
+                .ok_or_else(|| anyhow!("cannot remap ids in unknown opcode {:?}", op))?;
+
+            let mut walker = OperandWalker::new(instr_words);
+            match op {
+                spirv::Op::Switch => {
+                    let selector = instr_words.get(1).copied().unwrap_or_default();
+                    let width = result_tys
+                        .get(&selector)
+                        .and_then(|ty| int_widths.get(ty))
+                        .ok_or_else(|| anyhow!("selector of OpSwitch is not an integer"))?;
+                    walker.literal_nword = if *width > 32 { 2 } else { 1 };
+                }
+                spirv::Op::ExtInst => {
+                    let instr_set = instr_words.get(3).copied().unwrap_or_default();
+                    let name: &str = ext_instr_set_names
+                        .get(&instr_set)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    if !is_id_only_ext_instr_set(name) {
+                        return Err(anyhow!(
+                            "cannot remap ids in extended instruction set {:?}",
+                            name
+                        ));
+                    }
+                }
+                _ => {}
+            }
+            walker.walk(operands)?;
+            if walker.pos != instr_words.len() {
+                return Err(anyhow!("unexpected trailing operands in {:?}", op));
+            }
+
+            if let [(OperandKind::IdResultType, _), (OperandKind::IdResult, _), ..] = operands {
+                result_tys.insert(instr_words[2], instr_words[1]);
+            }
+            match op {
+                spirv::Op::TypeInt => {
+                    int_widths.insert(instr_words[1], instr_words[2]);
+                }
+                spirv::Op::ExtInstImport => {
+                    ext_instr_set_names.insert(instr_words[1], parse_str(&instr_words[2..]));
+                }
+                _ => {}
+            }
+
+            let mut instr_words = instr_words.to_vec();
+            for i in walker.id_offsets {
+                let nid = id_map.len() as u32 + 1;
+                instr_words[i] = *id_map.entry(instr_words[i]).or_insert(nid);
+            }
+            words.extend(instr_words);
+        }
+        words[3] = id_map.len() as u32 + 1;
+        Ok(SpirvBinary::from(words))
+    }
+    fn checked_header(&self) -> Result<SpirvHeader> {
+        let words = self.words();
+        if words.len() < 5 {
+            return Err(anyhow!(
+                "spirv header is truncated, expected at least 5 words but got {}",
+                words.len()
+            ));
+        }
+        if words[0] != spirv::MAGIC_NUMBER {
+            if words[0].swap_bytes() == spirv::MAGIC_NUMBER {
+                return Err(anyhow!("spirv words are byte-swapped"));
+            }
+            return Err(anyhow!("invalid spirv magic number {:#010x}", words[0]));
+        }
+        Ok(SpirvHeader {
+            magic: words[0],
+            version: words[1],
+            generator: words[2],
+            bound: words[3],
+            schema: words[4],
+        })
+    }
+}
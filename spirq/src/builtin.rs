@@ -0,0 +1,161 @@
+//! Stage validation of built-in variables.
+use std::fmt;
+
+use crate::spirv::{BuiltIn, ExecutionModel, StorageClass};
+
+/// A built-in variable used in an execution model or storage class the
+/// Vulkan specification doesn't allow, e.g., `FragCoord` read in a vertex
+/// shader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BuiltinUsageIssue {
+    pub exec_model: ExecutionModel,
+    /// Either `Input` or `Output`.
+    pub store_cls: StorageClass,
+    pub builtin: BuiltIn,
+}
+impl fmt::Display for BuiltinUsageIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "built-in {:?} cannot be used as {:?} in {:?} shaders",
+            self.builtin, self.store_cls, self.exec_model
+        )
+    }
+}
+
+/// Whether `builtin` can be declared in `store_cls` by an entry point of
+/// `exec_model`, according to section *Built-In Variables* of the Vulkan
+/// specification. Returns `None` for built-ins not covered by the table,
+/// e.g., those available to all stages like `SubgroupSize`, and storage
+/// classes other than `Input` and `Output`.
+pub fn is_builtin_allowed(
+    exec_model: ExecutionModel,
+    store_cls: StorageClass,
+    builtin: BuiltIn,
+) -> Option<bool> {
+    use ExecutionModel as E;
+    use StorageClass::{Input, Output};
+
+    let is_pre_raster = matches!(
+        exec_model,
+        E::Vertex | E::TessellationControl | E::TessellationEvaluation | E::Geometry
+    );
+    let is_mesh = matches!(exec_model, E::MeshNV | E::MeshEXT);
+    let is_compute_like = matches!(
+        exec_model,
+        E::GLCompute | E::TaskNV | E::TaskEXT | E::MeshNV | E::MeshEXT
+    );
+    let is_ray_tracing = matches!(
+        exec_model,
+        E::RayGenerationKHR
+            | E::IntersectionKHR
+            | E::AnyHitKHR
+            | E::ClosestHitKHR
+            | E::MissKHR
+            | E::CallableKHR
+    );
+    let is_hit = matches!(
+        exec_model,
+        E::IntersectionKHR | E::AnyHitKHR | E::ClosestHitKHR
+    );
+    let is_tess_or_geom = matches!(
+        exec_model,
+        E::TessellationControl | E::TessellationEvaluation | E::Geometry
+    );
+
+    let is_input = match store_cls {
+        Input => true,
+        Output => false,
+        _ => return None,
+    };
+    let allowed = match builtin {
+        BuiltIn::Position | BuiltIn::PointSize => {
+            if is_input {
+                is_tess_or_geom
+            } else {
+                is_pre_raster || is_mesh
+            }
+        }
+        BuiltIn::ClipDistance | BuiltIn::CullDistance => {
+            if is_input {
+                is_tess_or_geom || exec_model == E::Fragment
+            } else {
+                is_pre_raster || is_mesh
+            }
+        }
+        BuiltIn::VertexIndex
+        | BuiltIn::InstanceIndex
+        | BuiltIn::BaseVertex
+        | BuiltIn::BaseInstance => is_input && exec_model == E::Vertex,
+        BuiltIn::DrawIndex => {
+            is_input
+                && matches!(
+                    exec_model,
+                    E::Vertex | E::TaskNV | E::TaskEXT | E::MeshNV | E::MeshEXT
+                )
+        }
+        BuiltIn::InvocationId => {
+            is_input && matches!(exec_model, E::TessellationControl | E::Geometry)
+        }
+        BuiltIn::PatchVertices => {
+            is_input
+                && matches!(
+                    exec_model,
+                    E::TessellationControl | E::TessellationEvaluation
+                )
+        }
+        BuiltIn::TessLevelOuter | BuiltIn::TessLevelInner => {
+            if is_input {
+                exec_model == E::TessellationEvaluation
+            } else {
+                exec_model == E::TessellationControl
+            }
+        }
+        BuiltIn::TessCoord => is_input && exec_model == E::TessellationEvaluation,
+        BuiltIn::PrimitiveId => {
+            if is_input {
+                is_tess_or_geom || is_hit || exec_model == E::Fragment
+            } else {
+                exec_model == E::Geometry || is_mesh
+            }
+        }
+        BuiltIn::Layer | BuiltIn::ViewportIndex => {
+            if is_input {
+                exec_model == E::Fragment
+            } else {
+                matches!(
+                    exec_model,
+                    E::Vertex | E::TessellationEvaluation | E::Geometry
+                ) || is_mesh
+            }
+        }
+        BuiltIn::FragCoord
+        | BuiltIn::FrontFacing
+        | BuiltIn::PointCoord
+        | BuiltIn::HelperInvocation
+        | BuiltIn::SampleId
+        | BuiltIn::SamplePosition => is_input && exec_model == E::Fragment,
+        BuiltIn::SampleMask => exec_model == E::Fragment,
+        BuiltIn::FragDepth => !is_input && exec_model == E::Fragment,
+        BuiltIn::NumWorkgroups
+        | BuiltIn::WorkgroupId
+        | BuiltIn::LocalInvocationId
+        | BuiltIn::GlobalInvocationId
+        | BuiltIn::LocalInvocationIndex => is_input && is_compute_like,
+        BuiltIn::LaunchIdKHR | BuiltIn::LaunchSizeKHR => is_input && is_ray_tracing,
+        BuiltIn::WorldRayOriginKHR
+        | BuiltIn::WorldRayDirectionKHR
+        | BuiltIn::RayTminKHR
+        | BuiltIn::RayTmaxKHR
+        | BuiltIn::IncomingRayFlagsKHR => is_input && (is_hit || exec_model == E::MissKHR),
+        BuiltIn::ObjectRayOriginKHR
+        | BuiltIn::ObjectRayDirectionKHR
+        | BuiltIn::InstanceCustomIndexKHR
+        | BuiltIn::ObjectToWorldKHR
+        | BuiltIn::WorldToObjectKHR
+        | BuiltIn::RayGeometryIndexKHR => is_input && is_hit,
+        BuiltIn::HitKindKHR => is_input && matches!(exec_model, E::AnyHitKHR | E::ClosestHitKHR),
+        _ => return None,
+    };
+    Some(allowed)
+}
@@ -0,0 +1,217 @@
+//! Convenience queries on descriptor resources.
+use crate::{
+    spirv::ImageFormat,
+    ty::{
+        CombinedImageSamplerType, DescriptorType, SampledImageType, ScalarType, StorageImageType,
+        Type,
+    },
+    var::Variable,
+};
+
+/// Image details of [`Variable::Descriptor`] which are otherwise only
+/// reachable by matching on the descriptor's [`Type`].
+pub trait DescriptorVariableExt {
+    /// Whether the descriptor is a depth image, i.e., a sampled image or a
+    /// combined image sampler declared with `Depth` set in `OpTypeImage`.
+    /// Arrays of images report the depth flag of their element. Returns
+    /// `None` if the variable is not an image descriptor, or the depth flag
+    /// is not known from the SPIR-V.
+    fn is_depth_image(&self) -> Option<bool>;
+    /// Input attachment index of a subpass input, i.e., the index into
+    /// `pInputAttachments` of the subpass the descriptor at this set and
+    /// binding is read from. Returns `None` if the variable is not an input
+    /// attachment.
+    fn input_attachment_index(&self) -> Option<u32>;
+    /// Whether a subpass input reads from a multisampled attachment. Returns
+    /// `None` if the variable is not an input attachment.
+    fn is_multisampled_subpass_data(&self) -> Option<bool>;
+    /// Name of the block type of a uniform or storage buffer, e.g., `Data`
+    /// in `uniform Data { ... } data;` in GLSL, as opposed to the instance
+    /// name `data` in [`Variable::name`]. Returns `None` if the variable is
+    /// not a buffer descriptor or the type is not named by `OpName`.
+    fn type_name(&self) -> Option<&str>;
+}
+impl DescriptorVariableExt for Variable {
+    fn is_depth_image(&self) -> Option<bool> {
+        let mut ty = match self {
+            Variable::Descriptor { ty, .. } => ty,
+            _ => return None,
+        };
+        while let Type::Array(arr_ty) = ty {
+            ty = &arr_ty.element_ty;
+        }
+        match ty {
+            Type::SampledImage(sampled_img_ty) => sampled_img_ty.is_depth,
+            Type::CombinedImageSampler(combined_img_sampler_ty) => {
+                combined_img_sampler_ty.sampled_image_ty.is_depth
+            }
+            _ => None,
+        }
+    }
+    fn input_attachment_index(&self) -> Option<u32> {
+        match self {
+            Variable::Descriptor {
+                desc_ty: DescriptorType::InputAttachment(idx),
+                ..
+            } => Some(*idx),
+            _ => None,
+        }
+    }
+    fn is_multisampled_subpass_data(&self) -> Option<bool> {
+        let mut ty = match self {
+            Variable::Descriptor {
+                desc_ty: DescriptorType::InputAttachment(_),
+                ty,
+                ..
+            } => ty,
+            _ => return None,
+        };
+        while let Type::Array(arr_ty) = ty {
+            ty = &arr_ty.element_ty;
+        }
+        match ty {
+            Type::SubpassData(subpass_data_ty) => Some(subpass_data_ty.is_multisampled),
+            _ => None,
+        }
+    }
+    fn type_name(&self) -> Option<&str> {
+        let mut ty = match self {
+            Variable::Descriptor { ty, .. } => ty,
+            _ => return None,
+        };
+        while let Type::Array(arr_ty) = ty {
+            ty = &arr_ty.element_ty;
+        }
+        match ty {
+            Type::Struct(struct_ty) => struct_ty.name(),
+            _ => None,
+        }
+    }
+}
+
+/// Numeric mapping of [`DescriptorType`] to `VkDescriptorType`.
+pub trait DescriptorTypeExt {
+    /// `VkDescriptorType` value of the descriptor type. The access type of
+    /// storage resources doesn't affect the value.
+    ///
+    /// SPIR-V doesn't tell static buffers from dynamic ones, so uniform and
+    /// storage buffers are always mapped to the static
+    /// `VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER` and
+    /// `VK_DESCRIPTOR_TYPE_STORAGE_BUFFER`. Replace them with
+    /// `VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER_DYNAMIC` (8) and
+    /// `VK_DESCRIPTOR_TYPE_STORAGE_BUFFER_DYNAMIC` (9) if the buffers are
+    /// bound with dynamic offsets.
+    fn to_vk(&self) -> u32;
+}
+impl DescriptorTypeExt for DescriptorType {
+    fn to_vk(&self) -> u32 {
+        match self {
+            DescriptorType::Sampler() => 0,
+            DescriptorType::CombinedImageSampler() => 1,
+            DescriptorType::SampledImage() => 2,
+            DescriptorType::StorageImage(_) => 3,
+            DescriptorType::UniformTexelBuffer() => 4,
+            DescriptorType::StorageTexelBuffer(_) => 5,
+            DescriptorType::UniformBuffer() => 6,
+            DescriptorType::StorageBuffer(_) => 7,
+            DescriptorType::InputAttachment(_) => 10,
+            DescriptorType::AccelStruct() => 1000150000,
+        }
+    }
+}
+
+/// Texel types returned by image reads and sampling, e.g., to generate
+/// texture-fetch wrappers.
+pub trait ImageTexelExt {
+    /// Whether texels are floating-point numbers, including normalized
+    /// formats. Returns false if it's not known from the SPIR-V, e.g., for
+    /// storage images of `Unknown` format.
+    fn is_float(&self) -> bool;
+    /// Whether texels are signed integers.
+    fn is_sint(&self) -> bool;
+    /// Whether texels are unsigned integers.
+    fn is_uint(&self) -> bool;
+    /// Number of components in the texel vector type. Sampled images always
+    /// return 4 components, as is conventional in Vulkan; storage images
+    /// return the number of components in the image format, or `None` if
+    /// the format is `Unknown`.
+    fn texel_component_count(&self) -> Option<u32>;
+}
+impl ImageTexelExt for SampledImageType {
+    fn is_float(&self) -> bool {
+        matches!(self.scalar_ty, ScalarType::Float { .. })
+    }
+    fn is_sint(&self) -> bool {
+        matches!(
+            self.scalar_ty,
+            ScalarType::Integer {
+                is_signed: true,
+                ..
+            }
+        )
+    }
+    fn is_uint(&self) -> bool {
+        matches!(
+            self.scalar_ty,
+            ScalarType::Integer {
+                is_signed: false,
+                ..
+            }
+        )
+    }
+    fn texel_component_count(&self) -> Option<u32> {
+        Some(4)
+    }
+}
+impl ImageTexelExt for CombinedImageSamplerType {
+    fn is_float(&self) -> bool {
+        self.sampled_image_ty.is_float()
+    }
+    fn is_sint(&self) -> bool {
+        self.sampled_image_ty.is_sint()
+    }
+    fn is_uint(&self) -> bool {
+        self.sampled_image_ty.is_uint()
+    }
+    fn texel_component_count(&self) -> Option<u32> {
+        self.sampled_image_ty.texel_component_count()
+    }
+}
+/// Signedness of integer image formats, or `None` for floating-point and
+/// normalized formats, and `Unknown`.
+pub(crate) fn image_format_signedness(fmt: ImageFormat) -> Option<bool> {
+    use ImageFormat::*;
+    match fmt {
+        R32i | R16i | R8i | R64i | Rg32i | Rg16i | Rg8i | Rgba32i | Rgba16i | Rgba8i => Some(true),
+        R32ui | R16ui | R8ui | R64ui | Rg32ui | Rg16ui | Rg8ui | Rgba32ui | Rgba16ui | Rgba8ui
+        | Rgb10a2ui => Some(false),
+        Unknown | R32f | R16f | R16 | R8 | R16Snorm | R8Snorm | Rg32f | Rg16f | Rg16 | Rg8
+        | Rg16Snorm | Rg8Snorm | R11fG11fB10f | Rgba32f | Rgba16f | Rgba8 | Rgba8Snorm | Rgba16
+        | Rgb10A2 | Rgba16Snorm => None,
+    }
+}
+impl ImageTexelExt for StorageImageType {
+    fn is_float(&self) -> bool {
+        self.fmt != ImageFormat::Unknown && !self.is_sint() && !self.is_uint()
+    }
+    fn is_sint(&self) -> bool {
+        image_format_signedness(self.fmt) == Some(true)
+    }
+    fn is_uint(&self) -> bool {
+        image_format_signedness(self.fmt) == Some(false)
+    }
+    fn texel_component_count(&self) -> Option<u32> {
+        use ImageFormat::*;
+        let ncomp = match self.fmt {
+            Unknown => return None,
+            R32f | R16f | R16 | R8 | R16Snorm | R8Snorm | R32i | R16i | R8i | R32ui | R16ui
+            | R8ui | R64ui | R64i => 1,
+            Rg32f | Rg16f | Rg16 | Rg8 | Rg16Snorm | Rg8Snorm | Rg32i | Rg16i | Rg8i | Rg32ui
+            | Rg16ui | Rg8ui => 2,
+            R11fG11fB10f => 3,
+            Rgba32f | Rgba16f | Rgba8 | Rgba8Snorm | Rgba16 | Rgb10A2 | Rgba16Snorm | Rgba32i
+            | Rgba16i | Rgba8i | Rgba32ui | Rgba16ui | Rgba8ui | Rgb10a2ui => 4,
+        };
+        Some(ncomp)
+    }
+}
@@ -0,0 +1,30 @@
+//! Non-fatal observations made during reflection.
+use std::fmt;
+
+use crate::spirv;
+
+/// Something unusual in the module that doesn't stop reflection but might
+/// explain unexpected results. Register a sink with
+/// [`ReflectConfig::on_diagnostic`](crate::ReflectConfig::on_diagnostic) to
+/// receive them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// A matrix member of an explicitly laid out struct has no `MatrixStride`
+    /// decoration. The matrix stride is left unknown, and so is the size of
+    /// the struct.
+    MissingMatrixStride { ty_id: u32, member_idx: u32 },
+    /// An instruction is skipped because it has no effect on reflection.
+    SkippedInstr { opcode: spirv::Op },
+}
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Diagnostic::MissingMatrixStride { ty_id, member_idx } => write!(
+                f,
+                "member {} of struct {} has no matrix stride",
+                member_idx, ty_id
+            ),
+            Diagnostic::SkippedInstr { opcode } => write!(f, "skipped Op{:?}", opcode),
+        }
+    }
+}
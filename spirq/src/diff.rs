@@ -0,0 +1,143 @@
+//! Layout compatibility between entry points, e.g., to decide whether
+//! pipelines have to be recreated when a shader is hot-reloaded.
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::{
+    entry_point::EntryPoint,
+    locator::Locator,
+    ty::Type,
+    var::{DescriptorBinding, InterfaceLocation, Variable},
+};
+
+/// A difference in the interface of two entry points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutChange {
+    DescriptorAdded(DescriptorBinding),
+    DescriptorRemoved(DescriptorBinding),
+    /// The descriptor type, the number of bindings or the layout of the
+    /// descriptor data has changed.
+    DescriptorChanged(DescriptorBinding),
+    /// The byte range covered by push constants has changed; `None` if there
+    /// is no push constant block or the block is not sized.
+    PushConstantRangeChanged {
+        old: Option<Range<usize>>,
+        new: Option<Range<usize>>,
+    },
+    InputAdded(InterfaceLocation),
+    InputRemoved(InterfaceLocation),
+    /// The type of the input variable has changed.
+    InputChanged(InterfaceLocation),
+    OutputAdded(InterfaceLocation),
+    OutputRemoved(InterfaceLocation),
+    /// The type of the output variable has changed.
+    OutputChanged(InterfaceLocation),
+}
+
+/// Returns true if `a` and `b` are laid out identically. Debug names of
+/// structs and struct members are ignored, so that a rename doesn't break
+/// compatibility.
+pub(crate) fn is_layout_eq(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Struct(a), Type::Struct(b)) => {
+            a.members.len() == b.members.len()
+                && a.members.iter().zip(b.members.iter()).all(|(a, b)| {
+                    a.offset == b.offset && a.access_ty == b.access_ty && is_layout_eq(&a.ty, &b.ty)
+                })
+        }
+        (Type::Array(a), Type::Array(b)) => {
+            a.nelement == b.nelement
+                && a.stride == b.stride
+                && is_layout_eq(&a.element_ty, &b.element_ty)
+        }
+        (Type::DevicePointer(a), Type::DevicePointer(b)) => {
+            a.store_cls == b.store_cls && is_layout_eq(&a.pointee_ty, &b.pointee_ty)
+        }
+        (a, b) => a == b,
+    }
+}
+fn is_var_changed(old: &Variable, new: &Variable) -> bool {
+    match (old, new) {
+        (
+            Variable::Descriptor {
+                desc_ty: old_desc_ty,
+                ty: old_ty,
+                nbind: old_nbind,
+                ..
+            },
+            Variable::Descriptor {
+                desc_ty: new_desc_ty,
+                ty: new_ty,
+                nbind: new_nbind,
+                ..
+            },
+        ) => old_desc_ty != new_desc_ty || old_nbind != new_nbind || !is_layout_eq(old_ty, new_ty),
+        (Variable::Input { ty: old_ty, .. }, Variable::Input { ty: new_ty, .. })
+        | (Variable::Output { ty: old_ty, .. }, Variable::Output { ty: new_ty, .. }) => {
+            !is_layout_eq(old_ty, new_ty)
+        }
+        _ => false,
+    }
+}
+
+/// Changes of descriptors, push constants, inputs and outputs from `old` to
+/// `new`. Specialization constants are not considered. Changes are ordered by
+/// inputs, outputs and then descriptors, each by location or binding point,
+/// with the push constant change in the end. An empty list means the layouts
+/// are compatible.
+pub fn diff(old: &EntryPoint, new: &EntryPoint) -> Vec<LayoutChange> {
+    fn collect_vars(entry_point: &EntryPoint) -> BTreeMap<Locator, &Variable> {
+        entry_point
+            .vars
+            .iter()
+            .filter(|var| {
+                matches!(
+                    var,
+                    Variable::Input { .. } | Variable::Output { .. } | Variable::Descriptor { .. }
+                )
+            })
+            .map(|var| (Locator::from(var), var))
+            .collect()
+    }
+    let old_vars = collect_vars(old);
+    let new_vars = collect_vars(new);
+
+    let mut locators = old_vars.keys().chain(new_vars.keys()).collect::<Vec<_>>();
+    locators.sort();
+    locators.dedup();
+
+    let mut out = Vec::new();
+    for locator in locators {
+        let change = match (old_vars.get(locator), new_vars.get(locator)) {
+            (Some(_), None) => match *locator {
+                Locator::Input(location) => LayoutChange::InputRemoved(location),
+                Locator::Output(location) => LayoutChange::OutputRemoved(location),
+                Locator::Descriptor(desc_bind) => LayoutChange::DescriptorRemoved(desc_bind),
+                _ => continue,
+            },
+            (None, Some(_)) => match *locator {
+                Locator::Input(location) => LayoutChange::InputAdded(location),
+                Locator::Output(location) => LayoutChange::OutputAdded(location),
+                Locator::Descriptor(desc_bind) => LayoutChange::DescriptorAdded(desc_bind),
+                _ => continue,
+            },
+            (Some(old_var), Some(new_var)) if is_var_changed(old_var, new_var) => match *locator {
+                Locator::Input(location) => LayoutChange::InputChanged(location),
+                Locator::Output(location) => LayoutChange::OutputChanged(location),
+                Locator::Descriptor(desc_bind) => LayoutChange::DescriptorChanged(desc_bind),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        out.push(change);
+    }
+
+    let old_range = old.push_constant_byte_range();
+    let new_range = new.push_constant_byte_range();
+    if old_range != new_range {
+        out.push(LayoutChange::PushConstantRangeChanged {
+            old: old_range,
+            new: new_range,
+        });
+    }
+    out
+}
@@ -0,0 +1,237 @@
+//! GLSL-style formatting of types.
+use std::fmt;
+
+use crate::{
+    descriptor::image_format_signedness,
+    spirv::{Dim, ImageFormat},
+    ty::{MatrixType, ScalarType, Type, VectorType},
+};
+
+/// Alternative formatting of [`Type`].
+pub trait TypeDisplayExt {
+    /// Formatter of the type. `{}` formats the type as [`Type`]'s own
+    /// `Display` does, e.g., `vec4<f32>`; while `{:#}` formats the type as it
+    /// is spelled in GLSL, e.g., `vec4`, `mat3x4` and `usampler2DArray`.
+    fn display(&self) -> DisplayType<'_>;
+}
+impl TypeDisplayExt for Type {
+    fn display(&self) -> DisplayType<'_> {
+        DisplayType(self)
+    }
+}
+
+/// Formatter of a [`Type`], as is returned by [`TypeDisplayExt::display`].
+pub struct DisplayType<'a>(pub &'a Type);
+impl fmt::Display for DisplayType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write_glsl_ty(f, self.0)
+        } else {
+            fmt::Display::fmt(self.0, f)
+        }
+    }
+}
+
+fn write_glsl_scalar_ty(f: &mut fmt::Formatter, scalar_ty: &ScalarType) -> fmt::Result {
+    match scalar_ty {
+        ScalarType::Void => f.write_str("void"),
+        ScalarType::Boolean => f.write_str("bool"),
+        ScalarType::Integer {
+            bits: 32,
+            is_signed: true,
+        } => f.write_str("int"),
+        ScalarType::Integer {
+            bits: 32,
+            is_signed: false,
+        } => f.write_str("uint"),
+        ScalarType::Integer {
+            bits,
+            is_signed: true,
+        } => write!(f, "int{}_t", bits),
+        ScalarType::Integer {
+            bits,
+            is_signed: false,
+        } => write!(f, "uint{}_t", bits),
+        ScalarType::Float { bits: 32 } => f.write_str("float"),
+        ScalarType::Float { bits: 64 } => f.write_str("double"),
+        ScalarType::Float { bits } => write!(f, "float{}_t", bits),
+    }
+}
+/// Prefix of vector and matrix type names, e.g., `u` in `uvec4` and `f16` in
+/// `f16mat2`.
+fn glsl_vector_prefix(scalar_ty: &ScalarType) -> String {
+    match scalar_ty {
+        ScalarType::Boolean => "b".to_owned(),
+        ScalarType::Integer {
+            bits: 32,
+            is_signed: true,
+        } => "i".to_owned(),
+        ScalarType::Integer {
+            bits: 32,
+            is_signed: false,
+        } => "u".to_owned(),
+        ScalarType::Integer {
+            bits,
+            is_signed: true,
+        } => format!("i{}", bits),
+        ScalarType::Integer {
+            bits,
+            is_signed: false,
+        } => format!("u{}", bits),
+        ScalarType::Float { bits: 64 } => "d".to_owned(),
+        ScalarType::Float { bits } if *bits != 32 => format!("f{}", bits),
+        _ => String::new(),
+    }
+}
+/// Prefix of opaque type names by the scalar type of texel access results,
+/// e.g., `u` in `usampler2D`.
+fn glsl_texel_prefix(scalar_ty: &ScalarType) -> &'static str {
+    match scalar_ty {
+        ScalarType::Integer {
+            is_signed: true, ..
+        } => "i",
+        ScalarType::Integer {
+            is_signed: false, ..
+        } => "u",
+        _ => "",
+    }
+}
+/// Prefix of storage image type names by the image format, since storage
+/// images don't keep the scalar type of texel access results.
+fn glsl_format_prefix(fmt: ImageFormat) -> &'static str {
+    match image_format_signedness(fmt) {
+        Some(true) => "i",
+        Some(false) => "u",
+        None => "",
+    }
+}
+fn write_glsl_image_suffix(
+    f: &mut fmt::Formatter,
+    dim: Dim,
+    is_multisampled: bool,
+    is_array: bool,
+) -> fmt::Result {
+    let dim = match dim {
+        Dim::Dim1D => "1D",
+        Dim::Dim2D => "2D",
+        Dim::Dim3D => "3D",
+        Dim::DimCube => "Cube",
+        Dim::DimRect => "2DRect",
+        Dim::DimBuffer => "Buffer",
+        _ => "",
+    };
+    f.write_str(dim)?;
+    if is_multisampled {
+        f.write_str("MS")?;
+    }
+    if is_array {
+        f.write_str("Array")?;
+    }
+    Ok(())
+}
+fn write_glsl_vector_ty(f: &mut fmt::Formatter, vector_ty: &VectorType) -> fmt::Result {
+    let prefix = glsl_vector_prefix(&vector_ty.scalar_ty);
+    write!(f, "{}vec{}", prefix, vector_ty.nscalar)
+}
+fn write_glsl_matrix_ty(f: &mut fmt::Formatter, matrix_ty: &MatrixType) -> fmt::Result {
+    let prefix = glsl_vector_prefix(&matrix_ty.vector_ty.scalar_ty);
+    let nrow = matrix_ty.vector_ty.nscalar;
+    let ncol = matrix_ty.nvector;
+    if nrow == ncol {
+        write!(f, "{}mat{}", prefix, ncol)
+    } else {
+        write!(f, "{}mat{}x{}", prefix, ncol, nrow)
+    }
+}
+fn write_glsl_ty(f: &mut fmt::Formatter, ty: &Type) -> fmt::Result {
+    match ty {
+        Type::Scalar(scalar_ty) => write_glsl_scalar_ty(f, scalar_ty),
+        Type::Vector(vector_ty) => write_glsl_vector_ty(f, vector_ty),
+        Type::Matrix(matrix_ty) => write_glsl_matrix_ty(f, matrix_ty),
+        Type::Image(img_ty) => {
+            if img_ty.dim == Dim::DimSubpassData {
+                f.write_str(glsl_texel_prefix(&img_ty.scalar_ty))?;
+                f.write_str("subpassInput")?;
+                if img_ty.is_multisampled {
+                    f.write_str("MS")?;
+                }
+                return Ok(());
+            }
+            if img_ty.is_sampled == Some(false) {
+                f.write_str(glsl_format_prefix(img_ty.fmt))?;
+                f.write_str("image")?;
+            } else {
+                f.write_str(glsl_texel_prefix(&img_ty.scalar_ty))?;
+                f.write_str("texture")?;
+            }
+            write_glsl_image_suffix(f, img_ty.dim, img_ty.is_multisampled, img_ty.is_array)
+        }
+        Type::CombinedImageSampler(sampler_ty) => {
+            let img_ty = &sampler_ty.sampled_image_ty;
+            f.write_str(glsl_texel_prefix(&img_ty.scalar_ty))?;
+            f.write_str("sampler")?;
+            write_glsl_image_suffix(f, img_ty.dim, img_ty.is_multisampled, img_ty.is_array)?;
+            if img_ty.is_depth == Some(true) {
+                f.write_str("Shadow")?;
+            }
+            Ok(())
+        }
+        Type::SampledImage(img_ty) => {
+            f.write_str(glsl_texel_prefix(&img_ty.scalar_ty))?;
+            f.write_str("texture")?;
+            write_glsl_image_suffix(f, img_ty.dim, img_ty.is_multisampled, img_ty.is_array)
+        }
+        Type::StorageImage(img_ty) => {
+            f.write_str(glsl_format_prefix(img_ty.fmt))?;
+            f.write_str("image")?;
+            write_glsl_image_suffix(f, img_ty.dim, img_ty.is_multisampled, img_ty.is_array)
+        }
+        Type::Sampler(_) => f.write_str("sampler"),
+        Type::SubpassData(subpass_ty) => {
+            f.write_str(glsl_texel_prefix(&subpass_ty.scalar_ty))?;
+            f.write_str("subpassInput")?;
+            if subpass_ty.is_multisampled {
+                f.write_str("MS")?;
+            }
+            Ok(())
+        }
+        Type::Array(_) => {
+            // GLSL spells the outermost dimension first, e.g., `float[2][3]`
+            // is an array of two `float[3]`s.
+            let mut ty = ty;
+            let mut nelements = Vec::new();
+            while let Type::Array(arr_ty) = ty {
+                nelements.push(arr_ty.nelement);
+                ty = &arr_ty.element_ty;
+            }
+            write_glsl_ty(f, ty)?;
+            for nelement in nelements {
+                match nelement {
+                    Some(nelement) => write!(f, "[{}]", nelement)?,
+                    None => f.write_str("[]")?,
+                }
+            }
+            Ok(())
+        }
+        Type::Struct(struct_ty) => {
+            if let Some(name) = struct_ty.name.as_ref() {
+                return f.write_str(name);
+            }
+            f.write_str("struct {")?;
+            for member in struct_ty.members.iter() {
+                f.write_str(" ")?;
+                write_glsl_ty(f, &member.ty)?;
+                if let Some(name) = member.name.as_ref() {
+                    write!(f, " {}", name)?;
+                }
+                f.write_str(";")?;
+            }
+            f.write_str(" }")
+        }
+        Type::AccelStruct(_) => f.write_str("accelerationStructureEXT"),
+        Type::DeviceAddress(_) => f.write_str("uint64_t"),
+        Type::DevicePointer(ptr_ty) => write_glsl_ty(f, &ptr_ty.pointee_ty),
+        Type::RayQuery(_) => f.write_str("rayQueryEXT"),
+        _ => fmt::Display::fmt(ty, f),
+    }
+}
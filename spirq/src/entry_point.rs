@@ -1,10 +1,292 @@
 //! Entry-point function record.
-use std::fmt;
+use std::{collections::BTreeMap, convert::TryFrom, fmt, ops::Range};
 
-use crate::{func::ExecutionMode, spirv, var::Variable};
+use crate::{
+    builtin::{is_builtin_allowed, BuiltinUsageIssue},
+    constant::{Constant, ConstantValue},
+    func::ExecutionMode,
+    locator::Locator,
+    spirv,
+    ty::Type,
+    value::ConstantValueExt,
+    var::{DescriptorBinding, InterfaceLocation, SpecId, Variable},
+};
 
 pub use spirv::ExecutionModel;
 
+/// Textual and numeric conversions of [`ExecutionModel`].
+pub trait ExecutionModelExt: Sized {
+    /// Parse an execution model from its SPIR-V name like `"Fragment"`, or a
+    /// shader stage abbreviation as used in GLSL file extensions like `"frag"`.
+    fn from_name(name: &str) -> Option<Self>;
+    /// SPIR-V name of the execution model. Ray tracing stages are named after
+    /// `SPV_KHR_ray_tracing`.
+    fn name(&self) -> &'static str;
+    /// SPIR-V enumerant value of the execution model.
+    fn to_u32(&self) -> u32;
+    /// `VkShaderStageFlagBits` of the execution model. `Kernel` has no
+    /// Vulkan counterpart and is mapped to zero.
+    fn vk_stage_flags(&self) -> u32;
+}
+impl ExecutionModelExt for ExecutionModel {
+    fn from_name(name: &str) -> Option<Self> {
+        let exec_model = match name {
+            "vert" => ExecutionModel::Vertex,
+            "tesc" => ExecutionModel::TessellationControl,
+            "tese" => ExecutionModel::TessellationEvaluation,
+            "geom" => ExecutionModel::Geometry,
+            "frag" => ExecutionModel::Fragment,
+            "comp" => ExecutionModel::GLCompute,
+            "task" => ExecutionModel::TaskEXT,
+            "mesh" => ExecutionModel::MeshEXT,
+            "rgen" => ExecutionModel::RayGenerationKHR,
+            "rint" => ExecutionModel::IntersectionKHR,
+            "rahit" => ExecutionModel::AnyHitKHR,
+            "rchit" => ExecutionModel::ClosestHitKHR,
+            "rmiss" => ExecutionModel::MissKHR,
+            "rcall" => ExecutionModel::CallableKHR,
+            _ => return name.parse().ok(),
+        };
+        Some(exec_model)
+    }
+    fn name(&self) -> &'static str {
+        match self {
+            ExecutionModel::Vertex => "Vertex",
+            ExecutionModel::TessellationControl => "TessellationControl",
+            ExecutionModel::TessellationEvaluation => "TessellationEvaluation",
+            ExecutionModel::Geometry => "Geometry",
+            ExecutionModel::Fragment => "Fragment",
+            ExecutionModel::GLCompute => "GLCompute",
+            ExecutionModel::Kernel => "Kernel",
+            ExecutionModel::TaskNV => "TaskNV",
+            ExecutionModel::MeshNV => "MeshNV",
+            ExecutionModel::RayGenerationNV => "RayGenerationKHR",
+            ExecutionModel::IntersectionNV => "IntersectionKHR",
+            ExecutionModel::AnyHitNV => "AnyHitKHR",
+            ExecutionModel::ClosestHitNV => "ClosestHitKHR",
+            ExecutionModel::MissNV => "MissKHR",
+            ExecutionModel::CallableNV => "CallableKHR",
+            ExecutionModel::TaskEXT => "TaskEXT",
+            ExecutionModel::MeshEXT => "MeshEXT",
+        }
+    }
+    fn to_u32(&self) -> u32 {
+        *self as u32
+    }
+    fn vk_stage_flags(&self) -> u32 {
+        vk_stage_flags(*self)
+    }
+}
+
+/// `VkShaderStageFlagBits` of an execution model. `Kernel` has no Vulkan
+/// counterpart and is mapped to zero.
+pub(crate) fn vk_stage_flags(exec_model: ExecutionModel) -> u32 {
+    match exec_model {
+        ExecutionModel::Vertex => 0x0000_0001,
+        ExecutionModel::TessellationControl => 0x0000_0002,
+        ExecutionModel::TessellationEvaluation => 0x0000_0004,
+        ExecutionModel::Geometry => 0x0000_0008,
+        ExecutionModel::Fragment => 0x0000_0010,
+        ExecutionModel::GLCompute => 0x0000_0020,
+        ExecutionModel::Kernel => 0,
+        ExecutionModel::TaskNV | ExecutionModel::TaskEXT => 0x0000_0040,
+        ExecutionModel::MeshNV | ExecutionModel::MeshEXT => 0x0000_0080,
+        ExecutionModel::RayGenerationNV => 0x0000_0100,
+        ExecutionModel::AnyHitNV => 0x0000_0200,
+        ExecutionModel::ClosestHitNV => 0x0000_0400,
+        ExecutionModel::MissNV => 0x0000_0800,
+        ExecutionModel::IntersectionNV => 0x0000_1000,
+        ExecutionModel::CallableNV => 0x0000_2000,
+    }
+}
+
+/// Push constant range used by an entry point, in the shape of
+/// `VkPushConstantRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PushConstantRange {
+    /// `VkShaderStageFlagBits` of the entry point's execution model.
+    pub stage_flags: u32,
+    /// Offset of the first byte accessed by the entry point.
+    pub offset: u32,
+    /// Number of bytes from `offset` to the end of the last member.
+    pub size: u32,
+}
+impl PushConstantRange {
+    /// Byte range covered by the push constant range.
+    pub fn byte_range(&self) -> Range<usize> {
+        let offset = self.offset as usize;
+        offset..(offset + self.size as usize)
+    }
+}
+
+/// Byte range covered by the members of a push constant block. Leading bytes
+/// before the first member, e.g., skipped with `[[vk::offset(16)]]` in HLSL,
+/// are not included.
+fn push_constant_byte_range(ty: &Type) -> Option<Range<usize>> {
+    match ty {
+        Type::Struct(struct_ty) => {
+            let mut range: Option<Range<usize>> = None;
+            for member in struct_ty.members.iter() {
+                let offset = member.offset.unwrap_or_default();
+                let end = offset + member.ty.nbyte()?;
+                range = Some(match range {
+                    Some(range) => range.start.min(offset)..range.end.max(end),
+                    None => offset..end,
+                });
+            }
+            range
+        }
+        _ => Some(0..ty.nbyte()?),
+    }
+}
+
+/// Primitive topology consumed or produced by a tessellation, geometry or
+/// mesh shader, as declared by execution modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveTopology {
+    Points,
+    Lines,
+    LineStrip,
+    LinesAdjacency,
+    Triangles,
+    TriangleStrip,
+    TrianglesAdjacency,
+    Quads,
+    Isolines,
+}
+/// Spacing of the tessellated segments along the edges of a patch, as
+/// declared by the `SpacingEqual`, `SpacingFractionalEven` and
+/// `SpacingFractionalOdd` execution modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TessellationSpacing {
+    Equal,
+    FractionalEven,
+    FractionalOdd,
+}
+
+/// Subgroup requirements of an entry point, as declared by execution modes
+/// and implied by the subgroup built-ins it uses.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubgroupRequirements {
+    /// Whether the entry point uses any subgroup built-in like
+    /// `SubgroupLocalInvocationId` or `SubgroupEqMask`.
+    pub uses_subgroup_builtins: bool,
+    /// Subgroup size required by the `SubgroupSize` execution mode.
+    pub subgroup_size: Option<u32>,
+    /// Number of subgroups per workgroup required by the
+    /// `SubgroupsPerWorkgroup` or `SubgroupsPerWorkgroupId` execution mode.
+    pub subgroups_per_workgroup: Option<u32>,
+    /// Whether the entry point is declared with
+    /// `SubgroupUniformControlFlowKHR`, i.e., it requires subgroup operations
+    /// in uniform control flow to be executed by the entire subgroup.
+    pub uniform_control_flow: bool,
+}
+
+/// Transform feedback capture of an output variable, as decorated by
+/// `XfbBuffer`, `XfbStride` and `Offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XfbInfo {
+    /// Transform feedback buffer the output is captured to.
+    pub buffer: u32,
+    /// Stride between the captured vertices in the buffer, in bytes.
+    pub stride: u32,
+    /// Offset of the output in each captured vertex, in bytes. For output
+    /// blocks without an offset of their own, the smallest offset of the
+    /// members; the offsets of individual members are kept in the block type.
+    pub offset: u32,
+}
+
+fn is_subgroup_builtin(builtin: spirv::BuiltIn) -> bool {
+    use spirv::BuiltIn::*;
+    matches!(
+        builtin,
+        SubgroupSize
+            | SubgroupLocalInvocationId
+            | NumSubgroups
+            | SubgroupId
+            | SubgroupEqMask
+            | SubgroupGeMask
+            | SubgroupGtMask
+            | SubgroupLeMask
+            | SubgroupLtMask
+            | SubgroupMaxSize
+            | NumEnqueuedSubgroups
+    )
+}
+
+/// Value of an execution mode operand, if it's an integer representable in
+/// 32 bits.
+fn exec_mode_operand_u32(operand: &Constant) -> Option<u32> {
+    u32::try_from(operand.value.to_u64()?).ok()
+}
+
+/// Reflection results of an entry point other than its variables and
+/// execution modes. New kinds of results are added here without breaking
+/// code that builds [`EntryPoint`]s by hand, which can fill this in with
+/// `Default::default()`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct EntryPointInfo {
+    /// Locations of mesh shader outputs decorated with `PerPrimitiveEXT`.
+    /// Outputs not listed here are per-vertex.
+    pub per_primitive_outputs: Vec<InterfaceLocation>,
+    /// Outputs decorated with `Index` and their indices. In dual-source
+    /// blending, fragment shader outputs at a same location are told apart by
+    /// the index, i.e., the blend equation input they are bound to. Outputs
+    /// not listed here have index 0.
+    pub output_indices: Vec<(Variable, u32)>,
+    /// Locations and transform feedback captures of outputs decorated with
+    /// `XfbBuffer` and `XfbStride`, in the order they are listed in `vars`.
+    pub xfb_outputs: Vec<(InterfaceLocation, XfbInfo)>,
+    /// Variables decorated with `RelaxedPrecision`, i.e., declared `mediump`
+    /// or `lowp` in GLSL or `min16float` in HLSL. Only the decorations on the
+    /// variables themselves are recorded; decorated struct members are not.
+    pub relaxed_precision_vars: Vec<Locator>,
+    /// Binding points of descriptors declared as arrays of descriptors, so
+    /// called binding arrays. A binding array of a single element is still a
+    /// binding array, although its `nbind` is indistinguishable from a plain
+    /// descriptor.
+    pub binding_arrays: Vec<DescriptorBinding>,
+    /// Binding points of descriptors indexed non-uniformly, i.e., by access
+    /// chains or indices decorated `NonUniform` as by `nonuniformEXT` in
+    /// GLSL or `NonUniformResourceIndex` in HLSL. Such descriptors require
+    /// the `*ArrayNonUniformIndexing` features in
+    /// `VK_EXT_descriptor_indexing`.
+    pub nonuniform_indexed: Vec<DescriptorBinding>,
+    /// Binding points of storage buffers, storage images and texel buffers
+    /// accessed by atomic instructions, either directly or through
+    /// `OpImageTexelPointer`. Such accesses usually call for stronger memory
+    /// barriers.
+    pub atomic_accessed: Vec<DescriptorBinding>,
+    /// Total size in bytes of `Workgroup` variables, see
+    /// [`EntryPoint::shared_memory_bytes`].
+    pub shared_memory_bytes: Option<usize>,
+    /// HLSL semantics of interface variables, like `"POSITION"` and
+    /// `"SV_Target0"`, as decorated by `UserSemantic` when DXC compiles with
+    /// `-fspv-reflect`.
+    pub user_semantics: Vec<(Locator, String)>,
+    /// Default values of specialization constants as declared by
+    /// `OpSpecConstant`, `OpSpecConstantTrue` and `OpSpecConstantFalse`,
+    /// sorted by specialization constant ID. Values given by
+    /// [`ReflectConfig::specialize`] don't change the defaults.
+    ///
+    /// [`ReflectConfig::specialize`]: crate::ReflectConfig::specialize
+    pub spec_defaults: Vec<(SpecId, ConstantValue)>,
+    /// Workgroup size of a compute, task or mesh shader, see
+    /// [`EntryPoint::local_size`].
+    pub local_size: Option<[Constant; 3]>,
+    /// Built-in input and output variables, and built-in members of interface
+    /// blocks like `gl_PerVertex`.
+    pub builtins: Vec<(spirv::StorageClass, spirv::BuiltIn)>,
+    /// Built-in members of interface blocks like `gl_PerVertex`, by the
+    /// storage class of the block and the member index, e.g., to skip
+    /// built-in members when laying out transform feedback buffers.
+    pub builtin_members: Vec<(spirv::StorageClass, u32, spirv::BuiltIn)>,
+    /// IDs of the variables in the interface operands of `OpEntryPoint`,
+    /// see [`EntryPoint::interface_var_ids`].
+    pub interface_var_ids: Vec<u32>,
+}
+
 /// Representing an entry point described in a SPIR-V.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct EntryPoint {
@@ -22,6 +304,405 @@ pub struct EntryPoint {
     /// compute shader local sizes and specialization constant IDs of local
     /// sizes.
     pub exec_modes: Vec<ExecutionMode>,
+    /// Reflection results of the entry point other than its variables and
+    /// execution modes, mostly looked up with the methods of `EntryPoint`.
+    pub info: EntryPointInfo,
+}
+impl EntryPoint {
+    /// IDs of the variables in the interface operands of `OpEntryPoint`, as
+    /// they are declared. Since SPIR-V 1.4, all global variables statically
+    /// used by the entry point are listed, including descriptors and built-in
+    /// variables; before that, only inputs and outputs are listed.
+    pub fn interface_var_ids(&self) -> &[u32] {
+        &self.info.interface_var_ids
+    }
+    /// Returns true if the output at `location` is written per-primitive
+    /// rather than per-vertex in a mesh shader.
+    pub fn is_per_primitive(&self, location: InterfaceLocation) -> bool {
+        self.info.per_primitive_outputs.contains(&location)
+    }
+    /// Transform feedback capture of an output variable. Returns `None` if
+    /// `var` is not an output or is not captured.
+    pub fn xfb(&self, var: &Variable) -> Option<XfbInfo> {
+        match var {
+            Variable::Output { location, .. } => self
+                .info
+                .xfb_outputs
+                .iter()
+                .find(|(x, _)| x == location)
+                .map(|(_, xfb)| *xfb),
+            _ => None,
+        }
+    }
+    /// Fragment shader outputs to be written to color attachments, and their
+    /// indices in dual-source blending, sorted by location and index.
+    /// Built-in outputs like `FragDepth` and `SampleMask` are not included.
+    /// Returns an empty list for other execution models.
+    pub fn color_outputs(&self) -> Vec<(&Variable, u32)> {
+        if self.exec_model != spirv::ExecutionModel::Fragment {
+            return Vec::new();
+        }
+        // Outputs are matched by value rather than by position, so that
+        // `vars` can be filtered or reordered. Identical outputs are
+        // indistinguishable, so each index is taken once.
+        let mut indices = self.info.output_indices.iter().collect::<Vec<_>>();
+        let mut out = self
+            .vars
+            .iter()
+            .filter_map(|var| match var {
+                Variable::Output { location, .. } => {
+                    let index = indices
+                        .iter()
+                        .position(|(x, _)| x == var)
+                        .map_or(0, |i| indices.remove(i).1);
+                    Some((var, *location, index))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        out.sort_by_key(|(_, location, index)| (location.loc(), *index, location.comp()));
+        out.into_iter()
+            .map(|(var, _, index)| (var, index))
+            .collect()
+    }
+    /// Returns true if `var` is allowed to be computed in relaxed precision.
+    pub fn is_relaxed_precision(&self, var: &Variable) -> bool {
+        self.info
+            .relaxed_precision_vars
+            .contains(&Locator::from(var))
+    }
+    /// Returns true if `var` is a descriptor declared as an array of
+    /// descriptors, rather than a single descriptor of an array type.
+    pub fn is_binding_array(&self, var: &Variable) -> bool {
+        match var {
+            Variable::Descriptor { desc_bind, .. } => self.info.binding_arrays.contains(desc_bind),
+            _ => false,
+        }
+    }
+    /// Returns true if `var` is a descriptor indexed non-uniformly by the
+    /// entry point.
+    pub fn is_nonuniform_indexed(&self, var: &Variable) -> bool {
+        match var {
+            Variable::Descriptor { desc_bind, .. } => {
+                self.info.nonuniform_indexed.contains(desc_bind)
+            }
+            _ => false,
+        }
+    }
+    /// Returns true if `var` is a descriptor accessed by atomic instructions
+    /// in the entry point.
+    pub fn is_atomic_accessed(&self, var: &Variable) -> bool {
+        match var {
+            Variable::Descriptor { desc_bind, .. } => self.info.atomic_accessed.contains(desc_bind),
+            _ => false,
+        }
+    }
+    /// Returns true if `var` is a binding array whose size is only known at
+    /// runtime. Such descriptors require `RuntimeDescriptorArrayEXT` and a
+    /// variable descriptor count in `VK_EXT_descriptor_indexing`.
+    pub fn is_runtime_binding_array(&self, var: &Variable) -> bool {
+        matches!(var, Variable::Descriptor { nbind: 0, .. }) && self.is_binding_array(var)
+    }
+    /// Compiled-in default value of the specialization constant at
+    /// `spec_id`. Returns `None` if no scalar specialization constant is
+    /// declared with the ID; composite specialization constants don't have
+    /// IDs of their own.
+    pub fn spec_default(&self, spec_id: SpecId) -> Option<&ConstantValue> {
+        self.info
+            .spec_defaults
+            .iter()
+            .find(|(x, _)| *x == spec_id)
+            .map(|(_, value)| value)
+    }
+    /// HLSL semantic of an input or output variable. Returns `None` if the
+    /// variable is not decorated with `UserSemantic`.
+    pub fn semantic(&self, var: &Variable) -> Option<&str> {
+        let locator = Locator::from(var);
+        self.info
+            .user_semantics
+            .iter()
+            .find(|(x, _)| *x == locator)
+            .map(|(_, semantic)| semantic.as_str())
+    }
+    /// Built-in of the member at `member_idx` of the interface block in
+    /// `store_cls`, e.g., `Position` for member 0 of the `gl_PerVertex`
+    /// output block. Returns `None` if the member is not a built-in.
+    pub fn member_builtin(
+        &self,
+        store_cls: spirv::StorageClass,
+        member_idx: u32,
+    ) -> Option<spirv::BuiltIn> {
+        self.info
+            .builtin_members
+            .iter()
+            .find(|(x, y, _)| *x == store_cls && *y == member_idx)
+            .map(|(_, _, builtin)| *builtin)
+    }
+    /// Total size in bytes of `Workgroup` variables, i.e., `shared` variables
+    /// in GLSL and `groupshared` in HLSL, used by this entry point. Returns
+    /// `None` if any of the sizes depends on a specialization constant not
+    /// specialized, unless [`ReflectConfig::assume_spec_default`] is set.
+    ///
+    /// [`ReflectConfig::assume_spec_default`]: crate::ReflectConfig::assume_spec_default
+    pub fn shared_memory_bytes(&self) -> Option<usize> {
+        self.info.shared_memory_bytes
+    }
+    /// Workgroup size `[x, y, z]` of a compute, task or mesh shader, taken from
+    /// the constant decorated with `BuiltIn WorkgroupSize` if present, or the
+    /// `LocalSize` or `LocalSizeId` execution mode otherwise. Components
+    /// backed by specialization constants have `spec_id` set unless they are
+    /// specialized, in which case they carry the specialized value. Returns
+    /// `None` for other execution models or if the workgroup size is not
+    /// declared.
+    pub fn local_size(&self) -> Option<&[Constant; 3]> {
+        self.info.local_size.as_ref()
+    }
+    /// Values of [`EntryPoint::local_size`]. Components backed by
+    /// specialization constants not specialized are reported with their
+    /// default values.
+    pub fn local_size_u32(&self) -> Option<[u32; 3]> {
+        let [x, y, z] = self.info.local_size.as_ref()?;
+        Some([
+            exec_mode_operand_u32(x)?,
+            exec_mode_operand_u32(y)?,
+            exec_mode_operand_u32(z)?,
+        ])
+    }
+    /// Specialization constant IDs of the components of
+    /// [`EntryPoint::local_size`], either by `LocalSizeId` or a `BuiltIn
+    /// WorkgroupSize` composite of specialization constants. `None` for
+    /// components of literal sizes or constants, components specialized by
+    /// [`ReflectConfig::specialize`], and entry points without a workgroup
+    /// size.
+    ///
+    /// [`ReflectConfig::specialize`]: crate::ReflectConfig::specialize
+    pub fn workgroup_size_spec_ids(&self) -> [Option<SpecId>; 3] {
+        match &self.info.local_size {
+            Some([x, y, z]) => [x.spec_id, y.spec_id, z.spec_id],
+            None => [None; 3],
+        }
+    }
+    /// First operand of the execution mode `exec_mode` as an integer.
+    fn exec_mode_u32(&self, exec_mode: spirv::ExecutionMode) -> Option<u32> {
+        self.exec_modes
+            .iter()
+            .find(|x| x.exec_mode == exec_mode)
+            .and_then(|x| x.operands.first())
+            .and_then(exec_mode_operand_u32)
+    }
+    /// Maximum number of vertices a geometry shader emits, the number of
+    /// vertices in the output patch of a tessellation control shader, or the
+    /// maximum number of vertices a mesh shader outputs, as declared by
+    /// `OutputVertices`.
+    pub fn output_vertices(&self) -> Option<u32> {
+        self.exec_mode_u32(spirv::ExecutionMode::OutputVertices)
+    }
+    /// Number of times a geometry shader is invoked for each input primitive,
+    /// as declared by `Invocations`.
+    pub fn invocations(&self) -> Option<u32> {
+        self.exec_mode_u32(spirv::ExecutionMode::Invocations)
+    }
+    /// Returns true if the origin of `FragCoord` is the upper-left corner of
+    /// the framebuffer, i.e., the fragment shader is declared with
+    /// `OriginUpperLeft` as is required by Vulkan.
+    pub fn origin_upper_left(&self) -> bool {
+        self.exec_modes
+            .iter()
+            .any(|x| x.exec_mode == spirv::ExecutionMode::OriginUpperLeft)
+    }
+    /// Input primitive topology of a geometry shader, i.e., `InputPoints`,
+    /// `InputLines`, `InputLinesAdjacency`, `Triangles` or
+    /// `InputTrianglesAdjacency`; or the patch domain of a tessellation
+    /// shader, i.e., `Triangles`, `Quads` or `Isolines`.
+    pub fn primitive_topology(&self) -> Option<PrimitiveTopology> {
+        use spirv::ExecutionMode::*;
+        self.exec_modes.iter().find_map(|x| {
+            let topology = match x.exec_mode {
+                InputPoints => PrimitiveTopology::Points,
+                InputLines => PrimitiveTopology::Lines,
+                InputLinesAdjacency => PrimitiveTopology::LinesAdjacency,
+                Triangles => PrimitiveTopology::Triangles,
+                InputTrianglesAdjacency => PrimitiveTopology::TrianglesAdjacency,
+                Quads => PrimitiveTopology::Quads,
+                Isolines => PrimitiveTopology::Isolines,
+                _ => return None,
+            };
+            Some(topology)
+        })
+    }
+    /// Output primitive topology of a geometry shader, i.e., `OutputPoints`,
+    /// `OutputLineStrip` or `OutputTriangleStrip`; or of a mesh shader, i.e.,
+    /// `OutputPoints`, `OutputLinesEXT` or `OutputTrianglesEXT`. For a
+    /// tessellation evaluation shader, the topology of the generated
+    /// primitives is reported, i.e., points in `PointMode`, otherwise lines
+    /// for isolines and triangles for the other domains.
+    pub fn output_topology(&self) -> Option<PrimitiveTopology> {
+        use spirv::ExecutionMode::*;
+        if self.exec_model == ExecutionModel::TessellationEvaluation {
+            if self.exec_modes.iter().any(|x| x.exec_mode == PointMode) {
+                return Some(PrimitiveTopology::Points);
+            }
+            let topology = match self.primitive_topology()? {
+                PrimitiveTopology::Isolines => PrimitiveTopology::Lines,
+                _ => PrimitiveTopology::Triangles,
+            };
+            return Some(topology);
+        }
+        self.exec_modes.iter().find_map(|x| {
+            let topology = match x.exec_mode {
+                OutputPoints => PrimitiveTopology::Points,
+                OutputLineStrip => PrimitiveTopology::LineStrip,
+                OutputTriangleStrip => PrimitiveTopology::TriangleStrip,
+                // `OutputLinesEXT` and `OutputTrianglesEXT` alias these.
+                OutputLinesNV => PrimitiveTopology::Lines,
+                OutputTrianglesNV => PrimitiveTopology::Triangles,
+                _ => return None,
+            };
+            Some(topology)
+        })
+    }
+    /// Spacing of tessellated segments declared by a tessellation shader.
+    pub fn tessellation_spacing(&self) -> Option<TessellationSpacing> {
+        use spirv::ExecutionMode::*;
+        self.exec_modes.iter().find_map(|x| {
+            let spacing = match x.exec_mode {
+                SpacingEqual => TessellationSpacing::Equal,
+                SpacingFractionalEven => TessellationSpacing::FractionalEven,
+                SpacingFractionalOdd => TessellationSpacing::FractionalOdd,
+                _ => return None,
+            };
+            Some(spacing)
+        })
+    }
+    /// Subgroup-related execution modes and built-in usage of this entry
+    /// point. Sizes given by specialization constants not specialized are
+    /// reported with their default values.
+    pub fn subgroup_requirements(&self) -> SubgroupRequirements {
+        let mut out = SubgroupRequirements {
+            uses_subgroup_builtins: self
+                .info
+                .builtins
+                .iter()
+                .any(|(_, builtin)| is_subgroup_builtin(*builtin)),
+            ..Default::default()
+        };
+        for exec_mode in self.exec_modes.iter() {
+            let operand = exec_mode.operands.first();
+            match exec_mode.exec_mode {
+                spirv::ExecutionMode::SubgroupSize => {
+                    out.subgroup_size = operand.and_then(exec_mode_operand_u32);
+                }
+                spirv::ExecutionMode::SubgroupsPerWorkgroup
+                | spirv::ExecutionMode::SubgroupsPerWorkgroupId => {
+                    out.subgroups_per_workgroup = operand.and_then(exec_mode_operand_u32);
+                }
+                spirv::ExecutionMode::SubgroupUniformControlFlowKHR => {
+                    out.uniform_control_flow = true;
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+    /// Byte range `[min_offset, max_offset + size)` covered by the push
+    /// constant blocks used by this entry point. Returns `None` if there is
+    /// no push constant block or the block is not sized.
+    pub fn push_constant_byte_range(&self) -> Option<Range<usize>> {
+        let mut range: Option<Range<usize>> = None;
+        for var in self.vars.iter() {
+            if let Variable::PushConstant { ty, .. } = var {
+                let x = push_constant_byte_range(ty)?;
+                range = Some(match range {
+                    Some(range) => range.start.min(x.start)..range.end.max(x.end),
+                    None => x,
+                });
+            }
+        }
+        range
+    }
+    /// `VkShaderStageFlagBits` of the entry point's execution model, e.g.,
+    /// for `VkPushConstantRange::stageFlags` and
+    /// `VkPipelineShaderStageCreateInfo::stage`.
+    pub fn vk_stage_flags(&self) -> u32 {
+        vk_stage_flags(self.exec_model)
+    }
+    /// Push constant range to be declared in a pipeline layout for this entry
+    /// point, with stage flags implied by `exec_model`.
+    pub fn push_constant_range(&self) -> Option<PushConstantRange> {
+        let range = self.push_constant_byte_range()?;
+        let push_const_rng = PushConstantRange {
+            stage_flags: vk_stage_flags(self.exec_model),
+            offset: range.start as u32,
+            size: (range.end - range.start) as u32,
+        };
+        Some(push_const_rng)
+    }
+    /// Built-ins used in execution models or storage classes they are not
+    /// allowed in. Built-ins not covered by [`is_builtin_allowed`] are not
+    /// checked.
+    pub fn validate_builtin_usage(&self) -> Vec<BuiltinUsageIssue> {
+        self.info
+            .builtins
+            .iter()
+            .filter(|(store_cls, builtin)| {
+                is_builtin_allowed(self.exec_model, *store_cls, *builtin) == Some(false)
+            })
+            .map(|(store_cls, builtin)| BuiltinUsageIssue {
+                exec_model: self.exec_model,
+                store_cls: *store_cls,
+                builtin: *builtin,
+            })
+            .collect()
+    }
+    /// Descriptor resources used by this entry point, in the order they are
+    /// listed in `vars`.
+    pub fn descriptors(&self) -> impl Iterator<Item = &Variable> {
+        self.vars
+            .iter()
+            .filter(|x| matches!(x, Variable::Descriptor { .. }))
+    }
+    /// Descriptor resources grouped by descriptor set, each group sorted by
+    /// binding. Descriptors aliasing a same binding point are kept in the
+    /// order they are listed in `vars`.
+    pub fn descriptors_by_set(&self) -> BTreeMap<u32, Vec<&Variable>> {
+        let mut descs = self
+            .vars
+            .iter()
+            .filter_map(|var| match var {
+                Variable::Descriptor { desc_bind, .. } => Some((*desc_bind, var)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        descs.sort_by_key(|(desc_bind, _)| *desc_bind);
+        let mut out = BTreeMap::<u32, Vec<&Variable>>::new();
+        for (desc_bind, var) in descs {
+            out.entry(desc_bind.set()).or_default().push(var);
+        }
+        out
+    }
+    /// Get the base type of an output variable and the size of the array it's
+    /// wrapped in. Mesh shader outputs are always arrays of per-vertex or
+    /// per-primitive data, so the outermost array is unwrapped and its length
+    /// is returned along with its element type. For other execution models the
+    /// output type is returned as-is with no array size.
+    ///
+    /// Returns `None` if `var` is not an output variable.
+    pub fn output_base_ty<'b>(&self, var: &'b Variable) -> Option<(&'b Type, Option<u32>)> {
+        let ty = match var {
+            Variable::Output { ty, .. } => ty,
+            _ => return None,
+        };
+        match self.exec_model {
+            spirv::ExecutionModel::MeshEXT | spirv::ExecutionModel::MeshNV => {
+                if let Type::Array(arr_ty) = ty {
+                    Some((&*arr_ty.element_ty, arr_ty.nelement))
+                } else {
+                    Some((ty, None))
+                }
+            }
+            _ => Some((ty, None)),
+        }
+    }
 }
 impl fmt::Debug for EntryPoint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -30,6 +711,7 @@ impl fmt::Debug for EntryPoint {
             .field("name", &self.name)
             .field("vars", &self.vars)
             .field("exec_modes", &self.exec_modes)
+            .field("info", &self.info)
             .finish()
     }
 }
@@ -1,4 +1,6 @@
 //! Inspect SPIR-V function parsing.
+use std::ops::ControlFlow;
+
 use crate::{error::Result, parse::Instr, reflect::ReflectIntermediate};
 
 pub trait Inspector {
@@ -6,6 +8,14 @@ pub trait Inspector {
     /// the instruction after the reflector finishes processing it.
     fn inspect<'a>(&mut self, itm: &mut ReflectIntermediate<'a>, instr: &Instr) -> Result<()>;
 
+    /// Whether the inspector has seen enough of the module. Function parsing
+    /// stops right after an instruction is inspected if this returns true,
+    /// so the function being parsed and all the following ones are left
+    /// unreflected. By default, the entire module is parsed.
+    fn is_done(&self) -> bool {
+        false
+    }
+
     /// Chain two inspectors together. The second inspector will be called after
     /// the first one.
     fn chain<'a, I2: Inspector>(&'a mut self, second: &'a mut I2) -> Chain<Self, I2>
@@ -27,6 +37,27 @@ impl<F: FnMut(&mut ReflectIntermediate<'_>, &Instr)> Inspector for FnInspector<F
     }
 }
 
+/// Inspector that calls a function wrapped up in it until the function
+/// returns `ControlFlow::Break`.
+pub(crate) struct TryFnInspector<F: FnMut(&mut ReflectIntermediate<'_>, &Instr) -> ControlFlow<()>>
+{
+    pub f: F,
+    pub is_done: bool,
+}
+impl<F: FnMut(&mut ReflectIntermediate<'_>, &Instr) -> ControlFlow<()>> Inspector
+    for TryFnInspector<F>
+{
+    fn inspect<'a>(&mut self, itm: &mut ReflectIntermediate<'a>, instr: &Instr) -> Result<()> {
+        if (self.f)(itm, instr).is_break() {
+            self.is_done = true;
+        }
+        Ok(())
+    }
+    fn is_done(&self) -> bool {
+        self.is_done
+    }
+}
+
 pub struct Chain<'a, I1: Inspector, I2: Inspector> {
     first: &'a mut I1,
     second: &'a mut I2,
@@ -36,4 +67,7 @@ impl<I1: Inspector, I2: Inspector> Inspector for Chain<'_, I1, I2> {
         self.first.inspect(itm, instr)?;
         self.second.inspect(itm, instr)
     }
+    fn is_done(&self) -> bool {
+        self.first.is_done() || self.second.is_done()
+    }
 }
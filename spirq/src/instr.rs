@@ -48,6 +48,15 @@ macro_rules! define_ops {
 
 // Be aware that the order of the read methods is important.
 define_ops! {
+    // Capabilities unknown to `spirv` are kept as raw words.
+    OpCapability {
+        capability: u32 = read_u32(),
+    }
+
+    OpExtension {
+        name: &'a str = read_str(),
+    }
+
     OpExtInstImport {
         instr_set_id: InstrId = read_u32(),
         name: &'a str = read_str(),
@@ -62,6 +71,7 @@ define_ops! {
         exec_model: ExecutionModel = read_enum(),
         func_id: FunctionId = read_u32(),
         name: &'a str = read_str(),
+        interface_var_ids: &'a [u32] = read_list(),
     }
 
     OpExecutionModeCommonSPQ {
@@ -70,6 +80,11 @@ define_ops! {
         params: &'a [u32] = read_list(),
     }
 
+    OpString {
+        string_id: InstrId = read_u32(),
+        string: &'a str = read_str(),
+    }
+
     OpName {
         target_id: InstrId = read_u32(),
         name: &'a str = read_str(),
@@ -91,6 +106,12 @@ define_ops! {
         deco: Decoration = read_enum(),
         params: &'a [u32] = read_list(),
     }
+    // String decorations unknown to `spirv` are kept as raw words.
+    OpDecorateString {
+        target_id: InstrId = read_u32(),
+        deco: u32 = read_u32(),
+        value: &'a str = read_str(),
+    }
 
     OpTypeVoid {
         ty_id: TypeId = read_u32(),
@@ -197,10 +218,15 @@ define_ops! {
         return_ty_id: TypeId = read_u32(),
         func_id: TypeId = read_u32(),
     }
+    OpFunctionParameter {
+        _param_ty_id: TypeId = read_u32(),
+        param_id: VariableId = read_u32(),
+    }
     OpFunctionCall {
         return_ty_id: TypeId = read_u32(),
         return_id: InstrId = read_u32(),
         func_id: FunctionId = read_u32(),
+        arg_ids: &'a [InstrId] = read_list(),
     }
     OpLoad {
         return_ty_id: TypeId = read_u32(),
@@ -215,6 +241,26 @@ define_ops! {
         var_id: VariableId = read_u32(),
         accessed_var_id: VariableId = read_u32(),
     }
+    // `OpCopyObject`, `OpCopyLogical` and `OpBitcast`.
+    OpCopyObjectCommonSPQ {
+        _var_ty_id: TypeId = read_u32(),
+        var_id: VariableId = read_u32(),
+        copied_var_id: VariableId = read_u32(),
+    }
+    OpImageTexelPointer {
+        _texel_ptr_ty_id: TypeId = read_u32(),
+        texel_ptr_id: VariableId = read_u32(),
+        image_var_id: VariableId = read_u32(),
+    }
+    // `OpImageRead` and `OpImageSparseRead`.
+    OpImageReadCommonSPQ {
+        _texel_ty_id: TypeId = read_u32(),
+        _texel_id: InstrId = read_u32(),
+        image_id: InstrId = read_u32(),
+    }
+    OpImageWrite {
+        image_id: InstrId = read_u32(),
+    }
     OpTypeAccelerationStructureKHR {
         ty_id: TypeId = read_u32(),
     }
@@ -0,0 +1,84 @@
+//! Structural comparison and opcode scans of SPIR-V instructions.
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    parse::{Instr, Instrs},
+    spirv::Op,
+};
+
+/// Structural equality of instructions, e.g., to deduplicate decorations or
+/// types when linking modules. Two instructions are equal if they have the
+/// same opcode and the same operand words; the word count encoded in the
+/// first word is implied by the operands.
+pub trait InstrExt {
+    /// Returns true if `self` and `other` are structurally identical.
+    fn normalized_eq(&self, other: &Instr) -> bool;
+    /// Key that compares and hashes structurally, so that instructions can be
+    /// deduplicated in a `HashSet` or used as `HashMap` keys.
+    fn normalized(&self) -> NormalizedInstr<'_>;
+}
+impl InstrExt for Instr {
+    fn normalized_eq(&self, other: &Instr) -> bool {
+        self.normalized() == other.normalized()
+    }
+    fn normalized(&self) -> NormalizedInstr<'_> {
+        NormalizedInstr(self)
+    }
+}
+
+/// An instruction compared and hashed by its opcode and operands.
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizedInstr<'a>(pub &'a Instr);
+impl<'a> NormalizedInstr<'a> {
+    fn operand_words(&self) -> &'a [u32] {
+        &self.0.as_ref()[1..]
+    }
+}
+impl PartialEq for NormalizedInstr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.opcode() == other.0.opcode() && self.operand_words() == other.operand_words()
+    }
+}
+impl Eq for NormalizedInstr<'_> {}
+impl Hash for NormalizedInstr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.opcode().hash(state);
+        self.operand_words().hash(state);
+    }
+}
+
+/// Opcode scans over [`Instrs`], e.g., to find all `OpEntryPoint`s without
+/// matching on every instruction.
+pub trait InstrsExt<'a> {
+    /// Iterate over the instructions of opcode `op`. Iteration stops at the
+    /// first malformed instruction.
+    fn filter_op(self, op: Op) -> FilterOp<'a>;
+    /// The first instruction of opcode `op`.
+    fn find_first(self, op: Op) -> Option<&'a Instr>;
+}
+impl<'a> InstrsExt<'a> for Instrs<'a> {
+    fn filter_op(self, op: Op) -> FilterOp<'a> {
+        FilterOp { instrs: self, op }
+    }
+    fn find_first(self, op: Op) -> Option<&'a Instr> {
+        self.filter_op(op).next()
+    }
+}
+
+/// Iterator over the instructions of an opcode. See [`InstrsExt::filter_op`].
+pub struct FilterOp<'a> {
+    instrs: Instrs<'a>,
+    op: Op,
+}
+impl<'a> Iterator for FilterOp<'a> {
+    type Item = &'a Instr;
+    fn next(&mut self) -> Option<Self::Item> {
+        // Compare raw opcodes because `Instr::op` panics on unknown ones.
+        while let Ok(Some(instr)) = self.instrs.next() {
+            if instr.opcode() == self.op as u32 {
+                return Some(instr);
+            }
+        }
+        None
+    }
+}
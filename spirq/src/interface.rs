@@ -0,0 +1,106 @@
+//! Convenience queries on input and output variables.
+use crate::{
+    ty::{ScalarType, Type},
+    var::{InterfaceLocation, Variable},
+};
+
+/// Number of 32-bit components a scalar consumes. 64-bit scalars consume two
+/// components; narrower scalars are padded to 32 bits.
+fn ncomp_of_scalar(scalar_ty: &ScalarType) -> Option<u32> {
+    match scalar_ty {
+        ScalarType::Integer { bits: 64, .. } | ScalarType::Float { bits: 64 } => Some(2),
+        ScalarType::Integer { .. } | ScalarType::Float { .. } | ScalarType::Boolean => Some(1),
+        _ => None,
+    }
+}
+/// Append `ncomp` components starting at `location` and `component`,
+/// continuing to the next locations if a location is filled. Returns the
+/// number of locations spanned.
+fn push_comps(location: u32, component: u32, ncomp: u32, out: &mut Vec<InterfaceLocation>) -> u32 {
+    for i in 0..ncomp {
+        let comp = component + i;
+        out.push(InterfaceLocation::new(location + comp / 4, comp % 4));
+    }
+    (component + ncomp).div_ceil(4)
+}
+/// Append the slots consumed by a value of type `ty` at `location` and
+/// `component` to `out`, and return the number of locations consumed.
+fn push_consumed_locations(
+    location: u32,
+    component: u32,
+    ty: &Type,
+    out: &mut Vec<InterfaceLocation>,
+) -> Option<u32> {
+    let nlocation = match ty {
+        Type::Scalar(scalar_ty) => {
+            push_comps(location, component, ncomp_of_scalar(scalar_ty)?, out)
+        }
+        Type::Vector(vector_ty) => {
+            let ncomp = ncomp_of_scalar(&vector_ty.scalar_ty)? * vector_ty.nscalar;
+            push_comps(location, component, ncomp, out)
+        }
+        Type::Matrix(matrix_ty) => {
+            let column_ty = Type::Vector(matrix_ty.vector_ty.clone());
+            let mut nlocation = 0;
+            for _ in 0..matrix_ty.nvector {
+                nlocation += push_consumed_locations(location + nlocation, 0, &column_ty, out)?;
+            }
+            nlocation
+        }
+        Type::Array(arr_ty) => {
+            let mut nlocation = 0;
+            for _ in 0..arr_ty.nelement? {
+                nlocation += push_consumed_locations(
+                    location + nlocation,
+                    component,
+                    &arr_ty.element_ty,
+                    out,
+                )?;
+            }
+            nlocation
+        }
+        Type::Struct(struct_ty) => {
+            let mut nlocation = 0;
+            for member in struct_ty.members.iter() {
+                nlocation += push_consumed_locations(location + nlocation, 0, &member.ty, out)?;
+            }
+            nlocation
+        }
+        _ => return None,
+    };
+    Some(nlocation)
+}
+
+/// Location assignment of [`Variable::Input`] and [`Variable::Output`], as
+/// specified in section *Location Assignment* of the Vulkan specification.
+pub trait InterfaceVariableExt {
+    /// All `(location, component)` slots occupied by the variable, in the
+    /// order they are consumed. 64-bit scalars consume two components and
+    /// 64-bit vectors of more than two components spill into the next
+    /// location; matrices consume one location per column, arrays one or
+    /// more per element, and struct members start at new locations.
+    ///
+    /// Arrayed inputs and outputs of tessellation, geometry and mesh shaders
+    /// are considered arrays here, so their outermost array should be
+    /// unwrapped beforehand, e.g., with [`EntryPoint::output_base_ty`].
+    /// Returns an empty list if the variable is not an input or output, or
+    /// its type cannot be located, e.g., a runtime-sized array.
+    ///
+    /// [`EntryPoint::output_base_ty`]: crate::entry_point::EntryPoint::output_base_ty
+    fn consumed_locations(&self) -> Vec<InterfaceLocation>;
+}
+impl InterfaceVariableExt for Variable {
+    fn consumed_locations(&self) -> Vec<InterfaceLocation> {
+        let (location, ty) = match self {
+            Variable::Input { location, ty, .. } | Variable::Output { location, ty, .. } => {
+                (location, ty)
+            }
+            _ => return Vec::new(),
+        };
+        let mut out = Vec::new();
+        match push_consumed_locations(location.loc(), location.comp(), ty, &mut out) {
+            Some(_) => out,
+            None => Vec::new(),
+        }
+    }
+}
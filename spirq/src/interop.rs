@@ -0,0 +1,2 @@
+//! Conversions of reflection results to graphics API structures.
+pub mod vulkan;
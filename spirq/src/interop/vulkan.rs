@@ -0,0 +1,210 @@
+//! Vulkan structures derived from reflection results. Vulkan enumerants are
+//! given as plain integers so that no binding crate is required.
+use crate::{
+    entry_point::EntryPoint,
+    spirv,
+    ty::{ScalarType, SpirvType, Type},
+    var::Variable,
+};
+
+/// Vertex input attribute, as is described by
+/// `VkVertexInputAttributeDescription` without the vertex buffer binding and
+/// the offset in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VertexAttr {
+    pub location: u32,
+    pub component: u32,
+    /// `VkFormat` of the attribute.
+    pub format: u32,
+    /// Size of the attribute in bytes.
+    pub byte_size: usize,
+}
+
+/// `VkFormat` of a vertex attribute of `nscalar` components of `scalar_ty`.
+/// Returns `None` if there is no such format, e.g., for booleans.
+fn vertex_format(scalar_ty: &ScalarType, nscalar: u32) -> Option<u32> {
+    // `VkFormat`s of a same bit width are ordered by the number of
+    // components first, with a fixed distance between e.g. `R32_*` and
+    // `R32G32_*`.
+    let (base, stride) = match scalar_ty {
+        // VK_FORMAT_R8_UINT
+        ScalarType::Integer { bits: 8, .. } => (13, 7),
+        // VK_FORMAT_R16_UINT
+        ScalarType::Integer { bits: 16, .. } | ScalarType::Float { bits: 16 } => (74, 7),
+        // VK_FORMAT_R32_UINT
+        ScalarType::Integer { bits: 32, .. } | ScalarType::Float { bits: 32 } => (98, 3),
+        // VK_FORMAT_R64_UINT
+        ScalarType::Integer { bits: 64, .. } | ScalarType::Float { bits: 64 } => (110, 3),
+        _ => return None,
+    };
+    if nscalar == 0 || nscalar > 4 {
+        return None;
+    }
+    let offset = match (scalar_ty, nscalar) {
+        // `B8G8R8_*` formats are placed between `R8G8B8_*` and `R8G8B8A8_*`.
+        (ScalarType::Integer { bits: 8, .. }, 4) => 28,
+        _ => stride * (nscalar - 1),
+    };
+    let ty_offset = match scalar_ty {
+        ScalarType::Integer {
+            is_signed: false, ..
+        } => 0,
+        ScalarType::Integer {
+            is_signed: true, ..
+        } => 1,
+        ScalarType::Float { .. } => 2,
+        _ => return None,
+    };
+    Some(base + offset + ty_offset)
+}
+/// `VkFormat` of a storage image or storage texel buffer of format `fmt`.
+/// Returns `None` for `Unknown`, which is only allowed for images accessed
+/// with `shaderStorageImageReadWithoutFormat` or
+/// `shaderStorageImageWriteWithoutFormat`.
+pub fn image_format_to_vk(fmt: spirv::ImageFormat) -> Option<u32> {
+    use spirv::ImageFormat::*;
+    let vk_fmt = match fmt {
+        Unknown => return None,
+        // VK_FORMAT_R32G32B32A32_SFLOAT
+        Rgba32f => 109,
+        // VK_FORMAT_R16G16B16A16_SFLOAT
+        Rgba16f => 97,
+        // VK_FORMAT_R32_SFLOAT
+        R32f => 100,
+        // VK_FORMAT_R8G8B8A8_UNORM
+        Rgba8 => 37,
+        // VK_FORMAT_R8G8B8A8_SNORM
+        Rgba8Snorm => 38,
+        // VK_FORMAT_R32G32_SFLOAT
+        Rg32f => 103,
+        // VK_FORMAT_R16G16_SFLOAT
+        Rg16f => 83,
+        // VK_FORMAT_B10G11R11_UFLOAT_PACK32
+        R11fG11fB10f => 122,
+        // VK_FORMAT_R16_SFLOAT
+        R16f => 76,
+        // VK_FORMAT_R16G16B16A16_UNORM
+        Rgba16 => 91,
+        // VK_FORMAT_A2B10G10R10_UNORM_PACK32
+        Rgb10A2 => 64,
+        // VK_FORMAT_R16G16_UNORM
+        Rg16 => 77,
+        // VK_FORMAT_R8G8_UNORM
+        Rg8 => 16,
+        // VK_FORMAT_R16_UNORM
+        R16 => 70,
+        // VK_FORMAT_R8_UNORM
+        R8 => 9,
+        // VK_FORMAT_R16G16B16A16_SNORM
+        Rgba16Snorm => 92,
+        // VK_FORMAT_R16G16_SNORM
+        Rg16Snorm => 78,
+        // VK_FORMAT_R8G8_SNORM
+        Rg8Snorm => 17,
+        // VK_FORMAT_R16_SNORM
+        R16Snorm => 71,
+        // VK_FORMAT_R8_SNORM
+        R8Snorm => 10,
+        // VK_FORMAT_R32G32B32A32_SINT
+        Rgba32i => 108,
+        // VK_FORMAT_R16G16B16A16_SINT
+        Rgba16i => 96,
+        // VK_FORMAT_R8G8B8A8_SINT
+        Rgba8i => 42,
+        // VK_FORMAT_R32_SINT
+        R32i => 99,
+        // VK_FORMAT_R32G32_SINT
+        Rg32i => 102,
+        // VK_FORMAT_R16G16_SINT
+        Rg16i => 82,
+        // VK_FORMAT_R8G8_SINT
+        Rg8i => 21,
+        // VK_FORMAT_R16_SINT
+        R16i => 75,
+        // VK_FORMAT_R8_SINT
+        R8i => 14,
+        // VK_FORMAT_R32G32B32A32_UINT
+        Rgba32ui => 107,
+        // VK_FORMAT_R16G16B16A16_UINT
+        Rgba16ui => 95,
+        // VK_FORMAT_R8G8B8A8_UINT
+        Rgba8ui => 41,
+        // VK_FORMAT_R32_UINT
+        R32ui => 98,
+        // VK_FORMAT_A2B10G10R10_UINT_PACK32
+        Rgb10a2ui => 68,
+        // VK_FORMAT_R32G32_UINT
+        Rg32ui => 101,
+        // VK_FORMAT_R16G16_UINT
+        Rg16ui => 81,
+        // VK_FORMAT_R8G8_UINT
+        Rg8ui => 20,
+        // VK_FORMAT_R16_UINT
+        R16ui => 74,
+        // VK_FORMAT_R8_UINT
+        R8ui => 13,
+        // VK_FORMAT_R64_UINT
+        R64ui => 110,
+        // VK_FORMAT_R64_SINT
+        R64i => 111,
+    };
+    Some(vk_fmt)
+}
+/// Append the attributes of an input of type `ty` at `location` and
+/// `component` to `out`, and return the number of locations consumed.
+/// Matrices and arrays consume a location per column or element, and 64-bit
+/// vectors of more than two components consume two locations.
+fn push_vertex_attrs(
+    location: u32,
+    component: u32,
+    ty: &Type,
+    out: &mut Vec<VertexAttr>,
+) -> Option<u32> {
+    let (scalar_ty, nscalar) = match ty {
+        Type::Scalar(scalar_ty) => (scalar_ty, 1),
+        Type::Vector(vector_ty) => (&vector_ty.scalar_ty, vector_ty.nscalar),
+        Type::Matrix(matrix_ty) => {
+            let column_ty = Type::Vector(matrix_ty.vector_ty.clone());
+            let mut nlocation = 0;
+            for _ in 0..matrix_ty.nvector {
+                nlocation += push_vertex_attrs(location + nlocation, 0, &column_ty, out)?;
+            }
+            return Some(nlocation);
+        }
+        Type::Array(arr_ty) => {
+            let mut nlocation = 0;
+            for _ in 0..arr_ty.nelement? {
+                nlocation +=
+                    push_vertex_attrs(location + nlocation, component, &arr_ty.element_ty, out)?;
+            }
+            return Some(nlocation);
+        }
+        _ => return None,
+    };
+    let attr = VertexAttr {
+        location,
+        component,
+        format: vertex_format(scalar_ty, nscalar)?,
+        byte_size: scalar_ty.nbyte()? * nscalar as usize,
+    };
+    out.push(attr);
+    let nlocation = if attr.byte_size > 16 { 2 } else { 1 };
+    Some(nlocation)
+}
+
+/// Vertex input attributes of a vertex shader, sorted by location and
+/// component. Built-in inputs are not listed, and neither are inputs of
+/// types that have no corresponding `VkFormat`.
+pub fn vertex_input_attributes(vert: &EntryPoint) -> Vec<VertexAttr> {
+    let mut out = Vec::new();
+    for var in vert.vars.iter() {
+        if let Variable::Input { location, ty, .. } = var {
+            let mut attrs = Vec::new();
+            if push_vertex_attrs(location.loc(), location.comp(), ty, &mut attrs).is_some() {
+                out.extend(attrs);
+            }
+        }
+    }
+    out.sort_by_key(|x| (x.location, x.component));
+    out
+}
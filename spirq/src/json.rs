@@ -0,0 +1,229 @@
+//! JSON representation of reflection results.
+use std::io::Write;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::{
+    constant::Constant,
+    descriptor::DescriptorVariableExt,
+    entry_point::EntryPoint,
+    func::ExecutionMode,
+    ty::{self, AccessType, DescriptorType, Type},
+    value::ConstantValueExt,
+    var::Variable,
+};
+
+/// Access type of a member of a struct accessed by `outer`. The stricter one
+/// is taken so that members of nested structs are restricted by the access
+/// types of enclosing members.
+fn narrow_access(outer: AccessType, member: AccessType) -> AccessType {
+    match (outer, member) {
+        (AccessType::ReadWrite, x) | (x, AccessType::ReadWrite) => x,
+        (_, x) => x,
+    }
+}
+
+/// A struct member, accessed as is allowed by the enclosing member or
+/// variable.
+struct JsonMember<'a>(&'a ty::StructMember, AccessType);
+impl Serialize for JsonMember<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let member = self.0;
+        let access = narrow_access(self.1, member.access_ty);
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("Name", &member.name)?;
+        map.serialize_entry("Offset", &member.offset)?;
+        map.serialize_entry("AccessType", &format!("{:?}", access))?;
+        map.serialize_entry("MemberType", &JsonType(&member.ty, access))?;
+        map.end()
+    }
+}
+
+/// A type, whose struct members are accessed as is allowed by the access
+/// type.
+struct JsonType<'a>(&'a Type, AccessType);
+impl Serialize for JsonType<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let access = self.1;
+        match self.0 {
+            Type::Matrix(x) => {
+                let mut map = serializer.serialize_map(Some(5))?;
+                map.serialize_entry("Kind", "Matrix")?;
+                map.serialize_entry("AxisOrder", &x.axis_order.map(|x| format!("{:?}", x)))?;
+                map.serialize_entry("VectorType", &x.vector_ty.to_string())?;
+                map.serialize_entry("Count", &x.nvector)?;
+                map.serialize_entry("Stride", &x.stride)?;
+                map.end()
+            }
+            Type::Array(x) => {
+                let mut map = serializer.serialize_map(Some(4))?;
+                map.serialize_entry("Kind", "Array")?;
+                map.serialize_entry("ElementType", &JsonType(&x.element_ty, access))?;
+                map.serialize_entry("Count", &x.nelement)?;
+                map.serialize_entry("Stride", &x.stride)?;
+                map.end()
+            }
+            Type::Struct(x) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("Kind", "Struct")?;
+                map.serialize_entry(
+                    "Members",
+                    &JsonSeq(|| x.members.iter().map(|member| JsonMember(member, access))),
+                )?;
+                map.end()
+            }
+            Type::DevicePointer(x) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("Kind", "Pointer")?;
+                map.serialize_entry(
+                    "TargetType",
+                    &JsonType(&x.pointee_ty, AccessType::ReadWrite),
+                )?;
+                map.end()
+            }
+            ty => serializer.serialize_str(&ty.to_string()),
+        }
+    }
+}
+
+struct JsonVariable<'a>(&'a Variable);
+impl Serialize for JsonVariable<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use Variable::*;
+        let mut map = serializer.serialize_map(None)?;
+        match self.0 {
+            Input { name, location, ty } | Output { name, location, ty } => {
+                map.serialize_entry("Name", name)?;
+                map.serialize_entry("Location", &location.loc())?;
+                map.serialize_entry("Component", &location.comp())?;
+                map.serialize_entry("Type", &JsonType(ty, AccessType::ReadWrite))?;
+            }
+            Descriptor {
+                name,
+                desc_bind,
+                desc_ty,
+                ty,
+                nbind,
+            } => {
+                map.serialize_entry("Name", name)?;
+                map.serialize_entry("TypeName", &self.0.type_name())?;
+                map.serialize_entry("InstanceName", name)?;
+                map.serialize_entry("Set", &desc_bind.set())?;
+                map.serialize_entry("Binding", &desc_bind.bind())?;
+                map.serialize_entry("DescriptorType", &format!("{desc_ty:?}"))?;
+                // Uniform buffers are never written by shaders.
+                let access = match desc_ty {
+                    DescriptorType::StorageBuffer(access) => *access,
+                    DescriptorType::UniformBuffer() => AccessType::ReadOnly,
+                    _ => AccessType::ReadWrite,
+                };
+                map.serialize_entry("Type", &JsonType(ty, access))?;
+                map.serialize_entry("Count", nbind)?;
+            }
+            PushConstant { name, ty } => {
+                map.serialize_entry("Name", name)?;
+                map.serialize_entry("Type", &JsonType(ty, AccessType::ReadOnly))?;
+            }
+            SpecConstant { name, spec_id, ty } => {
+                map.serialize_entry("Name", name)?;
+                map.serialize_entry("SpecId", spec_id)?;
+                map.serialize_entry("Type", &JsonType(ty, AccessType::ReadWrite))?;
+            }
+        }
+        map.end()
+    }
+}
+
+struct JsonOperand<'a>(&'a Constant);
+impl Serialize for JsonOperand<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let operand = self.0;
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("Value", &operand.value.display().to_string())?;
+        map.serialize_entry("SpecId", &operand.spec_id)?;
+        map.end()
+    }
+}
+
+struct JsonExecutionMode<'a>(&'a ExecutionMode);
+impl Serialize for JsonExecutionMode<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let exec_mode = self.0;
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("ExecutionMode", &format!("{:?}", exec_mode.exec_mode))?;
+        map.serialize_entry(
+            "Operands",
+            &JsonSeq(|| exec_mode.operands.iter().map(JsonOperand)),
+        )?;
+        map.end()
+    }
+}
+
+/// Sequence of items created on the fly, so that no intermediate collection
+/// is allocated.
+struct JsonSeq<F>(F);
+impl<F, I> Serialize for JsonSeq<F>
+where
+    F: Fn() -> I,
+    I: Iterator,
+    I::Item: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((self.0)())
+    }
+}
+
+struct JsonVariables<'a>(&'a [Variable]);
+impl Serialize for JsonVariables<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let vars = self.0;
+        let filter = |f: fn(&Variable) -> bool| {
+            JsonSeq(move || vars.iter().filter(move |x| f(x)).map(JsonVariable))
+        };
+        let mut map = serializer.serialize_map(Some(5))?;
+        map.serialize_entry("Inputs", &filter(|x| matches!(x, Variable::Input { .. })))?;
+        map.serialize_entry("Outputs", &filter(|x| matches!(x, Variable::Output { .. })))?;
+        map.serialize_entry(
+            "Descriptors",
+            &filter(|x| matches!(x, Variable::Descriptor { .. })),
+        )?;
+        map.serialize_entry(
+            "PushConstants",
+            &filter(|x| matches!(x, Variable::PushConstant { .. })),
+        )?;
+        map.serialize_entry(
+            "SpecConstants",
+            &filter(|x| matches!(x, Variable::SpecConstant { .. })),
+        )?;
+        map.end()
+    }
+}
+
+/// Serializable view of an entry point, as is printed by `shader-reflect`.
+pub struct JsonEntryPoint<'a>(pub &'a EntryPoint);
+impl Serialize for JsonEntryPoint<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entry_point = self.0;
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("EntryPoint", &entry_point.name)?;
+        map.serialize_entry("ExecutionModel", &format!("{:?}", entry_point.exec_model))?;
+        map.serialize_entry(
+            "ExecutionModes",
+            &JsonSeq(|| entry_point.exec_modes.iter().map(JsonExecutionMode)),
+        )?;
+        map.serialize_entry("Variables", &JsonVariables(&entry_point.vars))?;
+        map.end()
+    }
+}
+
+/// JSON representation of an entry point, as is printed by `shader-reflect`.
+pub fn to_json(entry_point: &EntryPoint) -> serde_json::Value {
+    serde_json::to_value(JsonEntryPoint(entry_point))
+        .expect("entry point serialization never fails")
+}
+/// Write the pretty-printed JSON representation of an entry point to
+/// `writer` without building a [`serde_json::Value`] first. The output is
+/// identical to pretty-printing [`to_json`].
+pub fn write_json<W: Write>(entry_point: &EntryPoint, writer: W) -> serde_json::Result<()> {
+    serde_json::to_writer_pretty(writer, &JsonEntryPoint(entry_point))
+}
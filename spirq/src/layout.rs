@@ -0,0 +1,529 @@
+//! Memory layout rules of buffer-backed types.
+use crate::{
+    error::{anyhow, Result},
+    ty::{
+        ArrayType, MatrixAxisOrder, MatrixType, ScalarType, SpirvType, StructMember, StructType,
+        Type, VectorType,
+    },
+};
+
+/// Memory layout rules of buffer contents, as specified in section *Offset and
+/// Stride Assignment* of the Vulkan specification.
+///
+/// Booleans are laid out as 32-bit integers, as is done for `Workgroup`
+/// variables without explicit layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutRule {
+    /// Standard uniform buffer layout, i.e., `std140` in GLSL.
+    Std140,
+    /// Standard storage buffer layout, i.e., `std430` in GLSL.
+    Std430,
+    /// Scalar block layout enabled by `VK_EXT_scalar_block_layout`.
+    Scalar,
+}
+fn scalar_nbyte(scalar_ty: &ScalarType) -> Option<usize> {
+    match scalar_ty {
+        ScalarType::Boolean => Some(4),
+        _ => scalar_ty.nbyte(),
+    }
+}
+fn round_up(x: usize, align: usize) -> usize {
+    x.div_ceil(align) * align
+}
+
+impl LayoutRule {
+    fn vector_align(&self, scalar_ty: &ScalarType, nscalar: u32) -> Option<usize> {
+        let scalar_align = scalar_nbyte(scalar_ty)?;
+        let align = match (self, nscalar) {
+            (LayoutRule::Scalar, _) => scalar_align,
+            (_, 2) => scalar_align * 2,
+            (_, 3) | (_, 4) => scalar_align * 4,
+            _ => scalar_align,
+        };
+        Some(align)
+    }
+    fn round_up_composite_align(&self, align: usize) -> usize {
+        match self {
+            LayoutRule::Std140 => round_up(align, 16),
+            _ => align,
+        }
+    }
+    /// Base alignment of `ty` in bytes. Returns `None` if the type cannot be
+    /// represented in memory, e.g., images.
+    pub fn align_of(&self, ty: &Type) -> Option<usize> {
+        let align = match ty {
+            Type::Scalar(scalar_ty) => scalar_nbyte(scalar_ty)?,
+            Type::Vector(vector_ty) => {
+                self.vector_align(&vector_ty.scalar_ty, vector_ty.nscalar)?
+            }
+            Type::Matrix(matrix_ty) => {
+                // Matrices are laid out like arrays of column vectors if
+                // column-major; or arrays of row vectors if row-major.
+                let major_ty = matrix_ty.major_type();
+                let align = self.vector_align(&major_ty.scalar_ty, major_ty.nscalar)?;
+                self.round_up_composite_align(align)
+            }
+            Type::Array(arr_ty) => {
+                self.round_up_composite_align(self.align_of(&arr_ty.element_ty)?)
+            }
+            Type::Struct(struct_ty) => {
+                let mut align = 1;
+                for member in struct_ty.members.iter() {
+                    align = align.max(self.align_of(&member.ty)?);
+                }
+                self.round_up_composite_align(align)
+            }
+            Type::DeviceAddress(_) | Type::DevicePointer(_) => 8,
+            _ => return None,
+        };
+        Some(align)
+    }
+    /// Size of `ty` in bytes if it's laid out by this rule. Explicit offsets
+    /// and strides in `ty` are ignored. Returns `None` if the type cannot be
+    /// represented in memory or contains a runtime-sized array.
+    pub fn size_of(&self, ty: &Type) -> Option<usize> {
+        let nbyte = match ty {
+            Type::Scalar(scalar_ty) => scalar_nbyte(scalar_ty)?,
+            Type::Vector(vector_ty) => {
+                scalar_nbyte(&vector_ty.scalar_ty)? * vector_ty.nscalar as usize
+            }
+            Type::Matrix(matrix_ty) => {
+                let major_ty = matrix_ty.major_type();
+                let vector_nbyte = scalar_nbyte(&major_ty.scalar_ty)? * major_ty.nscalar as usize;
+                let stride = round_up(vector_nbyte, self.align_of(ty)?);
+                stride * matrix_ty.nmajor() as usize
+            }
+            Type::Array(arr_ty) => {
+                let nelement = arr_ty.nelement? as usize;
+                let stride = round_up(self.size_of(&arr_ty.element_ty)?, self.align_of(ty)?);
+                stride * nelement
+            }
+            Type::Struct(struct_ty) => {
+                let mut offset = 0;
+                for member in struct_ty.members.iter() {
+                    offset = round_up(offset, self.align_of(&member.ty)?);
+                    offset += self.size_of(&member.ty)?;
+                }
+                // The member following a struct is aligned to the struct's
+                // base alignment in the standard layouts.
+                match self {
+                    LayoutRule::Scalar => offset,
+                    _ => round_up(offset, self.align_of(ty)?),
+                }
+            }
+            Type::DeviceAddress(_) | Type::DevicePointer(_) => 8,
+            _ => return None,
+        };
+        Some(nbyte)
+    }
+}
+
+/// Layout validation and views of [`ArrayType`].
+pub trait ArrayTypeExt {
+    /// Check that the declared `ArrayStride` is no less than the element size
+    /// and is a multiple of the element alignment required by `rule`. For
+    /// `std140` this implies the stride is a multiple of 16.
+    fn validate_stride(&self, rule: LayoutRule) -> Result<()>;
+    /// Collapse nested arrays into a single logical array, e.g., `vec4
+    /// m[2][3]` is viewed as 6 `vec4`s. Returns the innermost non-array
+    /// element type, the total number of elements and the stride between
+    /// two elements. Returns `None` if any level is runtime-sized or has no
+    /// explicit stride, or if an outer stride doesn't equal the size of the
+    /// array nested in it, i.e., the elements are not contiguous.
+    fn flatten(&self) -> Option<(Type, u32, usize)>;
+}
+impl ArrayTypeExt for ArrayType {
+    fn validate_stride(&self, rule: LayoutRule) -> Result<()> {
+        let stride = self
+            .stride
+            .ok_or_else(|| anyhow!("array {} has no explicit stride", self))?;
+        let nbyte = self
+            .element_ty
+            .nbyte()
+            .ok_or_else(|| anyhow!("array element {} is not sized", self.element_ty))?;
+        let align = rule
+            .align_of(&self.element_ty)
+            .map(|x| rule.round_up_composite_align(x))
+            .ok_or_else(|| anyhow!("array element {} has no alignment", self.element_ty))?;
+        if stride < nbyte {
+            return Err(anyhow!(
+                "array stride {} is smaller than element size {}",
+                stride,
+                nbyte
+            ));
+        }
+        if stride % align != 0 {
+            return Err(anyhow!(
+                "array stride {} is not a multiple of {} required by {:?}",
+                stride,
+                align,
+                rule
+            ));
+        }
+        Ok(())
+    }
+    fn flatten(&self) -> Option<(Type, u32, usize)> {
+        let mut nelement = self.nelement?;
+        let mut stride = self.stride?;
+        let mut element_ty = &*self.element_ty;
+        while let Type::Array(arr_ty) = element_ty {
+            let inner_nelement = arr_ty.nelement?;
+            let inner_stride = arr_ty.stride?;
+            if stride != inner_stride * inner_nelement as usize {
+                return None;
+            }
+            nelement = nelement.checked_mul(inner_nelement)?;
+            stride = inner_stride;
+            element_ty = &arr_ty.element_ty;
+        }
+        Some((element_ty.clone(), nelement, stride))
+    }
+}
+
+/// Dimensions of [`MatrixType`]. SPIR-V matrices are always made of column
+/// vectors; the axis order only decides how they are laid out in memory.
+pub trait MatrixTypeExt {
+    /// Number of rows and columns, regardless of the axis order. For example,
+    /// GLSL `mat3x4` has 3 columns and 4 rows and gives `(4, 3)`.
+    fn dimensions(&self) -> (u32, u32);
+    /// Type of the columns, i.e., `vector_ty`.
+    fn column_type(&self) -> VectorType;
+    /// Type of the rows.
+    fn row_type(&self) -> VectorType;
+    /// Type of the vectors contiguous in memory, i.e., the columns if
+    /// column-major, or the rows if row-major. `stride` is the distance
+    /// between two such vectors.
+    fn major_type(&self) -> VectorType;
+    /// Number of vectors of [`MatrixTypeExt::major_type`] in the matrix.
+    fn nmajor(&self) -> u32;
+}
+impl MatrixTypeExt for MatrixType {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.vector_ty.nscalar, self.nvector)
+    }
+    fn column_type(&self) -> VectorType {
+        self.vector_ty.clone()
+    }
+    fn row_type(&self) -> VectorType {
+        VectorType {
+            scalar_ty: self.vector_ty.scalar_ty.clone(),
+            nscalar: self.nvector,
+        }
+    }
+    fn major_type(&self) -> VectorType {
+        match self.axis_order {
+            Some(MatrixAxisOrder::RowMajor) => self.row_type(),
+            _ => self.column_type(),
+        }
+    }
+    fn nmajor(&self) -> u32 {
+        match self.axis_order {
+            Some(MatrixAxisOrder::RowMajor) => self.vector_ty.nscalar,
+            _ => self.nvector,
+        }
+    }
+}
+
+/// Layout-aware constructors and transformers of [`Type`].
+pub trait TypeLayoutExt: Sized {
+    /// Wrap the type in an array of `count` elements, with the array stride
+    /// computed by `rule`. Explicit offsets and strides inside the element
+    /// type are kept as-is. Returns `None` if the element type cannot be laid
+    /// out by `rule`.
+    fn array_of(&self, count: u32, rule: LayoutRule) -> Option<Self>;
+    /// Copy the struct member offsets, array strides and matrix strides and
+    /// axis orders from `other` onto a clone of `self`. An error is returned
+    /// if the two types are not structurally identical.
+    fn with_offsets_from(&self, other: &Type) -> Result<Self>;
+    /// Whether the type is backed by memory that can be shared with the
+    /// host, i.e., scalars, vectors, matrices, device addresses and pointers,
+    /// and arrays and structs composed entirely of them. Opaque types like
+    /// images, samplers, subpass data, acceleration structures and ray
+    /// queries are not host-shareable, nor is any aggregate containing them.
+    /// Unlike [`SpirvType::is_sized`], runtime arrays of host-shareable
+    /// elements are host-shareable.
+    fn is_host_shareable(&self) -> bool;
+    /// Byte offsets and types of all the scalars in the type, by the explicit
+    /// offsets and strides, in declaration order. Padding is skipped; matrix
+    /// elements are listed by the vectors contiguous in memory. Elements of
+    /// runtime-sized arrays are not listed as the count is unknown. An empty
+    /// vec is returned for opaque types and types without explicit layout.
+    fn scalar_leaves(&self) -> Vec<(usize, ScalarType)>;
+}
+fn collect_scalar_leaves(
+    ty: &Type,
+    offset: usize,
+    out: &mut Vec<(usize, ScalarType)>,
+) -> Option<()> {
+    match ty {
+        Type::Scalar(scalar_ty) => {
+            scalar_nbyte(scalar_ty)?;
+            out.push((offset, scalar_ty.clone()));
+        }
+        Type::Vector(vector_ty) => {
+            let nbyte = scalar_nbyte(&vector_ty.scalar_ty)?;
+            for i in 0..vector_ty.nscalar as usize {
+                out.push((offset + i * nbyte, vector_ty.scalar_ty.clone()));
+            }
+        }
+        Type::Matrix(matrix_ty) => {
+            let stride = matrix_ty.stride?;
+            let major_ty = matrix_ty.major_type();
+            for i in 0..matrix_ty.nmajor() as usize {
+                collect_scalar_leaves(&Type::Vector(major_ty.clone()), offset + i * stride, out)?;
+            }
+        }
+        Type::Array(arr_ty) => {
+            if let Some(nelement) = arr_ty.nelement {
+                let stride = arr_ty.stride?;
+                for i in 0..nelement as usize {
+                    collect_scalar_leaves(&arr_ty.element_ty, offset + i * stride, out)?;
+                }
+            }
+        }
+        Type::Struct(struct_ty) => {
+            for member in struct_ty.members.iter() {
+                collect_scalar_leaves(&member.ty, offset + member.offset?, out)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+impl TypeLayoutExt for Type {
+    fn array_of(&self, count: u32, rule: LayoutRule) -> Option<Self> {
+        let mut arr_ty = ArrayType {
+            element_ty: Box::new(self.clone()),
+            nelement: Some(count),
+            stride: None,
+        };
+        let align = rule.align_of(&Type::Array(arr_ty.clone()))?;
+        arr_ty.stride = Some(round_up(rule.size_of(self)?, align));
+        Some(Type::Array(arr_ty))
+    }
+    fn with_offsets_from(&self, other: &Type) -> Result<Self> {
+        let mismatch = || anyhow!("type {} is not structurally identical to {}", self, other);
+        let ty = match (self, other) {
+            (Type::Array(arr_ty), Type::Array(other_arr_ty)) => {
+                if arr_ty.nelement != other_arr_ty.nelement {
+                    return Err(mismatch());
+                }
+                Type::Array(ArrayType {
+                    element_ty: Box::new(
+                        arr_ty
+                            .element_ty
+                            .with_offsets_from(&other_arr_ty.element_ty)?,
+                    ),
+                    nelement: arr_ty.nelement,
+                    stride: other_arr_ty.stride,
+                })
+            }
+            (Type::Struct(struct_ty), Type::Struct(other_struct_ty)) => {
+                if struct_ty.members.len() != other_struct_ty.members.len() {
+                    return Err(mismatch());
+                }
+                let members = struct_ty
+                    .members
+                    .iter()
+                    .zip(other_struct_ty.members.iter())
+                    .map(|(member, other_member)| {
+                        let member = StructMember {
+                            name: member.name.clone(),
+                            offset: other_member.offset,
+                            ty: member.ty.with_offsets_from(&other_member.ty)?,
+                            access_ty: member.access_ty,
+                        };
+                        Ok(member)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Type::Struct(StructType {
+                    name: struct_ty.name.clone(),
+                    members,
+                })
+            }
+            (Type::Matrix(mat_ty), Type::Matrix(other_mat_ty)) => {
+                if mat_ty.vector_ty != other_mat_ty.vector_ty
+                    || mat_ty.nvector != other_mat_ty.nvector
+                {
+                    return Err(mismatch());
+                }
+                let mut mat_ty = mat_ty.clone();
+                mat_ty.axis_order = other_mat_ty.axis_order;
+                mat_ty.stride = other_mat_ty.stride;
+                Type::Matrix(mat_ty)
+            }
+            _ => {
+                if self != other {
+                    return Err(mismatch());
+                }
+                self.clone()
+            }
+        };
+        Ok(ty)
+    }
+    fn is_host_shareable(&self) -> bool {
+        match self {
+            Type::Scalar(_)
+            | Type::Vector(_)
+            | Type::Matrix(_)
+            | Type::DeviceAddress(_)
+            | Type::DevicePointer(_) => true,
+            Type::Array(arr_ty) => arr_ty.element_ty.is_host_shareable(),
+            Type::Struct(struct_ty) => struct_ty
+                .members
+                .iter()
+                .all(|member| member.ty.is_host_shareable()),
+            _ => false,
+        }
+    }
+    fn scalar_leaves(&self) -> Vec<(usize, ScalarType)> {
+        let mut out = Vec::new();
+        match collect_scalar_leaves(self, 0, &mut out) {
+            Some(()) => out,
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Conversions between data laid out by the explicit offsets and strides of
+/// a [`Type`], e.g., in a `std140` uniform buffer, and tightly packed host
+/// data with no padding. Struct members and array elements are packed in
+/// declaration order; matrices are packed as consecutive column vectors, or
+/// row vectors if they are row-major, same as they are in the buffer.
+pub trait TypePackExt {
+    /// Size of the tightly packed data in bytes.
+    fn packed_nbyte(&self) -> Result<usize>;
+    /// Lay out tightly packed `host_bytes` by the offsets and strides of the
+    /// type. Padding bytes are zeroed.
+    fn pack(&self, host_bytes: &[u8]) -> Result<Vec<u8>>;
+    /// Gather the data in `gpu_bytes`, laid out by the offsets and strides of
+    /// the type, into tightly packed bytes.
+    fn unpack(&self, gpu_bytes: &[u8]) -> Result<Vec<u8>>;
+}
+/// A contiguous run of bytes at `gpu_offset` in the buffer, and at
+/// `host_offset` in the tightly packed data.
+struct PackedRange {
+    gpu_offset: usize,
+    host_offset: usize,
+    nbyte: usize,
+}
+fn collect_packed_ranges(
+    ty: &Type,
+    gpu_offset: usize,
+    host_offset: &mut usize,
+    out: &mut Vec<PackedRange>,
+) -> Result<()> {
+    let mut push = |gpu_offset: usize, nbyte: usize| {
+        out.push(PackedRange {
+            gpu_offset,
+            host_offset: *host_offset,
+            nbyte,
+        });
+        *host_offset += nbyte;
+    };
+    match ty {
+        Type::Scalar(scalar_ty) => {
+            let nbyte = scalar_nbyte(scalar_ty)
+                .ok_or_else(|| anyhow!("scalar {} is not sized", scalar_ty))?;
+            push(gpu_offset, nbyte);
+        }
+        Type::Vector(vector_ty) => {
+            let nbyte = scalar_nbyte(&vector_ty.scalar_ty)
+                .ok_or_else(|| anyhow!("vector {} is not sized", vector_ty))?;
+            push(gpu_offset, nbyte * vector_ty.nscalar as usize);
+        }
+        Type::DeviceAddress(_) | Type::DevicePointer(_) => push(gpu_offset, 8),
+        Type::Matrix(matrix_ty) => {
+            let stride = matrix_ty
+                .stride
+                .ok_or_else(|| anyhow!("matrix {} has no explicit stride", matrix_ty))?;
+            let (nscalar, nvector) = match matrix_ty.axis_order {
+                Some(MatrixAxisOrder::RowMajor) => (matrix_ty.nvector, matrix_ty.vector_ty.nscalar),
+                _ => (matrix_ty.vector_ty.nscalar, matrix_ty.nvector),
+            };
+            let scalar_ty = &matrix_ty.vector_ty.scalar_ty;
+            let nbyte = scalar_nbyte(scalar_ty)
+                .ok_or_else(|| anyhow!("matrix {} is not sized", matrix_ty))?;
+            for i in 0..nvector as usize {
+                push(gpu_offset + i * stride, nbyte * nscalar as usize);
+            }
+        }
+        Type::Array(arr_ty) => {
+            let nelement = arr_ty
+                .nelement
+                .ok_or_else(|| anyhow!("array {} is not sized", arr_ty))?;
+            let stride = arr_ty
+                .stride
+                .ok_or_else(|| anyhow!("array {} has no explicit stride", arr_ty))?;
+            for i in 0..nelement as usize {
+                collect_packed_ranges(
+                    &arr_ty.element_ty,
+                    gpu_offset + i * stride,
+                    host_offset,
+                    out,
+                )?;
+            }
+        }
+        Type::Struct(struct_ty) => {
+            for member in struct_ty.members.iter() {
+                let offset = member
+                    .offset
+                    .ok_or_else(|| anyhow!("struct member of {} has no offset", struct_ty))?;
+                collect_packed_ranges(&member.ty, gpu_offset + offset, host_offset, out)?;
+            }
+        }
+        _ => return Err(anyhow!("{} cannot be laid out in a buffer", ty)),
+    }
+    Ok(())
+}
+fn packed_ranges(ty: &Type) -> Result<(Vec<PackedRange>, usize)> {
+    let mut ranges = Vec::new();
+    let mut host_nbyte = 0;
+    collect_packed_ranges(ty, 0, &mut host_nbyte, &mut ranges)?;
+    Ok((ranges, host_nbyte))
+}
+impl TypePackExt for Type {
+    fn packed_nbyte(&self) -> Result<usize> {
+        packed_ranges(self).map(|(_, host_nbyte)| host_nbyte)
+    }
+    fn pack(&self, host_bytes: &[u8]) -> Result<Vec<u8>> {
+        let (ranges, host_nbyte) = packed_ranges(self)?;
+        if host_bytes.len() != host_nbyte {
+            return Err(anyhow!(
+                "expected {} bytes of packed {}, got {}",
+                host_nbyte,
+                self,
+                host_bytes.len()
+            ));
+        }
+        let gpu_end = ranges
+            .iter()
+            .map(|x| x.gpu_offset + x.nbyte)
+            .max()
+            .unwrap_or(0);
+        let gpu_nbyte = self.nbyte().unwrap_or(gpu_end).max(gpu_end);
+        let mut out = vec![0; gpu_nbyte];
+        for range in ranges {
+            out[range.gpu_offset..range.gpu_offset + range.nbyte]
+                .copy_from_slice(&host_bytes[range.host_offset..range.host_offset + range.nbyte]);
+        }
+        Ok(out)
+    }
+    fn unpack(&self, gpu_bytes: &[u8]) -> Result<Vec<u8>> {
+        let (ranges, host_nbyte) = packed_ranges(self)?;
+        let mut out = vec![0; host_nbyte];
+        for range in ranges {
+            let src = gpu_bytes
+                .get(range.gpu_offset..range.gpu_offset + range.nbyte)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "buffer of {} bytes is too short for {}",
+                        gpu_bytes.len(),
+                        self
+                    )
+                })?;
+            out[range.host_offset..range.host_offset + range.nbyte].copy_from_slice(src);
+        }
+        Ok(out)
+    }
+}
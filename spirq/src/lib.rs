@@ -59,11 +59,29 @@
 //! [`reflect`]: reflect/struct.ReflectConfig.html#method.reflect
 //! [`Type`]: ty/enum.Type.html
 mod instr;
+mod operand;
 
+pub mod binary;
+pub mod builtin;
+pub mod descriptor;
+pub mod diagnostic;
+pub mod diff;
+pub mod display;
 pub mod entry_point;
 pub mod inspect;
+pub mod instruction;
+pub mod interface;
+pub mod interop;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod layout;
+pub mod locator;
+pub mod module;
 pub mod reflect;
 pub mod reflect_cfg;
+pub mod registry;
+pub mod value;
+pub mod walk;
 
 #[cfg(test)]
 mod tests;
@@ -84,9 +102,12 @@ pub use reflect_cfg::ReflectConfig;
 pub mod prelude {
     pub use super::ReflectConfig;
     pub use super::{
+        binary::SpirvBinaryExt,
         constant::ConstantValue,
-        entry_point::{EntryPoint, ExecutionModel},
+        descriptor::{DescriptorTypeExt, DescriptorVariableExt, ImageTexelExt},
+        entry_point::{EntryPoint, ExecutionModel, ExecutionModelExt},
         error::{Error, Result},
+        locator::Locator,
         parse::SpirvBinary,
         ty::{AccessType, DescriptorType, SpirvType, Type},
         var::{DescriptorBinding, InterfaceLocation, SpecId, Variable},
@@ -0,0 +1,48 @@
+//! Locators that uniquely identify a variable within an entry point.
+use std::fmt;
+
+use crate::var::{DescriptorBinding, InterfaceLocation, SpecId, Variable};
+
+/// The place a variable is bound to, regardless of its type. Input and output
+/// interface variables have separated location spaces so they are
+/// distinguished here.
+///
+/// Locators are ordered by their kinds as listed below, and then by their
+/// payloads. [`InterfaceLocation`]s are ordered by location first and then by
+/// component, and [`DescriptorBinding`]s by set first and then by binding, so
+/// the iteration order of a `BTreeMap` keyed by locators is deterministic.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Locator {
+    Input(InterfaceLocation),
+    Output(InterfaceLocation),
+    Descriptor(DescriptorBinding),
+    PushConstant,
+    SpecConstant(SpecId),
+}
+impl From<&Variable> for Locator {
+    fn from(var: &Variable) -> Self {
+        match var {
+            Variable::Input { location, .. } => Locator::Input(*location),
+            Variable::Output { location, .. } => Locator::Output(*location),
+            Variable::Descriptor { desc_bind, .. } => Locator::Descriptor(*desc_bind),
+            Variable::PushConstant { .. } => Locator::PushConstant,
+            Variable::SpecConstant { spec_id, .. } => Locator::SpecConstant(*spec_id),
+        }
+    }
+}
+impl fmt::Display for Locator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Locator::Input(location) => write!(f, "input{}", location),
+            Locator::Output(location) => write!(f, "output{}", location),
+            Locator::Descriptor(desc_bind) => write!(f, "descriptor{}", desc_bind),
+            Locator::PushConstant => write!(f, "push_constant"),
+            Locator::SpecConstant(spec_id) => write!(f, "spec_constant(id={})", spec_id),
+        }
+    }
+}
+impl fmt::Debug for Locator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self as &dyn fmt::Display).fmt(f)
+    }
+}
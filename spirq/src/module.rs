@@ -0,0 +1,265 @@
+//! Module-wide reflection results.
+use std::collections::BTreeMap;
+
+use crate::{
+    diff::is_layout_eq,
+    entry_point::{vk_stage_flags, EntryPoint, ExecutionModel, PushConstantRange},
+    error::{anyhow, Result},
+    spirv,
+    ty::{AccessType, DescriptorType, Type},
+    var::{DescriptorBinding, Variable},
+};
+
+/// Debug information of a SPIR-V module that doesn't belong to any specific
+/// entry point.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ModuleMetadata {
+    /// Names of the source files the module is compiled from, in the order
+    /// they are first referred to. Only `OpString`s referred to as file names
+    /// by `OpSource` and `OpLine` are listed.
+    pub source_files: Vec<String>,
+}
+
+/// Module-wide requirements declared in the header and the leading
+/// instructions of a SPIR-V module, e.g., to select device features.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInfo {
+    /// SPIR-V version of the module as `(major, minor)`.
+    pub version: (u8, u8),
+    /// Capabilities declared by `OpCapability`, in declaration order.
+    pub capabilities: Vec<spirv::Capability>,
+    /// Capabilities not known to the `spirv` crate, as raw enum values in
+    /// declaration order. They are skipped rather than failing reflection.
+    pub unknown_capabilities: Vec<u32>,
+    /// Extensions declared by `OpExtension`, in declaration order.
+    pub extensions: Vec<String>,
+    pub memory_model: spirv::MemoryModel,
+    pub addressing_model: spirv::AddressingModel,
+}
+impl ModuleInfo {
+    /// Returns true if the module declares `capability`, e.g.,
+    /// `RuntimeDescriptorArrayEXT` for runtime binding arrays.
+    pub fn has_capability(&self, capability: spirv::Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+}
+
+/// Reflection result of an entire SPIR-V module.
+#[derive(Debug, Clone)]
+pub struct ReflectedModule {
+    /// Version, capabilities and extensions required by the module.
+    pub info: ModuleInfo,
+    /// Module-wide debug information.
+    pub metadata: ModuleMetadata,
+    /// All entry points in the module.
+    pub entry_points: Vec<EntryPoint>,
+}
+impl ReflectedModule {
+    /// All descriptors used by any entry point in the module, merged by their
+    /// binding points and sorted by set and binding. The access types of
+    /// storage resources are unioned across entry points. Resources aliased
+    /// at a same binding point are kept in [`MergedDescriptor::desc_tys`] and
+    /// [`MergedDescriptor::tys`]. An error is returned only if entry points
+    /// disagree on the number of bindings of a binding point.
+    pub fn descriptors(&self) -> Result<Vec<MergedDescriptor>> {
+        merge_descriptors(&self.entry_points)
+    }
+}
+
+/// Reflection result of SPIR-V modules linked into a single pipeline, e.g.,
+/// the stages of a ray-tracing pipeline.
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    /// Entry points of all the modules, in the order the modules are added.
+    pub entry_points: Vec<EntryPoint>,
+    /// Descriptors used by any of the entry points, merged by their binding
+    /// points and sorted by set and binding.
+    pub descriptors: Vec<MergedDescriptor>,
+    /// Push constant ranges to be declared in the pipeline layout, as is
+    /// given by [`merge_push_constant_ranges`].
+    pub push_constant_ranges: Vec<PushConstantRange>,
+}
+
+/// Push constant ranges of a pipeline made of `entry_points`. Vulkan allows
+/// each stage in at most one `VkPushConstantRange`, so the ranges of entry
+/// points of a same stage are unioned; and stages covering exactly the same
+/// bytes share one range. Different stages can use disjoint or overlapping
+/// parts of a same push constant block, in which case a range is given per
+/// stage. Ranges are sorted by stage flags.
+pub fn merge_push_constant_ranges(entry_points: &[EntryPoint]) -> Vec<PushConstantRange> {
+    let mut stage_ranges = BTreeMap::<u32, PushConstantRange>::new();
+    for range in entry_points.iter().filter_map(|x| x.push_constant_range()) {
+        stage_ranges
+            .entry(range.stage_flags)
+            .and_modify(|x| {
+                let start = x.offset.min(range.offset);
+                let end = (x.offset + x.size).max(range.offset + range.size);
+                x.offset = start;
+                x.size = end - start;
+            })
+            .or_insert(range);
+    }
+    let mut out = Vec::<PushConstantRange>::new();
+    for range in stage_ranges.into_values() {
+        let same_range = out
+            .iter_mut()
+            .find(|x| x.offset == range.offset && x.size == range.size);
+        match same_range {
+            Some(x) => x.stage_flags |= range.stage_flags,
+            None => out.push(range),
+        }
+    }
+    out.sort_by_key(|x| x.stage_flags);
+    out
+}
+
+/// How a descriptor is used by an entry point.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DescriptorUsage {
+    /// Whether the descriptor is accessed by any function in the call graph
+    /// of the entry point. Descriptors not reached are pruned from the entry
+    /// point unless [`ReflectConfig::ref_all_rscs`] is set.
+    ///
+    /// [`ReflectConfig::ref_all_rscs`]: crate::ReflectConfig::ref_all_rscs
+    pub is_reachable: bool,
+    /// IDs of the functions in the call graph of the entry point that access
+    /// the descriptor, in ascending order. Empty if the descriptor is not
+    /// reachable.
+    pub func_ids: Vec<u32>,
+}
+
+/// Reachability of all descriptors in a module from an entry point, to
+/// explain why a descriptor is or is not reflected.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UsageInfo {
+    /// Usage of every descriptor declared in the module, sorted by set and
+    /// binding.
+    pub descriptors: Vec<(DescriptorBinding, DescriptorUsage)>,
+}
+impl UsageInfo {
+    /// Usage of the descriptor at `desc_bind`. Returns `None` if no
+    /// descriptor is declared at the binding point.
+    pub fn usage(&self, desc_bind: DescriptorBinding) -> Option<&DescriptorUsage> {
+        self.descriptors
+            .iter()
+            .find(|(x, _)| *x == desc_bind)
+            .map(|(_, usage)| usage)
+    }
+}
+
+pub(crate) fn merge_descriptors(entry_points: &[EntryPoint]) -> Result<Vec<MergedDescriptor>> {
+    let mut descs = BTreeMap::<DescriptorBinding, MergedDescriptor>::new();
+    for entry_point in entry_points.iter() {
+        for var in entry_point.vars.iter() {
+            if let Variable::Descriptor {
+                name,
+                desc_bind,
+                desc_ty,
+                ty,
+                nbind,
+            } = var
+            {
+                if let Some(desc) = descs.get_mut(desc_bind) {
+                    desc.merge(entry_point.exec_model, desc_ty, ty, *nbind)?;
+                } else {
+                    let desc = MergedDescriptor {
+                        name: name.clone(),
+                        desc_bind: *desc_bind,
+                        desc_tys: vec![desc_ty.clone()],
+                        tys: vec![ty.clone()],
+                        nbind: *nbind,
+                        exec_models: vec![entry_point.exec_model],
+                    };
+                    descs.insert(*desc_bind, desc);
+                }
+            }
+        }
+    }
+    Ok(descs.into_values().collect())
+}
+
+/// Descriptor resource used by one or more entry points in a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedDescriptor {
+    pub name: Option<String>,
+    pub desc_bind: DescriptorBinding,
+    /// Descriptor types of the resources bound at the binding point, in the
+    /// order they are first seen. Resources of different descriptor types
+    /// can alias a same binding point, e.g., a `Texture2D` and a
+    /// `SamplerState` at the same binding in HLSL, or sampled and storage
+    /// views of one image. Access types of storage resources are unioned
+    /// across the entry points.
+    pub desc_tys: Vec<DescriptorType>,
+    /// Types of the resources bound at the binding point, in the order they
+    /// are first seen. Resources of different layouts can alias a same
+    /// binding point, e.g., storage buffers of different block structs, in
+    /// which case each layout is listed once. Debug names are not compared,
+    /// so the first name given to a layout is kept.
+    pub tys: Vec<Type>,
+    pub nbind: u32,
+    /// Execution models of the entry points using the descriptor, in the
+    /// order the entry points are reflected.
+    pub exec_models: Vec<ExecutionModel>,
+}
+impl MergedDescriptor {
+    /// `VkShaderStageFlags` of all the entry points using the descriptor.
+    pub fn stage_flags(&self) -> u32 {
+        self.exec_models
+            .iter()
+            .fold(0, |seed, x| seed | vk_stage_flags(*x))
+    }
+    fn merge(
+        &mut self,
+        exec_model: ExecutionModel,
+        desc_ty: &DescriptorType,
+        ty: &Type,
+        nbind: u32,
+    ) -> Result<()> {
+        fn merge_access(a: AccessType, b: AccessType) -> AccessType {
+            if a == b {
+                a
+            } else {
+                AccessType::ReadWrite
+            }
+        }
+        fn merge_desc_ty(a: &DescriptorType, b: &DescriptorType) -> Option<DescriptorType> {
+            let out = match (a, b) {
+                (DescriptorType::StorageBuffer(a), DescriptorType::StorageBuffer(b)) => {
+                    DescriptorType::StorageBuffer(merge_access(*a, *b))
+                }
+                (DescriptorType::StorageImage(a), DescriptorType::StorageImage(b)) => {
+                    DescriptorType::StorageImage(merge_access(*a, *b))
+                }
+                (DescriptorType::StorageTexelBuffer(a), DescriptorType::StorageTexelBuffer(b)) => {
+                    DescriptorType::StorageTexelBuffer(merge_access(*a, *b))
+                }
+                (a, b) if a == b => a.clone(),
+                _ => return None,
+            };
+            Some(out)
+        }
+        if self.nbind != nbind {
+            return Err(anyhow!(
+                "descriptor count conflict at {}: {} and {}",
+                self.desc_bind,
+                self.nbind,
+                nbind
+            ));
+        }
+        let merged = self
+            .desc_tys
+            .iter_mut()
+            .find_map(|x| merge_desc_ty(x, desc_ty).map(|merged| (x, merged)));
+        match merged {
+            Some((x, merged)) => *x = merged,
+            None => self.desc_tys.push(desc_ty.clone()),
+        }
+        if !self.tys.iter().any(|x| is_layout_eq(x, ty)) {
+            self.tys.push(ty.clone());
+        }
+        if !self.exec_models.contains(&exec_model) {
+            self.exec_models.push(exec_model);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,3596 @@
+//! Operand kinds of SPIR-V instructions, as is specified in the SPIR-V
+//! grammar. Generated by `scripts/generate_operand_kinds.py` from
+//! `assets/spirv/spirv.core.grammar.json` (SPIR-V 1.6 revision 1).
+//! Don't edit by hand.
+
+/// Kind of an operand, as far as the words it takes and the IDs in it are
+/// concerned. Enums without parameters are simply [`OperandKind::Word`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OperandKind {
+    IdResultType,
+    IdResult,
+    /// Any other ID, e.g., `IdRef`, `IdScope` and `IdMemorySemantics`.
+    Id,
+    /// A single literal word, or an enumerant without parameters.
+    Word,
+    /// A nul-terminated UTF-8 string padded to words.
+    String,
+    /// A literal number taking the rest of the instruction, whose width
+    /// depends on the result type, e.g., the value of `OpConstant`.
+    ContextDependentNumber,
+    /// The opcode of `OpSpecConstantOp`, followed by the operands of the
+    /// opcode except the result type and the result ID.
+    SpecConstantOpInteger,
+    /// A literal and an ID, e.g., a case of `OpSwitch`. The literal is as
+    /// wide as the selector.
+    PairWordId,
+    PairIdWord,
+    PairIdId,
+    /// `Decoration` enumerant followed by its parameters.
+    Decoration,
+    /// `ExecutionMode` enumerant followed by its parameters.
+    ExecutionMode,
+    /// `ImageOperands` bit mask followed by the parameters of each set bit in
+    /// ascending order.
+    ImageOperands,
+    /// `LoopControl` bit mask followed by the parameters of each set bit in
+    /// ascending order.
+    LoopControl,
+    /// `MemoryAccess` bit mask followed by the parameters of each set bit in
+    /// ascending order.
+    MemoryAccess,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Quantifier {
+    One,
+    Optional,
+    Variadic,
+}
+
+use OperandKind::*;
+use Quantifier::*;
+
+/// Operands of the instruction of `opcode`. Returns `None` if the opcode is
+/// not known by the grammar.
+pub(crate) fn operand_kinds(opcode: u32) -> Option<&'static [(OperandKind, Quantifier)]> {
+    let out: &'static [(OperandKind, Quantifier)] = match opcode {
+        // OpNop
+        0 => &[],
+        // OpUndef
+        1 => &[(IdResultType, One), (IdResult, One)],
+        // OpSourceContinued
+        2 => &[(String, One)],
+        // OpSource
+        3 => &[(Word, One), (Word, One), (Id, Optional), (String, Optional)],
+        // OpSourceExtension
+        4 => &[(String, One)],
+        // OpName
+        5 => &[(Id, One), (String, One)],
+        // OpMemberName
+        6 => &[(Id, One), (Word, One), (String, One)],
+        // OpString
+        7 => &[(IdResult, One), (String, One)],
+        // OpLine
+        8 => &[(Id, One), (Word, One), (Word, One)],
+        // OpExtension
+        10 => &[(String, One)],
+        // OpExtInstImport
+        11 => &[(IdResult, One), (String, One)],
+        // OpExtInst
+        12 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, Variadic),
+        ],
+        // OpMemoryModel
+        14 => &[(Word, One), (Word, One)],
+        // OpEntryPoint
+        15 => &[(Word, One), (Id, One), (String, One), (Id, Variadic)],
+        // OpExecutionMode
+        16 => &[(Id, One), (ExecutionMode, One)],
+        // OpCapability
+        17 => &[(Word, One)],
+        // OpTypeVoid
+        19 => &[(IdResult, One)],
+        // OpTypeBool
+        20 => &[(IdResult, One)],
+        // OpTypeInt
+        21 => &[(IdResult, One), (Word, One), (Word, One)],
+        // OpTypeFloat
+        22 => &[(IdResult, One), (Word, One)],
+        // OpTypeVector
+        23 => &[(IdResult, One), (Id, One), (Word, One)],
+        // OpTypeMatrix
+        24 => &[(IdResult, One), (Id, One), (Word, One)],
+        // OpTypeImage
+        25 => &[
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, Optional),
+        ],
+        // OpTypeSampler
+        26 => &[(IdResult, One)],
+        // OpTypeSampledImage
+        27 => &[(IdResult, One), (Id, One)],
+        // OpTypeArray
+        28 => &[(IdResult, One), (Id, One), (Id, One)],
+        // OpTypeRuntimeArray
+        29 => &[(IdResult, One), (Id, One)],
+        // OpTypeStruct
+        30 => &[(IdResult, One), (Id, Variadic)],
+        // OpTypeOpaque
+        31 => &[(IdResult, One), (String, One)],
+        // OpTypePointer
+        32 => &[(IdResult, One), (Word, One), (Id, One)],
+        // OpTypeFunction
+        33 => &[(IdResult, One), (Id, One), (Id, Variadic)],
+        // OpTypeEvent
+        34 => &[(IdResult, One)],
+        // OpTypeDeviceEvent
+        35 => &[(IdResult, One)],
+        // OpTypeReserveId
+        36 => &[(IdResult, One)],
+        // OpTypeQueue
+        37 => &[(IdResult, One)],
+        // OpTypePipe
+        38 => &[(IdResult, One), (Word, One)],
+        // OpTypeForwardPointer
+        39 => &[(Id, One), (Word, One)],
+        // OpConstantTrue
+        41 => &[(IdResultType, One), (IdResult, One)],
+        // OpConstantFalse
+        42 => &[(IdResultType, One), (IdResult, One)],
+        // OpConstant
+        43 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (ContextDependentNumber, One),
+        ],
+        // OpConstantComposite
+        44 => &[(IdResultType, One), (IdResult, One), (Id, Variadic)],
+        // OpConstantSampler
+        45 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpConstantNull
+        46 => &[(IdResultType, One), (IdResult, One)],
+        // OpSpecConstantTrue
+        48 => &[(IdResultType, One), (IdResult, One)],
+        // OpSpecConstantFalse
+        49 => &[(IdResultType, One), (IdResult, One)],
+        // OpSpecConstant
+        50 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (ContextDependentNumber, One),
+        ],
+        // OpSpecConstantComposite
+        51 => &[(IdResultType, One), (IdResult, One), (Id, Variadic)],
+        // OpSpecConstantOp
+        52 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (SpecConstantOpInteger, One),
+        ],
+        // OpFunction
+        54 => &[(IdResultType, One), (IdResult, One), (Word, One), (Id, One)],
+        // OpFunctionParameter
+        55 => &[(IdResultType, One), (IdResult, One)],
+        // OpFunctionEnd
+        56 => &[],
+        // OpFunctionCall
+        57 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpVariable
+        59 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Word, One),
+            (Id, Optional),
+        ],
+        // OpImageTexelPointer
+        60 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpLoad
+        61 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (MemoryAccess, Optional),
+        ],
+        // OpStore
+        62 => &[(Id, One), (Id, One), (MemoryAccess, Optional)],
+        // OpCopyMemory
+        63 => &[
+            (Id, One),
+            (Id, One),
+            (MemoryAccess, Optional),
+            (MemoryAccess, Optional),
+        ],
+        // OpCopyMemorySized
+        64 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (MemoryAccess, Optional),
+            (MemoryAccess, Optional),
+        ],
+        // OpAccessChain
+        65 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpInBoundsAccessChain
+        66 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpPtrAccessChain
+        67 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpArrayLength
+        68 => &[(IdResultType, One), (IdResult, One), (Id, One), (Word, One)],
+        // OpGenericPtrMemSemantics
+        69 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpInBoundsPtrAccessChain
+        70 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpDecorate
+        71 => &[(Id, One), (Decoration, One)],
+        // OpMemberDecorate
+        72 => &[(Id, One), (Word, One), (Decoration, One)],
+        // OpDecorationGroup
+        73 => &[(IdResult, One)],
+        // OpGroupDecorate
+        74 => &[(Id, One), (Id, Variadic)],
+        // OpGroupMemberDecorate
+        75 => &[(Id, One), (PairIdWord, Variadic)],
+        // OpVectorExtractDynamic
+        77 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpVectorInsertDynamic
+        78 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpVectorShuffle
+        79 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, Variadic),
+        ],
+        // OpCompositeConstruct
+        80 => &[(IdResultType, One), (IdResult, One), (Id, Variadic)],
+        // OpCompositeExtract
+        81 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, Variadic),
+        ],
+        // OpCompositeInsert
+        82 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, Variadic),
+        ],
+        // OpCopyObject
+        83 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpTranspose
+        84 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSampledImage
+        86 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpImageSampleImplicitLod
+        87 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSampleExplicitLod
+        88 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSampleDrefImplicitLod
+        89 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSampleDrefExplicitLod
+        90 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSampleProjImplicitLod
+        91 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSampleProjExplicitLod
+        92 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSampleProjDrefImplicitLod
+        93 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSampleProjDrefExplicitLod
+        94 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageFetch
+        95 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageGather
+        96 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageDrefGather
+        97 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageRead
+        98 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageWrite
+        99 => &[(Id, One), (Id, One), (Id, One), (ImageOperands, Optional)],
+        // OpImage
+        100 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpImageQueryFormat
+        101 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpImageQueryOrder
+        102 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpImageQuerySizeLod
+        103 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpImageQuerySize
+        104 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpImageQueryLod
+        105 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpImageQueryLevels
+        106 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpImageQuerySamples
+        107 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertFToU
+        109 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertFToS
+        110 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertSToF
+        111 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertUToF
+        112 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpUConvert
+        113 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSConvert
+        114 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFConvert
+        115 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpQuantizeToF16
+        116 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertPtrToU
+        117 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSatConvertSToU
+        118 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSatConvertUToS
+        119 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertUToPtr
+        120 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpPtrCastToGeneric
+        121 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGenericCastToPtr
+        122 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGenericCastToPtrExplicit
+        123 => &[(IdResultType, One), (IdResult, One), (Id, One), (Word, One)],
+        // OpBitcast
+        124 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSNegate
+        126 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFNegate
+        127 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIAdd
+        128 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFAdd
+        129 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpISub
+        130 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFSub
+        131 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIMul
+        132 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFMul
+        133 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUDiv
+        134 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSDiv
+        135 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFDiv
+        136 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUMod
+        137 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSRem
+        138 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSMod
+        139 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFRem
+        140 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFMod
+        141 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpVectorTimesScalar
+        142 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpMatrixTimesScalar
+        143 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpVectorTimesMatrix
+        144 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpMatrixTimesVector
+        145 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpMatrixTimesMatrix
+        146 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpOuterProduct
+        147 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpDot
+        148 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIAddCarry
+        149 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpISubBorrow
+        150 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUMulExtended
+        151 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSMulExtended
+        152 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpAny
+        154 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpAll
+        155 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIsNan
+        156 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIsInf
+        157 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIsFinite
+        158 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIsNormal
+        159 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSignBitSet
+        160 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpLessOrGreater
+        161 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpOrdered
+        162 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUnordered
+        163 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpLogicalEqual
+        164 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpLogicalNotEqual
+        165 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpLogicalOr
+        166 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpLogicalAnd
+        167 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpLogicalNot
+        168 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSelect
+        169 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpIEqual
+        170 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpINotEqual
+        171 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUGreaterThan
+        172 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSGreaterThan
+        173 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUGreaterThanEqual
+        174 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSGreaterThanEqual
+        175 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpULessThan
+        176 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSLessThan
+        177 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpULessThanEqual
+        178 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSLessThanEqual
+        179 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdEqual
+        180 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordEqual
+        181 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdNotEqual
+        182 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordNotEqual
+        183 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdLessThan
+        184 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordLessThan
+        185 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdGreaterThan
+        186 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordGreaterThan
+        187 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdLessThanEqual
+        188 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordLessThanEqual
+        189 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFOrdGreaterThanEqual
+        190 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFUnordGreaterThanEqual
+        191 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpShiftRightLogical
+        194 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpShiftRightArithmetic
+        195 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpShiftLeftLogical
+        196 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpBitwiseOr
+        197 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpBitwiseXor
+        198 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpBitwiseAnd
+        199 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpNot
+        200 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpBitFieldInsert
+        201 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpBitFieldSExtract
+        202 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpBitFieldUExtract
+        203 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpBitReverse
+        204 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpBitCount
+        205 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdx
+        207 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdy
+        208 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFwidth
+        209 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdxFine
+        210 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdyFine
+        211 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFwidthFine
+        212 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdxCoarse
+        213 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpDPdyCoarse
+        214 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFwidthCoarse
+        215 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpEmitVertex
+        218 => &[],
+        // OpEndPrimitive
+        219 => &[],
+        // OpEmitStreamVertex
+        220 => &[(Id, One)],
+        // OpEndStreamPrimitive
+        221 => &[(Id, One)],
+        // OpControlBarrier
+        224 => &[(Id, One), (Id, One), (Id, One)],
+        // OpMemoryBarrier
+        225 => &[(Id, One), (Id, One)],
+        // OpAtomicLoad
+        227 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicStore
+        228 => &[(Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpAtomicExchange
+        229 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicCompareExchange
+        230 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicCompareExchangeWeak
+        231 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicIIncrement
+        232 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicIDecrement
+        233 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicIAdd
+        234 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicISub
+        235 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicSMin
+        236 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicUMin
+        237 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicSMax
+        238 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicUMax
+        239 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicAnd
+        240 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicOr
+        241 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicXor
+        242 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpPhi
+        245 => &[(IdResultType, One), (IdResult, One), (PairIdId, Variadic)],
+        // OpLoopMerge
+        246 => &[(Id, One), (Id, One), (LoopControl, One)],
+        // OpSelectionMerge
+        247 => &[(Id, One), (Word, One)],
+        // OpLabel
+        248 => &[(IdResult, One)],
+        // OpBranch
+        249 => &[(Id, One)],
+        // OpBranchConditional
+        250 => &[(Id, One), (Id, One), (Id, One), (Word, Variadic)],
+        // OpSwitch
+        251 => &[(Id, One), (Id, One), (PairWordId, Variadic)],
+        // OpKill
+        252 => &[],
+        // OpReturn
+        253 => &[],
+        // OpReturnValue
+        254 => &[(Id, One)],
+        // OpUnreachable
+        255 => &[],
+        // OpLifetimeStart
+        256 => &[(Id, One), (Word, One)],
+        // OpLifetimeStop
+        257 => &[(Id, One), (Word, One)],
+        // OpGroupAsyncCopy
+        259 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupWaitEvents
+        260 => &[(Id, One), (Id, One), (Id, One)],
+        // OpGroupAll
+        261 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupAny
+        262 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupBroadcast
+        263 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupIAdd
+        264 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFAdd
+        265 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFMin
+        266 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupUMin
+        267 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupSMin
+        268 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFMax
+        269 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupUMax
+        270 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupSMax
+        271 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpReadPipe
+        274 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpWritePipe
+        275 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReservedReadPipe
+        276 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReservedWritePipe
+        277 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReserveReadPipePackets
+        278 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReserveWritePipePackets
+        279 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpCommitReadPipe
+        280 => &[(Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpCommitWritePipe
+        281 => &[(Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpIsValidReserveId
+        282 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGetNumPipePackets
+        283 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGetMaxPipePackets
+        284 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupReserveReadPipePackets
+        285 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupReserveWritePipePackets
+        286 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupCommitReadPipe
+        287 => &[(Id, One), (Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpGroupCommitWritePipe
+        288 => &[(Id, One), (Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpEnqueueMarker
+        291 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpEnqueueKernel
+        292 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpGetKernelNDrangeSubGroupCount
+        293 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGetKernelNDrangeMaxSubGroupSize
+        294 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGetKernelWorkGroupSize
+        295 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGetKernelPreferredWorkGroupSizeMultiple
+        296 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpRetainEvent
+        297 => &[(Id, One)],
+        // OpReleaseEvent
+        298 => &[(Id, One)],
+        // OpCreateUserEvent
+        299 => &[(IdResultType, One), (IdResult, One)],
+        // OpIsValidEvent
+        300 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSetUserEventStatus
+        301 => &[(Id, One), (Id, One)],
+        // OpCaptureEventProfilingInfo
+        302 => &[(Id, One), (Id, One), (Id, One)],
+        // OpGetDefaultQueue
+        303 => &[(IdResultType, One), (IdResult, One)],
+        // OpBuildNDRange
+        304 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpImageSparseSampleImplicitLod
+        305 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseSampleExplicitLod
+        306 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSparseSampleDrefImplicitLod
+        307 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseSampleDrefExplicitLod
+        308 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSparseSampleProjImplicitLod
+        309 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseSampleProjExplicitLod
+        310 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSparseSampleProjDrefImplicitLod
+        311 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseSampleProjDrefExplicitLod
+        312 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, One),
+        ],
+        // OpImageSparseFetch
+        313 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseGather
+        314 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseDrefGather
+        315 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpImageSparseTexelsResident
+        316 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpNoLine
+        317 => &[],
+        // OpAtomicFlagTestAndSet
+        318 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicFlagClear
+        319 => &[(Id, One), (Id, One), (Id, One)],
+        // OpImageSparseRead
+        320 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpSizeOf
+        321 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpTypePipeStorage
+        322 => &[(IdResult, One)],
+        // OpConstantPipeStorage
+        323 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpCreatePipeFromPipeStorage
+        324 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGetKernelLocalSizeForSubgroupCount
+        325 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGetKernelMaxNumSubgroups
+        326 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpTypeNamedBarrier
+        327 => &[(IdResult, One)],
+        // OpNamedBarrierInitialize
+        328 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpMemoryNamedBarrier
+        329 => &[(Id, One), (Id, One), (Id, One)],
+        // OpModuleProcessed
+        330 => &[(String, One)],
+        // OpExecutionModeId
+        331 => &[(Id, One), (ExecutionMode, One)],
+        // OpDecorateId
+        332 => &[(Id, One), (Decoration, One)],
+        // OpGroupNonUniformElect
+        333 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGroupNonUniformAll
+        334 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformAny
+        335 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformAllEqual
+        336 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformBroadcast
+        337 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformBroadcastFirst
+        338 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformBallot
+        339 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformInverseBallot
+        340 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformBallotBitExtract
+        341 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformBallotBitCount
+        342 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformBallotFindLSB
+        343 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformBallotFindMSB
+        344 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpGroupNonUniformShuffle
+        345 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformShuffleXor
+        346 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformShuffleUp
+        347 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformShuffleDown
+        348 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformIAdd
+        349 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformFAdd
+        350 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformIMul
+        351 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformFMul
+        352 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformSMin
+        353 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformUMin
+        354 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformFMin
+        355 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformSMax
+        356 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformUMax
+        357 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformFMax
+        358 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformBitwiseAnd
+        359 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformBitwiseOr
+        360 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformBitwiseXor
+        361 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformLogicalAnd
+        362 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformLogicalOr
+        363 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformLogicalXor
+        364 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpGroupNonUniformQuadBroadcast
+        365 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupNonUniformQuadSwap
+        366 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpCopyLogical
+        400 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpPtrEqual
+        401 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpPtrNotEqual
+        402 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpPtrDiff
+        403 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpColorAttachmentReadEXT
+        4160 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpDepthAttachmentReadEXT
+        4161 => &[(IdResultType, One), (IdResult, One), (Id, Optional)],
+        // OpStencilAttachmentReadEXT
+        4162 => &[(IdResultType, One), (IdResult, One), (Id, Optional)],
+        // OpTerminateInvocation
+        4416 => &[],
+        // OpSubgroupBallotKHR
+        4421 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupFirstInvocationKHR
+        4422 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAllKHR
+        4428 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAnyKHR
+        4429 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAllEqualKHR
+        4430 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpGroupNonUniformRotateKHR
+        4431 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, Optional),
+        ],
+        // OpSubgroupReadInvocationKHR
+        4432 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpTraceRayKHR
+        4445 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpExecuteCallableKHR
+        4446 => &[(Id, One), (Id, One)],
+        // OpConvertUToAccelerationStructureKHR
+        4447 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpIgnoreIntersectionKHR
+        4448 => &[],
+        // OpTerminateRayKHR
+        4449 => &[],
+        // OpSDot
+        4450 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpUDot
+        4451 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpSUDot
+        4452 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpSDotAccSat
+        4453 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpUDotAccSat
+        4454 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpSUDotAccSat
+        4455 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpTypeCooperativeMatrixKHR
+        4456 => &[
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpCooperativeMatrixLoadKHR
+        4457 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, Optional),
+            (MemoryAccess, Optional),
+        ],
+        // OpCooperativeMatrixStoreKHR
+        4458 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, Optional),
+            (MemoryAccess, Optional),
+        ],
+        // OpCooperativeMatrixMulAddKHR
+        4459 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Word, Optional),
+        ],
+        // OpCooperativeMatrixLengthKHR
+        4460 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpTypeRayQueryKHR
+        4472 => &[(IdResult, One)],
+        // OpRayQueryInitializeKHR
+        4473 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpRayQueryTerminateKHR
+        4474 => &[(Id, One)],
+        // OpRayQueryGenerateIntersectionKHR
+        4475 => &[(Id, One), (Id, One)],
+        // OpRayQueryConfirmIntersectionKHR
+        4476 => &[(Id, One)],
+        // OpRayQueryProceedKHR
+        4477 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetIntersectionTypeKHR
+        4479 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpImageSampleWeightedQCOM
+        4480 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpImageBoxFilterQCOM
+        4481 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpImageBlockMatchSSDQCOM
+        4482 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpImageBlockMatchSADQCOM
+        4483 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpGroupIAddNonUniformAMD
+        5000 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFAddNonUniformAMD
+        5001 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFMinNonUniformAMD
+        5002 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupUMinNonUniformAMD
+        5003 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupSMinNonUniformAMD
+        5004 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFMaxNonUniformAMD
+        5005 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupUMaxNonUniformAMD
+        5006 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupSMaxNonUniformAMD
+        5007 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpFragmentMaskFetchAMD
+        5011 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFragmentFetchAMD
+        5012 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReadClockKHR
+        5056 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFinalizeNodePayloadsAMDX
+        5075 => &[(Id, One)],
+        // OpFinishWritingNodePayloadAMDX
+        5078 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpInitializeNodePayloadsAMDX
+        5090 => &[(Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpHitObjectRecordHitMotionNV
+        5249 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectRecordHitWithIndexMotionNV
+        5250 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectRecordMissMotionNV
+        5251 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectGetWorldToObjectNV
+        5252 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetObjectToWorldNV
+        5253 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetObjectRayDirectionNV
+        5254 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetObjectRayOriginNV
+        5255 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectTraceRayMotionNV
+        5256 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectGetShaderRecordBufferHandleNV
+        5257 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetShaderBindingTableRecordIndexNV
+        5258 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectRecordEmptyNV
+        5259 => &[(Id, One)],
+        // OpHitObjectTraceRayNV
+        5260 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectRecordHitNV
+        5261 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectRecordHitWithIndexNV
+        5262 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectRecordMissNV
+        5263 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpHitObjectExecuteShaderNV
+        5264 => &[(Id, One), (Id, One)],
+        // OpHitObjectGetCurrentTimeNV
+        5265 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetAttributesNV
+        5266 => &[(Id, One), (Id, One)],
+        // OpHitObjectGetHitKindNV
+        5267 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetPrimitiveIndexNV
+        5268 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetGeometryIndexNV
+        5269 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetInstanceIdNV
+        5270 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetInstanceCustomIndexNV
+        5271 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetWorldRayDirectionNV
+        5272 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetWorldRayOriginNV
+        5273 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetRayTMaxNV
+        5274 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectGetRayTMinNV
+        5275 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectIsEmptyNV
+        5276 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectIsHitNV
+        5277 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpHitObjectIsMissNV
+        5278 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpReorderThreadWithHitObjectNV
+        5279 => &[(Id, One), (Id, Optional), (Id, Optional)],
+        // OpReorderThreadWithHintNV
+        5280 => &[(Id, One), (Id, One)],
+        // OpTypeHitObjectNV
+        5281 => &[(IdResult, One)],
+        // OpImageSampleFootprintNV
+        5283 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (ImageOperands, Optional),
+        ],
+        // OpEmitMeshTasksEXT
+        5294 => &[(Id, One), (Id, One), (Id, One), (Id, Optional)],
+        // OpSetMeshOutputsEXT
+        5295 => &[(Id, One), (Id, One)],
+        // OpGroupNonUniformPartitionNV
+        5296 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpWritePackedPrimitiveIndices4x8NV
+        5299 => &[(Id, One), (Id, One)],
+        // OpFetchMicroTriangleVertexPositionNV
+        5300 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpFetchMicroTriangleVertexBarycentricNV
+        5301 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpReportIntersectionNV
+        5334 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIgnoreIntersectionNV
+        5335 => &[],
+        // OpTerminateRayNV
+        5336 => &[],
+        // OpTraceNV
+        5337 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpTraceMotionNV
+        5338 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpTraceRayMotionNV
+        5339 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpRayQueryGetIntersectionTriangleVertexPositionsKHR
+        5340 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpTypeAccelerationStructureNV
+        5341 => &[(IdResult, One)],
+        // OpExecuteCallableNV
+        5344 => &[(Id, One), (Id, One)],
+        // OpTypeCooperativeMatrixNV
+        5358 => &[(IdResult, One), (Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpCooperativeMatrixLoadNV
+        5359 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (MemoryAccess, Optional),
+        ],
+        // OpCooperativeMatrixStoreNV
+        5360 => &[
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (MemoryAccess, Optional),
+        ],
+        // OpCooperativeMatrixMulAddNV
+        5361 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpCooperativeMatrixLengthNV
+        5362 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpBeginInvocationInterlockEXT
+        5364 => &[],
+        // OpEndInvocationInterlockEXT
+        5365 => &[],
+        // OpDemoteToHelperInvocation
+        5380 => &[],
+        // OpIsHelperInvocationEXT
+        5381 => &[(IdResultType, One), (IdResult, One)],
+        // OpConvertUToImageNV
+        5391 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertUToSamplerNV
+        5392 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertImageToUNV
+        5393 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertSamplerToUNV
+        5394 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertUToSampledImageNV
+        5395 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertSampledImageToUNV
+        5396 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSamplerImageAddressingModeNV
+        5397 => &[(Word, One)],
+        // OpSubgroupShuffleINTEL
+        5571 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupShuffleDownINTEL
+        5572 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupShuffleUpINTEL
+        5573 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupShuffleXorINTEL
+        5574 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupBlockReadINTEL
+        5575 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupBlockWriteINTEL
+        5576 => &[(Id, One), (Id, One)],
+        // OpSubgroupImageBlockReadINTEL
+        5577 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupImageBlockWriteINTEL
+        5578 => &[(Id, One), (Id, One), (Id, One)],
+        // OpSubgroupImageMediaBlockReadINTEL
+        5580 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupImageMediaBlockWriteINTEL
+        5581 => &[(Id, One), (Id, One), (Id, One), (Id, One), (Id, One)],
+        // OpUCountLeadingZerosINTEL
+        5585 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpUCountTrailingZerosINTEL
+        5586 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpAbsISubINTEL
+        5587 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpAbsUSubINTEL
+        5588 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIAddSatINTEL
+        5589 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUAddSatINTEL
+        5590 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIAverageINTEL
+        5591 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUAverageINTEL
+        5592 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIAverageRoundedINTEL
+        5593 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUAverageRoundedINTEL
+        5594 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpISubSatINTEL
+        5595 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUSubSatINTEL
+        5596 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpIMul32x16INTEL
+        5597 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpUMul32x16INTEL
+        5598 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpConstantFunctionPointerINTEL
+        5600 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpFunctionPointerCallINTEL
+        5601 => &[(IdResultType, One), (IdResult, One), (Id, Variadic)],
+        // OpAsmTargetINTEL
+        5609 => &[(IdResultType, One), (IdResult, One), (String, One)],
+        // OpAsmINTEL
+        5610 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (String, One),
+            (String, One),
+        ],
+        // OpAsmCallINTEL
+        5611 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, Variadic),
+        ],
+        // OpAtomicFMinEXT
+        5614 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAtomicFMaxEXT
+        5615 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpAssumeTrueKHR
+        5630 => &[(Id, One)],
+        // OpExpectKHR
+        5631 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpDecorateString
+        5632 => &[(Id, One), (Decoration, One)],
+        // OpMemberDecorateString
+        5633 => &[(Id, One), (Word, One), (Decoration, One)],
+        // OpVmeImageINTEL
+        5699 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpTypeVmeImageINTEL
+        5700 => &[(IdResult, One), (Id, One)],
+        // OpTypeAvcImePayloadINTEL
+        5701 => &[(IdResult, One)],
+        // OpTypeAvcRefPayloadINTEL
+        5702 => &[(IdResult, One)],
+        // OpTypeAvcSicPayloadINTEL
+        5703 => &[(IdResult, One)],
+        // OpTypeAvcMcePayloadINTEL
+        5704 => &[(IdResult, One)],
+        // OpTypeAvcMceResultINTEL
+        5705 => &[(IdResult, One)],
+        // OpTypeAvcImeResultINTEL
+        5706 => &[(IdResult, One)],
+        // OpTypeAvcImeResultSingleReferenceStreamoutINTEL
+        5707 => &[(IdResult, One)],
+        // OpTypeAvcImeResultDualReferenceStreamoutINTEL
+        5708 => &[(IdResult, One)],
+        // OpTypeAvcImeSingleReferenceStreaminINTEL
+        5709 => &[(IdResult, One)],
+        // OpTypeAvcImeDualReferenceStreaminINTEL
+        5710 => &[(IdResult, One)],
+        // OpTypeAvcRefResultINTEL
+        5711 => &[(IdResult, One)],
+        // OpTypeAvcSicResultINTEL
+        5712 => &[(IdResult, One)],
+        // OpSubgroupAvcMceGetDefaultInterBaseMultiReferencePenaltyINTEL
+        5713 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceSetInterBaseMultiReferencePenaltyINTEL
+        5714 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultInterShapePenaltyINTEL
+        5715 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceSetInterShapePenaltyINTEL
+        5716 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultInterDirectionPenaltyINTEL
+        5717 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceSetInterDirectionPenaltyINTEL
+        5718 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultIntraLumaShapePenaltyINTEL
+        5719 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultInterMotionVectorCostTableINTEL
+        5720 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultHighPenaltyCostTableINTEL
+        5721 => &[(IdResultType, One), (IdResult, One)],
+        // OpSubgroupAvcMceGetDefaultMediumPenaltyCostTableINTEL
+        5722 => &[(IdResultType, One), (IdResult, One)],
+        // OpSubgroupAvcMceGetDefaultLowPenaltyCostTableINTEL
+        5723 => &[(IdResultType, One), (IdResult, One)],
+        // OpSubgroupAvcMceSetMotionVectorCostFunctionINTEL
+        5724 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcMceGetDefaultIntraLumaModePenaltyINTEL
+        5725 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceGetDefaultNonDcLumaIntraPenaltyINTEL
+        5726 => &[(IdResultType, One), (IdResult, One)],
+        // OpSubgroupAvcMceGetDefaultIntraChromaModeBasePenaltyINTEL
+        5727 => &[(IdResultType, One), (IdResult, One)],
+        // OpSubgroupAvcMceSetAcOnlyHaarINTEL
+        5728 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceSetSourceInterlacedFieldPolarityINTEL
+        5729 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceSetSingleReferenceInterlacedFieldPolarityINTEL
+        5730 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcMceSetDualReferenceInterlacedFieldPolaritiesINTEL
+        5731 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcMceConvertToImePayloadINTEL
+        5732 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceConvertToImeResultINTEL
+        5733 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceConvertToRefPayloadINTEL
+        5734 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceConvertToRefResultINTEL
+        5735 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceConvertToSicPayloadINTEL
+        5736 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceConvertToSicResultINTEL
+        5737 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetMotionVectorsINTEL
+        5738 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterDistortionsINTEL
+        5739 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetBestInterDistortionsINTEL
+        5740 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterMajorShapeINTEL
+        5741 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterMinorShapeINTEL
+        5742 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterDirectionsINTEL
+        5743 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterMotionVectorCountINTEL
+        5744 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterReferenceIdsINTEL
+        5745 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcMceGetInterReferenceInterlacedFieldPolaritiesINTEL
+        5746 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeInitializeINTEL
+        5747 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeSetSingleReferenceINTEL
+        5748 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeSetDualReferenceINTEL
+        5749 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeRefWindowSizeINTEL
+        5750 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeAdjustRefOffsetINTEL
+        5751 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeConvertToMcePayloadINTEL
+        5752 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeSetMaxMotionVectorCountINTEL
+        5753 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeSetUnidirectionalMixDisableINTEL
+        5754 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeSetEarlySearchTerminationThresholdINTEL
+        5755 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeSetWeightedSadINTEL
+        5756 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeEvaluateWithSingleReferenceINTEL
+        5757 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithDualReferenceINTEL
+        5758 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminINTEL
+        5759 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithDualReferenceStreaminINTEL
+        5760 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithSingleReferenceStreamoutINTEL
+        5761 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithDualReferenceStreamoutINTEL
+        5762 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithSingleReferenceStreaminoutINTEL
+        5763 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeEvaluateWithDualReferenceStreaminoutINTEL
+        5764 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeConvertToMceResultINTEL
+        5765 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetSingleReferenceStreaminINTEL
+        5766 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetDualReferenceStreaminINTEL
+        5767 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeStripSingleReferenceStreamoutINTEL
+        5768 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeStripDualReferenceStreamoutINTEL
+        5769 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeMotionVectorsINTEL
+        5770 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeDistortionsINTEL
+        5771 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeGetStreamoutSingleReferenceMajorShapeReferenceIdsINTEL
+        5772 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeMotionVectorsINTEL
+        5773 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeDistortionsINTEL
+        5774 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeGetStreamoutDualReferenceMajorShapeReferenceIdsINTEL
+        5775 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcImeGetBorderReachedINTEL
+        5776 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcImeGetTruncatedSearchIndicationINTEL
+        5777 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetUnidirectionalEarlySearchTerminationINTEL
+        5778 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetWeightingPatternMinimumMotionVectorINTEL
+        5779 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcImeGetWeightingPatternMinimumDistortionINTEL
+        5780 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcFmeInitializeINTEL
+        5781 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcBmeInitializeINTEL
+        5782 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcRefConvertToMcePayloadINTEL
+        5783 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcRefSetBidirectionalMixDisableINTEL
+        5784 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcRefSetBilinearFilterEnableINTEL
+        5785 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcRefEvaluateWithSingleReferenceINTEL
+        5786 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcRefEvaluateWithDualReferenceINTEL
+        5787 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcRefEvaluateWithMultiReferenceINTEL
+        5788 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcRefEvaluateWithMultiReferenceInterlacedINTEL
+        5789 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcRefConvertToMceResultINTEL
+        5790 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicInitializeINTEL
+        5791 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicConfigureSkcINTEL
+        5792 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicConfigureIpeLumaINTEL
+        5793 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicConfigureIpeLumaChromaINTEL
+        5794 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicGetMotionVectorMaskINTEL
+        5795 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicConvertToMcePayloadINTEL
+        5796 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicSetIntraLumaShapePenaltyINTEL
+        5797 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicSetIntraLumaModeCostFunctionINTEL
+        5798 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicSetIntraChromaModeCostFunctionINTEL
+        5799 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicSetBilinearFilterEnableINTEL
+        5800 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicSetSkcForwardTransformEnableINTEL
+        5801 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicSetBlockBasedRawSkipSadINTEL
+        5802 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicEvaluateIpeINTEL
+        5803 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpSubgroupAvcSicEvaluateWithSingleReferenceINTEL
+        5804 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicEvaluateWithDualReferenceINTEL
+        5805 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicEvaluateWithMultiReferenceINTEL
+        5806 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicEvaluateWithMultiReferenceInterlacedINTEL
+        5807 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpSubgroupAvcSicConvertToMceResultINTEL
+        5808 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetIpeLumaShapeINTEL
+        5809 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetBestIpeLumaDistortionINTEL
+        5810 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetBestIpeChromaDistortionINTEL
+        5811 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetPackedIpeLumaModesINTEL
+        5812 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetIpeChromaModeINTEL
+        5813 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetPackedSkcLumaCountThresholdINTEL
+        5814 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetPackedSkcLumaSumThresholdINTEL
+        5815 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSubgroupAvcSicGetInterRawSadsINTEL
+        5816 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpVariableLengthArrayINTEL
+        5818 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpSaveMemoryINTEL
+        5819 => &[(IdResultType, One), (IdResult, One)],
+        // OpRestoreMemoryINTEL
+        5820 => &[(Id, One)],
+        // OpArbitraryFloatSinCosPiINTEL
+        5840 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCastINTEL
+        5841 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCastFromIntINTEL
+        5842 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCastToIntINTEL
+        5843 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatAddINTEL
+        5846 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatSubINTEL
+        5847 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatMulINTEL
+        5848 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatDivINTEL
+        5849 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatGTINTEL
+        5850 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatGEINTEL
+        5851 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLTINTEL
+        5852 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLEINTEL
+        5853 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatEQINTEL
+        5854 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatRecipINTEL
+        5855 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatRSqrtINTEL
+        5856 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCbrtINTEL
+        5857 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatHypotINTEL
+        5858 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatSqrtINTEL
+        5859 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLogINTEL
+        5860 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLog2INTEL
+        5861 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLog10INTEL
+        5862 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatLog1pINTEL
+        5863 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatExpINTEL
+        5864 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatExp2INTEL
+        5865 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatExp10INTEL
+        5866 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatExpm1INTEL
+        5867 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatSinINTEL
+        5868 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCosINTEL
+        5869 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatSinCosINTEL
+        5870 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatSinPiINTEL
+        5871 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatCosPiINTEL
+        5872 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatASinINTEL
+        5873 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatASinPiINTEL
+        5874 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatACosINTEL
+        5875 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatACosPiINTEL
+        5876 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatATanINTEL
+        5877 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatATanPiINTEL
+        5878 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatATan2INTEL
+        5879 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatPowINTEL
+        5880 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatPowRINTEL
+        5881 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpArbitraryFloatPowNINTEL
+        5882 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpLoopControlINTEL
+        5887 => &[(Word, Variadic)],
+        // OpAliasDomainDeclINTEL
+        5911 => &[(IdResult, One), (Id, Optional)],
+        // OpAliasScopeDeclINTEL
+        5912 => &[(IdResult, One), (Id, One), (Id, Optional)],
+        // OpAliasScopeListDeclINTEL
+        5913 => &[(IdResult, One), (Id, Variadic)],
+        // OpFixedSqrtINTEL
+        5923 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedRecipINTEL
+        5924 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedRsqrtINTEL
+        5925 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedSinINTEL
+        5926 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedCosINTEL
+        5927 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedSinCosINTEL
+        5928 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedSinPiINTEL
+        5929 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedCosPiINTEL
+        5930 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedSinCosPiINTEL
+        5931 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedLogINTEL
+        5932 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpFixedExpINTEL
+        5933 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+            (Word, One),
+        ],
+        // OpPtrCastToCrossWorkgroupINTEL
+        5934 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpCrossWorkgroupCastToPtrINTEL
+        5938 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpReadPipeBlockingINTEL
+        5946 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpWritePipeBlockingINTEL
+        5947 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpFPGARegINTEL
+        5949 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetRayTMinKHR
+        6016 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetRayFlagsKHR
+        6017 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetIntersectionTKHR
+        6018 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionInstanceCustomIndexKHR
+        6019 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionInstanceIdKHR
+        6020 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR
+        6021 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionGeometryIndexKHR
+        6022 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionPrimitiveIndexKHR
+        6023 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionBarycentricsKHR
+        6024 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionFrontFaceKHR
+        6025 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionCandidateAABBOpaqueKHR
+        6026 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetIntersectionObjectRayDirectionKHR
+        6027 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionObjectRayOriginKHR
+        6028 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetWorldRayDirectionKHR
+        6029 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetWorldRayOriginKHR
+        6030 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpRayQueryGetIntersectionObjectToWorldKHR
+        6031 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpRayQueryGetIntersectionWorldToObjectKHR
+        6032 => &[(IdResultType, One), (IdResult, One), (Id, One), (Id, One)],
+        // OpAtomicFAddEXT
+        6035 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+            (Id, One),
+        ],
+        // OpTypeBufferSurfaceINTEL
+        6086 => &[(IdResult, One), (Word, One)],
+        // OpTypeStructContinuedINTEL
+        6090 => &[(Id, Variadic)],
+        // OpConstantCompositeContinuedINTEL
+        6091 => &[(Id, Variadic)],
+        // OpSpecConstantCompositeContinuedINTEL
+        6092 => &[(Id, Variadic)],
+        // OpCompositeConstructContinuedINTEL
+        6096 => &[(IdResultType, One), (IdResult, One), (Id, Variadic)],
+        // OpConvertFToBF16INTEL
+        6116 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpConvertBF16ToFINTEL
+        6117 => &[(IdResultType, One), (IdResult, One), (Id, One)],
+        // OpControlBarrierArriveINTEL
+        6142 => &[(Id, One), (Id, One), (Id, One)],
+        // OpControlBarrierWaitINTEL
+        6143 => &[(Id, One), (Id, One), (Id, One)],
+        // OpGroupIMulKHR
+        6401 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupFMulKHR
+        6402 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupBitwiseAndKHR
+        6403 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupBitwiseOrKHR
+        6404 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupBitwiseXorKHR
+        6405 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupLogicalAndKHR
+        6406 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupLogicalOrKHR
+        6407 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        // OpGroupLogicalXorKHR
+        6408 => &[
+            (IdResultType, One),
+            (IdResult, One),
+            (Id, One),
+            (Word, One),
+            (Id, One),
+        ],
+        _ => return None,
+    };
+    Some(out)
+}
+
+/// Parameters following the enum operand of `kind` and `value`. For bit
+/// enums, `value` is a single bit.
+pub(crate) fn enum_parameters(
+    kind: OperandKind,
+    value: u32,
+) -> &'static [(OperandKind, Quantifier)] {
+    match (kind, value) {
+        (Decoration, 0x1) => &[(Word, One)],
+        (Decoration, 0x6) => &[(Word, One)],
+        (Decoration, 0x7) => &[(Word, One)],
+        (Decoration, 0xb) => &[(Word, One)],
+        (Decoration, 0x1b) => &[(Id, One)],
+        (Decoration, 0x1d) => &[(Word, One)],
+        (Decoration, 0x1e) => &[(Word, One)],
+        (Decoration, 0x1f) => &[(Word, One)],
+        (Decoration, 0x20) => &[(Word, One)],
+        (Decoration, 0x21) => &[(Word, One)],
+        (Decoration, 0x22) => &[(Word, One)],
+        (Decoration, 0x23) => &[(Word, One)],
+        (Decoration, 0x24) => &[(Word, One)],
+        (Decoration, 0x25) => &[(Word, One)],
+        (Decoration, 0x26) => &[(Word, One)],
+        (Decoration, 0x27) => &[(Word, One)],
+        (Decoration, 0x28) => &[(Word, One)],
+        (Decoration, 0x29) => &[(String, One), (Word, One)],
+        (Decoration, 0x2b) => &[(Word, One)],
+        (Decoration, 0x2c) => &[(Word, One)],
+        (Decoration, 0x2d) => &[(Word, One)],
+        (Decoration, 0x2e) => &[(Id, One)],
+        (Decoration, 0x2f) => &[(Id, One)],
+        (Decoration, 0x139b) => &[(Id, One)],
+        (Decoration, 0x139c) => &[(Id, One)],
+        (Decoration, 0x13e3) => &[(String, One)],
+        (Decoration, 0x1488) => &[(Word, One)],
+        (Decoration, 0x15df) => &[(Word, One)],
+        (Decoration, 0x15e7) => &[(String, One)],
+        (Decoration, 0x15f9) => &[(Word, One)],
+        (Decoration, 0x15fc) => &[(Word, One)],
+        (Decoration, 0x1602) => &[(Id, One)],
+        (Decoration, 0x1603) => &[(String, One)],
+        (Decoration, 0x1604) => &[(String, One)],
+        (Decoration, 0x16be) => &[(Word, One), (Word, One)],
+        (Decoration, 0x16bf) => &[(Word, One), (Word, One)],
+        (Decoration, 0x16c2) => &[(String, One)],
+        (Decoration, 0x16c3) => &[(Word, One)],
+        (Decoration, 0x16c4) => &[(Word, One)],
+        (Decoration, 0x16c5) => &[(Word, One)],
+        (Decoration, 0x16c8) => &[(Word, One)],
+        (Decoration, 0x16ca) => &[(String, One), (String, One)],
+        (Decoration, 0x16cb) => &[(Word, Variadic)],
+        (Decoration, 0x16cc) => &[(Word, One)],
+        (Decoration, 0x16fb) => &[(Word, One)],
+        (Decoration, 0x16fc) => &[(Word, One)],
+        (Decoration, 0x170c) => &[(Word, One)],
+        (Decoration, 0x170e) => &[(Word, One)],
+        (Decoration, 0x1715) => &[(Word, One), (Word, One)],
+        (Decoration, 0x171a) => &[(Id, One)],
+        (Decoration, 0x171b) => &[(Id, One)],
+        (Decoration, 0x171d) => &[(Word, One)],
+        (Decoration, 0x171e) => &[(Word, One)],
+        (Decoration, 0x171f) => &[(Word, One)],
+        (Decoration, 0x1721) => &[(Word, One)],
+        (Decoration, 0x1738) => &[(Word, One)],
+        (Decoration, 0x17c0) => &[(Word, One), (Word, One)],
+        (Decoration, 0x181a) => &[(Word, One)],
+        (Decoration, 0x181c) => &[(Word, One)],
+        (Decoration, 0x181d) => &[(Word, One), (Word, One), (Word, One)],
+        (Decoration, 0x1821) => &[(Word, One)],
+        (Decoration, 0x1822) => &[(Word, One)],
+        (Decoration, 0x1823) => &[(Word, One)],
+        (Decoration, 0x1824) => &[(Word, One)],
+        (Decoration, 0x1825) => &[(Word, One)],
+        (Decoration, 0x1826) => &[(Word, One)],
+        (Decoration, 0x182c) => &[(Word, One), (String, One)],
+        (Decoration, 0x182e) => &[(Word, One)],
+        (Decoration, 0x182f) => &[(Word, One)],
+        (Decoration, 0x192a) => &[(Word, One), (Word, One)],
+        (Decoration, 0x192b) => &[(Word, One), (Word, One)],
+        (ExecutionMode, 0x0) => &[(Word, One)],
+        (ExecutionMode, 0x11) => &[(Word, One), (Word, One), (Word, One)],
+        (ExecutionMode, 0x12) => &[(Word, One), (Word, One), (Word, One)],
+        (ExecutionMode, 0x1a) => &[(Word, One)],
+        (ExecutionMode, 0x1e) => &[(Word, One)],
+        (ExecutionMode, 0x23) => &[(Word, One)],
+        (ExecutionMode, 0x24) => &[(Word, One)],
+        (ExecutionMode, 0x25) => &[(Id, One)],
+        (ExecutionMode, 0x26) => &[(Id, One), (Id, One), (Id, One)],
+        (ExecutionMode, 0x27) => &[(Id, One), (Id, One), (Id, One)],
+        (ExecutionMode, 0x116b) => &[(Word, One)],
+        (ExecutionMode, 0x116c) => &[(Word, One)],
+        (ExecutionMode, 0x116d) => &[(Word, One)],
+        (ExecutionMode, 0x116e) => &[(Word, One)],
+        (ExecutionMode, 0x116f) => &[(Word, One)],
+        (ExecutionMode, 0x13cf) => &[(Id, One)],
+        (ExecutionMode, 0x13d0) => &[(Id, One), (Id, One), (Id, One)],
+        (ExecutionMode, 0x13d1) => &[(Id, One)],
+        (ExecutionMode, 0x13d5) => &[(Id, One), (Id, One), (Id, One)],
+        (ExecutionMode, 0x1496) => &[(Word, One)],
+        (ExecutionMode, 0x15f2) => &[(Word, One)],
+        (ExecutionMode, 0x15f4) => &[(Word, One)],
+        (ExecutionMode, 0x15f5) => &[(Word, One)],
+        (ExecutionMode, 0x15f6) => &[(Word, One)],
+        (ExecutionMode, 0x15f7) => &[(Word, One)],
+        (ExecutionMode, 0x1705) => &[(Word, One), (Word, One), (Word, One)],
+        (ExecutionMode, 0x1706) => &[(Word, One)],
+        (ExecutionMode, 0x1708) => &[(Word, One)],
+        (ExecutionMode, 0x170f) => &[(Word, One)],
+        (ExecutionMode, 0x180a) => &[(Word, One)],
+        (ExecutionMode, 0x1810) => &[(Word, One)],
+        (ExecutionMode, 0x1911) => &[(Word, One)],
+        (ImageOperands, 0x1) => &[(Id, One)],
+        (ImageOperands, 0x2) => &[(Id, One)],
+        (ImageOperands, 0x4) => &[(Id, One), (Id, One)],
+        (ImageOperands, 0x8) => &[(Id, One)],
+        (ImageOperands, 0x10) => &[(Id, One)],
+        (ImageOperands, 0x20) => &[(Id, One)],
+        (ImageOperands, 0x40) => &[(Id, One)],
+        (ImageOperands, 0x80) => &[(Id, One)],
+        (ImageOperands, 0x100) => &[(Id, One)],
+        (ImageOperands, 0x200) => &[(Id, One)],
+        (ImageOperands, 0x10000) => &[(Id, One)],
+        (LoopControl, 0x8) => &[(Word, One)],
+        (LoopControl, 0x10) => &[(Word, One)],
+        (LoopControl, 0x20) => &[(Word, One)],
+        (LoopControl, 0x40) => &[(Word, One)],
+        (LoopControl, 0x80) => &[(Word, One)],
+        (LoopControl, 0x100) => &[(Word, One)],
+        (LoopControl, 0x10000) => &[(Word, One)],
+        (LoopControl, 0x20000) => &[(Word, One)],
+        (LoopControl, 0x40000) => &[(Word, One)],
+        (LoopControl, 0x80000) => &[(Word, One)],
+        (LoopControl, 0x100000) => &[(Word, One)],
+        (LoopControl, 0x200000) => &[(Word, One)],
+        (LoopControl, 0x400000) => &[(Word, One)],
+        (LoopControl, 0x1000000) => &[(Word, One)],
+        (LoopControl, 0x2000000) => &[(Word, One)],
+        (MemoryAccess, 0x2) => &[(Word, One)],
+        (MemoryAccess, 0x8) => &[(Id, One)],
+        (MemoryAccess, 0x10) => &[(Id, One)],
+        (MemoryAccess, 0x10000) => &[(Id, One)],
+        (MemoryAccess, 0x20000) => &[(Id, One)],
+        _ => &[],
+    }
+}
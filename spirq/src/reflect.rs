@@ -8,12 +8,16 @@ use spq_core::parse::Instrs;
 use crate::{
     annotation::{DecorationRegistry, NameRegistry},
     constant::{Constant, ConstantValue},
-    entry_point::{EntryPoint, ExecutionModel},
+    diagnostic::Diagnostic,
+    entry_point::{EntryPoint, EntryPointInfo, ExecutionModel, XfbInfo},
     error::{anyhow, Error, Result},
     evaluator::Evaluator,
     func::{ExecutionMode, Function, FunctionRegistry},
     inspect::Inspector,
     instr::*,
+    layout::LayoutRule,
+    locator::Locator,
+    module::{DescriptorUsage, ModuleInfo, ModuleMetadata, ReflectedModule, UsageInfo},
     parse::Instr,
     reflect_cfg::ReflectConfig,
     spirv::{self, Op},
@@ -23,9 +27,13 @@ use crate::{
         SampledImageType, SamplerType, ScalarType, StorageClass, StorageImageType, StructMember,
         StructType, SubpassDataType, Type, TypeRegistry, VectorType,
     },
-    var::{Variable, VariableAlloc, VariableRegistry},
+    value::CompositeValue,
+    var::{
+        DescriptorBinding, InterfaceLocation, SpecId, Variable, VariableAlloc, VariableRegistry,
+    },
 };
 
+type InstrId = u32;
 type ConstantId = u32;
 type FunctionId = u32;
 type TypeId = u32;
@@ -37,6 +45,7 @@ struct EntryPointDeclaration<'a> {
     name: &'a str,
     exec_model: ExecutionModel,
     exec_modes: Vec<ExecutionModeDeclaration>,
+    interface_var_ids: &'a [VariableId],
 }
 enum ExecutionModeOperand {
     Literal(u32),
@@ -47,6 +56,17 @@ struct ExecutionModeDeclaration {
     exec_mode: spirv::ExecutionMode,
     operands: Vec<ExecutionModeOperand>,
 }
+/// A `DeviceAddress` in a type, standing for a pointer declared by
+/// `OpTypeForwardPointer` whose `OpTypePointer` is not seen yet.
+#[derive(Clone)]
+struct ForwardPointerSlot {
+    /// Member indices, or 0 for array elements and pointees, leading from the
+    /// type to the address.
+    path: Vec<u32>,
+    ptr_id: TypeId,
+    /// The type directly containing the address.
+    origin: TypeId,
+}
 
 // The actual reflection to take place.
 
@@ -78,6 +98,7 @@ fn is_ty_op(op: Op) -> bool {
         Op::TypeRayQueryKHR => true,
         Op::TypeAccelerationStructureKHR => true,
         Op::TypeCooperativeMatrixNV => true,
+        Op::TypeCooperativeMatrixKHR => true,
         Op::TypeVmeImageINTEL => true,
         Op::TypeAvcImePayloadINTEL => true,
         Op::TypeAvcRefPayloadINTEL => true,
@@ -137,6 +158,32 @@ fn is_atomic_store_op(op: Op) -> bool {
         _ => false,
     }
 }
+// Cooperative matrix loads and stores share the leading operands of `OpLoad`
+// and `OpStore`.
+fn is_coop_mat_load_op(op: Op) -> bool {
+    matches!(
+        op,
+        Op::CooperativeMatrixLoadKHR | Op::CooperativeMatrixLoadNV
+    )
+}
+fn is_coop_mat_store_op(op: Op) -> bool {
+    matches!(
+        op,
+        Op::CooperativeMatrixStoreKHR | Op::CooperativeMatrixStoreNV
+    )
+}
+
+/// Decorations on a pointer, typically into `PhysicalStorageBuffer`, that
+/// constrain how it can be dereferenced.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointerDecorations {
+    /// Known minimum alignment in bytes of the address, as decorated by
+    /// `Alignment`.
+    pub alignment: Option<u32>,
+    /// Known maximum byte offset from the pointer that is dereferenced, as
+    /// decorated by `MaxByteOffset`.
+    pub max_byte_offset: Option<u32>,
+}
 
 /// SPIR-V reflection intermediate.
 pub struct ReflectIntermediate<'a> {
@@ -148,6 +195,55 @@ pub struct ReflectIntermediate<'a> {
     pub func_reg: FunctionRegistry,
     pub interp: Evaluator,
     entry_point_declrs: HashMap<FunctionId, EntryPointDeclaration<'a>>,
+    strings: HashMap<InstrId, &'a str>,
+    source_file_ids: Vec<InstrId>,
+    /// Constants, types and variables depending on specialization constants
+    /// that are not specialized, i.e., sized or valued by default values.
+    spec_dependent_ids: HashSet<InstrId>,
+    user_semantics: HashMap<InstrId, &'a str>,
+    /// All names given by `OpName` and `OpMemberName`, so that generated
+    /// names never collide with them.
+    debug_names: HashSet<&'a str>,
+    /// Default values of scalar specialization constants as declared by
+    /// `OpSpecConstant*`, regardless of specialization.
+    spec_defaults: HashMap<SpecId, ConstantValue>,
+    /// Access qualifiers of image types, and array types, pointer types and
+    /// variables of such images.
+    image_access_qualifiers: HashMap<InstrId, AccessType>,
+    /// Built-ins decorated on variables and struct members, and inherited by
+    /// array types, pointer types and variables of such structs.
+    builtins: HashMap<InstrId, Vec<spirv::BuiltIn>>,
+    /// Built-ins of struct members by member index, inherited by the
+    /// pointers and variables of the structs like `builtins`.
+    member_builtins: HashMap<InstrId, Vec<(u32, spirv::BuiltIn)>>,
+    /// Constituents of composite constants, so that the scalars can be
+    /// extracted in specialization constant ops.
+    composite_constituents: HashMap<ConstantId, &'a [ConstantId]>,
+    /// SPIR-V version in the module header, filled in by the caller because
+    /// the header is not part of the instruction stream.
+    pub(crate) version: (u8, u8),
+    capabilities: Vec<spirv::Capability>,
+    unknown_capabilities: Vec<u32>,
+    extensions: Vec<&'a str>,
+    addressing_model: Option<spirv::AddressingModel>,
+    memory_model: Option<spirv::MemoryModel>,
+    /// Parameters of each function, in declaration order.
+    func_params: HashMap<FunctionId, Vec<VariableId>>,
+    /// Function calls as `(caller, callee, arguments)`. Arguments derived
+    /// from access chains are resolved to the accessed variables.
+    func_calls: Vec<(FunctionId, FunctionId, Vec<InstrId>)>,
+    /// Kinds of access to variables observed in each function, i.e., loads,
+    /// stores, image reads and writes, and atomics.
+    var_usages: HashMap<FunctionId, HashMap<VariableId, AccessType>>,
+    /// Variables indexed by access chains decorated `NonUniform` or with
+    /// indices decorated `NonUniform`, in each function.
+    nonuniform_indexed_vars: HashMap<FunctionId, HashSet<VariableId>>,
+    /// Variables accessed by atomic instructions, directly or through texel
+    /// pointers, in each function.
+    atomic_accessed_vars: HashMap<FunctionId, HashSet<VariableId>>,
+    /// Addresses of forward pointers in the types declared before the
+    /// pointers, to be back-patched when the pointers are declared.
+    forward_ptr_slots: HashMap<TypeId, Vec<ForwardPointerSlot>>,
 }
 impl<'a> ReflectIntermediate<'a> {
     pub fn new(cfg: &'a ReflectConfig) -> Result<Self> {
@@ -160,13 +256,229 @@ impl<'a> ReflectIntermediate<'a> {
             func_reg: Default::default(),
             interp: Default::default(),
             entry_point_declrs: Default::default(),
+            strings: Default::default(),
+            source_file_ids: Default::default(),
+            spec_dependent_ids: Default::default(),
+            user_semantics: Default::default(),
+            debug_names: Default::default(),
+            spec_defaults: Default::default(),
+            image_access_qualifiers: Default::default(),
+            builtins: Default::default(),
+            member_builtins: Default::default(),
+            composite_constituents: Default::default(),
+            version: Default::default(),
+            capabilities: Default::default(),
+            unknown_capabilities: Default::default(),
+            extensions: Default::default(),
+            addressing_model: Default::default(),
+            memory_model: Default::default(),
+            func_params: Default::default(),
+            func_calls: Default::default(),
+            var_usages: Default::default(),
+            nonuniform_indexed_vars: Default::default(),
+            atomic_accessed_vars: Default::default(),
+            forward_ptr_slots: Default::default(),
         };
         Ok(out)
     }
+
+    /// Decorations on a pointer value like a function parameter or a
+    /// variable, of a type like `OpTypePointer PhysicalStorageBuffer`. The
+    /// decorations are not part of the pointer type, so they are only
+    /// available by the IDs of the pointers, e.g., in an inspector.
+    pub fn pointer_decorations(&self, id: InstrId) -> PointerDecorations {
+        PointerDecorations {
+            alignment: self.deco_reg.get_u32(id, spirv::Decoration::Alignment).ok(),
+            max_byte_offset: self
+                .deco_reg
+                .get_u32(id, spirv::Decoration::MaxByteOffset)
+                .ok(),
+        }
+    }
+    /// Type `id` as resolved so far, e.g., in an inspector. Pointer types
+    /// resolve to [`Type::DevicePointer`] whatever their storage classes.
+    /// Returns `None` if `id` is not a type or is not supported.
+    pub fn resolve_type(&self, id: TypeId) -> Option<&Type> {
+        self.ty_reg.get(id).ok()
+    }
+    /// Value of the scalar constant `id` as resolved so far, e.g., in an
+    /// inspector. Specialization constants take their specialized values
+    /// like in [`ReflectIntermediate::constant_value`]. Returns `None` for
+    /// composite constants and IDs that are not constants.
+    pub fn resolve_constant(&self, id: ConstantId) -> Option<&ConstantValue> {
+        self.interp.get_value(id).ok()
+    }
+    /// Value of the constant `id`, including composite constants like
+    /// `OpConstantComposite` and `OpSpecConstantComposite`, whose
+    /// constituents are resolved recursively. Specialization constants take
+    /// their specialized values if [`ReflectConfig::specialize`] is given,
+    /// or their default values otherwise.
+    pub fn constant_value(&self, id: ConstantId) -> Result<CompositeValue> {
+        self.constant_value_impl(id, self.composite_constituents.len())
+    }
+    fn constant_value_impl(&self, id: ConstantId, max_depth: usize) -> Result<CompositeValue> {
+        if let Some(constituent_ids) = self.composite_constituents.get(&id) {
+            // Constituents are always declared before the composite so this
+            // could only loop forever on malformed modules.
+            let max_depth = max_depth
+                .checked_sub(1)
+                .ok_or_else(|| anyhow!("composite constant {} refers to itself", id))?;
+            let constituents = constituent_ids
+                .iter()
+                .map(|x| self.constant_value_impl(*x, max_depth))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(CompositeValue::Composite(constituents))
+        } else {
+            let value = self.interp.get_value(id)?.clone();
+            Ok(CompositeValue::Scalar(value))
+        }
+    }
+}
+/// Interpret an integer constant of any width as an array length. Returns
+/// `None` if the value is not a positive integer representable in `u32`.
+fn array_len(value: &ConstantValue) -> Option<u32> {
+    let x = match value {
+        ConstantValue::S8(x) => u32::try_from(*x).ok()?,
+        ConstantValue::S16(x) => u32::try_from(*x).ok()?,
+        ConstantValue::S32(x) => u32::try_from(*x).ok()?,
+        ConstantValue::S64(x) => u32::try_from(*x).ok()?,
+        ConstantValue::U8(x) => *x as u32,
+        ConstantValue::U16(x) => *x as u32,
+        ConstantValue::U32(x) => *x,
+        ConstantValue::U64(x) => u32::try_from(*x).ok()?,
+        _ => return None,
+    };
+    if x > 0 {
+        Some(x)
+    } else {
+        None
+    }
+}
+/// Result ID and constituent IDs of `OpConstantComposite` or
+/// `OpSpecConstantComposite`.
+fn read_composite_constituents(instr: &Instr) -> Result<(ConstantId, &[ConstantId])> {
+    let const_id = instr
+        .operands()
+        .nth(1)
+        .ok_or_else(|| anyhow!("composite constant has no result id"))?;
+    Ok((const_id, &instr.as_ref()[3..]))
+}
+/// The type at `path` in `ty`, as is recorded in `ForwardPointerSlot`.
+fn forward_ptr_slot_mut<'t>(ty: &'t mut Type, path: &[u32]) -> Option<&'t mut Type> {
+    let (&step, path) = match path.split_first() {
+        Some(x) => x,
+        None => return Some(ty),
+    };
+    let ty = match ty {
+        Type::Struct(struct_ty) => &mut struct_ty.members.get_mut(step as usize)?.ty,
+        Type::Array(arr_ty) => &mut *arr_ty.element_ty,
+        Type::DevicePointer(ptr_ty) => &mut *ptr_ty.pointee_ty,
+        _ => return None,
+    };
+    forward_ptr_slot_mut(ty, path)
 }
 fn broken_nested_ty(id: TypeId) -> Error {
     Error::msg(format!("broken nested type: {}", id))
 }
+
+/// Iterate over the instructions in SPIR-V `words` after making sure that the
+/// header is present, every instruction is fully contained in the binary and
+/// all opcodes are known. The parser in `spq-core` assumes all of these and
+/// panics otherwise, so we validate untrusted input ahead of reflection.
+pub(crate) fn checked_instrs(words: &[u32]) -> Result<Instrs<'_>> {
+    const HEADER_LEN: usize = 5;
+    if words.len() < HEADER_LEN {
+        return Err(anyhow!("spirv binary is too short to contain a header"));
+    }
+    if words[0] != spirv::MAGIC_NUMBER {
+        return Err(anyhow!("invalid spirv magic number {:#010x}", words[0]));
+    }
+    let mut nword = HEADER_LEN;
+    let mut instrs = Instrs::new(&words[HEADER_LEN..])?;
+    while let Some(instr) = instrs.next()? {
+        if Op::from_u32(instr.opcode()).is_none() {
+            return Err(anyhow!("unknown opcode {}", instr.opcode()));
+        }
+        nword += instr.word_count();
+    }
+    if nword != words.len() {
+        return Err(anyhow!("instruction is truncated"));
+    }
+    Instrs::new(&words[HEADER_LEN..])
+}
+/// Reject specialization constant operations that `Evaluator` can't fold
+/// without panicking, e.g., integer division by zero.
+fn check_spec_const_op_operands(opcode: Op, operands: &[ConstantValue]) -> Result<()> {
+    let as_i64 = |x: &ConstantValue| match x {
+        ConstantValue::S32(x) => Some(*x as i64),
+        ConstantValue::U32(x) => Some(*x as i64),
+        _ => None,
+    };
+    match (opcode, operands) {
+        (Op::SNegate, [ConstantValue::S32(i32::MIN)]) => {
+            Err(anyhow!("integer overflow in specialization constant"))
+        }
+        (Op::IMul, [a, b])
+            if as_i64(a)
+                .zip(as_i64(b))
+                .is_some_and(|(a, b)| a.checked_mul(b).is_none()) =>
+        {
+            Err(anyhow!("integer overflow in specialization constant"))
+        }
+        (Op::UDiv | Op::SDiv | Op::UMod | Op::SRem | Op::SMod, [_, b]) if as_i64(b) == Some(0) => {
+            Err(anyhow!("division by zero in specialization constant"))
+        }
+        (Op::ShiftRightLogical | Op::ShiftRightArithmetic | Op::ShiftLeftLogical, [_, shift])
+            if as_i64(shift).is_some_and(|x| !(0..32).contains(&x)) =>
+        {
+            Err(anyhow!("shift amount exceeds bit width"))
+        }
+        (
+            Op::ShiftRightLogical
+            | Op::ShiftRightArithmetic
+            | Op::ShiftLeftLogical
+            | Op::BitwiseOr
+            | Op::BitwiseXor
+            | Op::BitwiseAnd,
+            [_, _],
+        ) => Ok(()),
+        (
+            Op::ShiftRightLogical
+            | Op::ShiftRightArithmetic
+            | Op::ShiftLeftLogical
+            | Op::BitwiseOr
+            | Op::BitwiseXor
+            | Op::BitwiseAnd,
+            _,
+        ) => Err(anyhow!("{:?} takes exactly 2 operands", opcode)),
+        (Op::Not, [_]) => Ok(()),
+        (Op::Not, _) => Err(anyhow!("{:?} takes exactly 1 operand", opcode)),
+        _ => Ok(()),
+    }
+}
+/// Fold boolean specialization constant operations which `Evaluator` doesn't
+/// support, e.g., `OpSelect` picking an array size by a boolean
+/// specialization constant. Returns `None` if the operation is not one of
+/// them.
+fn fold_logical_spec_const_op(opcode: Op, operands: &[ConstantValue]) -> Option<ConstantValue> {
+    use ConstantValue::Bool;
+    let value = match (opcode, operands) {
+        (Op::Select, [Bool(cond), a, b]) => {
+            if *cond {
+                a.clone()
+            } else {
+                b.clone()
+            }
+        }
+        (Op::LogicalNot, [Bool(a)]) => Bool(!a),
+        (Op::LogicalAnd, [Bool(a), Bool(b)]) => Bool(*a && *b),
+        (Op::LogicalOr, [Bool(a), Bool(b)]) => Bool(*a || *b),
+        (Op::LogicalEqual, [Bool(a), Bool(b)]) => Bool(a == b),
+        (Op::LogicalNotEqual, [Bool(a), Bool(b)]) => Bool(a != b),
+        _ => return None,
+    };
+    Some(value)
+}
 impl<'a> ReflectIntermediate<'a> {
     fn populate_one_ty(&mut self, instr: &Instr) -> Result<()> {
         match instr.op() {
@@ -248,6 +560,17 @@ impl<'a> ReflectIntermediate<'a> {
                         2 => None,
                         _ => return Err(anyhow!("unsupported image depth type")),
                     };
+                    // The optional access qualifier follows the image format.
+                    // It's mostly emitted for OpenCL-style images.
+                    if let Some(access_qualifier) = instr.operands().nth(8) {
+                        let access = match spirv::AccessQualifier::from_u32(access_qualifier) {
+                            Some(spirv::AccessQualifier::ReadOnly) => AccessType::ReadOnly,
+                            Some(spirv::AccessQualifier::WriteOnly) => AccessType::WriteOnly,
+                            Some(spirv::AccessQualifier::ReadWrite) => AccessType::ReadWrite,
+                            None => return Err(anyhow!("unsupported image access qualifier")),
+                        };
+                        self.image_access_qualifiers.insert(op.ty_id, access);
+                    }
                     let image_ty = ImageType {
                         scalar_ty,
                         dim: op.dim,
@@ -287,6 +610,8 @@ impl<'a> ReflectIntermediate<'a> {
             }
             Op::TypeArray => {
                 let op = OpTypeArray::try_from(instr)?;
+                self.inherit_image_access_qualifier(op.ty_id, op.element_ty_id);
+                self.inherit_builtins(op.ty_id, op.element_ty_id);
                 // FIXME: Workaround old storage buffers.
                 if self
                     .deco_reg
@@ -313,11 +638,13 @@ impl<'a> ReflectIntermediate<'a> {
                 // behavior of `glslang` is to treat the specialization
                 // constants as normal constants, then I would say...
                 // probably it's fine to size array with them?
-                let nelement = match self.interp.get_value(op.nelement_const_id)? {
-                    ConstantValue::S32(x) if *x > 0 => *x as u32,
-                    ConstantValue::U32(x) if *x > 0 => *x,
-                    _ => return Err(anyhow!("invalid array size")),
-                };
+                if self.spec_dependent_ids.contains(&op.nelement_const_id)
+                    || self.spec_dependent_ids.contains(&op.element_ty_id)
+                {
+                    self.spec_dependent_ids.insert(op.ty_id);
+                }
+                let nelement = array_len(self.interp.get_value(op.nelement_const_id)?)
+                    .ok_or_else(|| anyhow!("invalid array size"))?;
                 let stride = self
                     .deco_reg
                     .get_u32(op.ty_id, spirv::Decoration::ArrayStride)
@@ -338,10 +665,13 @@ impl<'a> ReflectIntermediate<'a> {
                         stride: None,
                     }
                 };
+                self.inherit_forward_ptr_slots(op.ty_id, 0, op.element_ty_id);
                 self.ty_reg.set(op.ty_id, Type::Array(arr_ty))?;
             }
             Op::TypeRuntimeArray => {
                 let op = OpTypeRuntimeArray::try_from(instr)?;
+                self.inherit_image_access_qualifier(op.ty_id, op.element_ty_id);
+                self.inherit_builtins(op.ty_id, op.element_ty_id);
                 let element_ty = if let Ok(x) = self.ty_reg.get(op.element_ty_id) {
                     x
                 } else {
@@ -367,21 +697,23 @@ impl<'a> ReflectIntermediate<'a> {
                         stride: None,
                     }
                 };
+                self.inherit_forward_ptr_slots(op.ty_id, 0, op.element_ty_id);
                 self.ty_reg.set(op.ty_id, Type::Array(arr_ty))?;
             }
             Op::TypeStruct => {
                 let op = OpTypeStruct::try_from(instr)?;
-                let struct_name =
-                    self.name_reg
-                        .get(op.ty_id)
-                        .map(ToOwned::to_owned)
-                        .or_else(|| {
-                            if self.cfg.gen_unique_names {
-                                Some(format!("type_{}", op.ty_id))
-                            } else {
-                                None
-                            }
-                        });
+                let struct_name = self
+                    .name_reg
+                    .get(op.ty_id)
+                    .map(ToOwned::to_owned)
+                    .or_else(|| self.gen_unique_name(format!("type_{}", op.ty_id)));
+                if op
+                    .member_ty_ids
+                    .iter()
+                    .any(|x| self.spec_dependent_ids.contains(x))
+                {
+                    self.spec_dependent_ids.insert(op.ty_id);
+                }
                 let mut members = Vec::new();
                 for (i, &member_ty_id) in op.member_ty_ids.iter().enumerate() {
                     let i = i as u32;
@@ -402,6 +734,15 @@ impl<'a> ReflectIntermediate<'a> {
                         );
                         if let Ok(mat_stride) = mat_stride {
                             mat_ty.stride = Some(mat_stride as usize);
+                        } else if self.deco_reg.contains_member(
+                            op.ty_id,
+                            i,
+                            spirv::Decoration::Offset,
+                        ) {
+                            self.cfg.diagnose(Diagnostic::MissingMatrixStride {
+                                ty_id: op.ty_id,
+                                member_idx: i,
+                            });
                         }
 
                         let is_row_major =
@@ -424,11 +765,7 @@ impl<'a> ReflectIntermediate<'a> {
                         .get_member(op.ty_id, i)
                         .map(ToOwned::to_owned)
                         .or_else(|| {
-                            if self.cfg.gen_unique_names {
-                                Some(format!("type_{}_member_{}", op.ty_id, i))
-                            } else {
-                                None
-                            }
+                            self.gen_unique_name(format!("type_{}_member_{}", op.ty_id, i))
                         });
                     // For shader input/output blocks there are no offset
                     // decoration. Since these variables are not externally
@@ -454,12 +791,20 @@ impl<'a> ReflectIntermediate<'a> {
                     name: struct_name,
                     members: members,
                 };
+                for (i, &member_ty_id) in op.member_ty_ids.iter().enumerate() {
+                    self.inherit_forward_ptr_slots(op.ty_id, i as u32, member_ty_id);
+                }
                 // Don't have to shrink-to-fit because the types in `ty_map`
                 // won't be used directly and will be cloned later.
                 self.ty_reg.set(op.ty_id, Type::Struct(struct_ty))?;
             }
             Op::TypePointer => {
                 let op = OpTypePointer::try_from(instr)?;
+                if self.spec_dependent_ids.contains(&op.target_ty_id) {
+                    self.spec_dependent_ids.insert(op.ty_id);
+                }
+                self.inherit_image_access_qualifier(op.ty_id, op.target_ty_id);
+                self.inherit_builtins(op.ty_id, op.target_ty_id);
                 if let Ok(pointee_ty) = self.ty_reg.get(op.target_ty_id) {
                     // Before SPIR-V 1.3, there is no `StorageBuffer` storage
                     // class. And from a pointer perspective you can't tell if
@@ -476,7 +821,13 @@ impl<'a> ReflectIntermediate<'a> {
                         pointee_ty: Box::new(pointee_ty.clone()),
                         store_cls,
                     };
+                    let is_forward_declared =
+                        matches!(self.ty_reg.get(op.ty_id), Ok(Type::DeviceAddress(_)));
+                    self.inherit_forward_ptr_slots(op.ty_id, 0, op.target_ty_id);
                     self.ty_reg.set(op.ty_id, Type::DevicePointer(pointer_ty))?;
+                    if is_forward_declared {
+                        self.backpatch_forward_ptr(op.ty_id, op.target_ty_id)?;
+                    }
                 } else {
                     // Ignore unknown types. Currently only funtion pointers can
                     // step into this.
@@ -497,11 +848,19 @@ impl<'a> ReflectIntermediate<'a> {
                 let op = OpTypeRayQueryKHR::try_from(instr)?;
                 self.ty_reg.set(op.ty_id, Type::RayQuery(RayQueryType {}))?;
             }
+            Op::TypeCooperativeMatrixKHR | Op::TypeCooperativeMatrixNV => {
+                // Cooperative matrices only live in `Function` and `Private`
+                // storage and have no `Type` representation. Leave them
+                // unregistered so types and variables built upon them are
+                // ignored like other unknown types.
+                let opcode = instr.op();
+                self.cfg.diagnose(Diagnostic::SkippedInstr { opcode });
+            }
             _ => return Err(anyhow!("unexpected opcode {:?}", instr.op())),
         }
         Ok(())
     }
-    fn populate_one_const(&mut self, instr: &Instr) -> Result<()> {
+    fn populate_one_const(&mut self, instr: &'a Instr) -> Result<()> {
         let opcode = instr.op();
         match opcode {
             Op::ConstantTrue | Op::ConstantFalse | Op::Constant => {
@@ -517,21 +876,18 @@ impl<'a> ReflectIntermediate<'a> {
                     .name_reg
                     .get(op.const_id)
                     .map(ToOwned::to_owned)
-                    .or_else(|| {
-                        if self.cfg.gen_unique_names {
-                            Some(format!("const_{}", op.const_id))
-                        } else {
-                            None
-                        }
-                    });
+                    .or_else(|| self.gen_unique_name(format!("const_{}", op.const_id)));
                 let constant = Constant::new(name, ty, value);
                 self.interp.set(op.const_id, constant)?;
                 Ok(())
             }
-            Op::ConstantComposite
-            | Op::ConstantSampler
-            | Op::ConstantNull
-            | Op::ConstantPipeStorage => Ok(()),
+            Op::ConstantComposite => {
+                let (const_id, constituent_ids) = read_composite_constituents(instr)?;
+                self.composite_constituents
+                    .insert(const_id, constituent_ids);
+                Ok(())
+            }
+            Op::ConstantSampler | Op::ConstantNull | Op::ConstantPipeStorage => Ok(()),
             Op::SpecConstantTrue | Op::SpecConstantFalse | Op::SpecConstant => {
                 let op = OpConstantScalarCommonSPQ::try_from(instr)?;
                 let name = self.name_reg.get(op.const_id).map(ToString::to_string);
@@ -539,42 +895,217 @@ impl<'a> ReflectIntermediate<'a> {
                     .deco_reg
                     .get_u32(op.const_id, spirv::Decoration::SpecId)?;
                 let ty = self.ty_reg.get(op.ty_id)?.clone();
+                let default_value = match opcode {
+                    Op::SpecConstantTrue => ConstantValue::from(true),
+                    Op::SpecConstantFalse => ConstantValue::from(false),
+                    Op::SpecConstant => ConstantValue::from(op.value).to_typed(&ty)?,
+                    _ => unreachable!(),
+                };
+                self.spec_defaults.insert(spec_id, default_value.clone());
                 let constant = if let Some(user_value) = self.cfg.spec_values.get(&spec_id) {
                     let user_value = if matches!(user_value, ConstantValue::Typeless(_)) {
                         user_value.to_typed(&ty)?
                     } else {
                         user_value.clone()
                     };
+                    let is_bool_ty = ty.as_scalar() == Some(&ScalarType::Boolean);
+                    if is_bool_ty != matches!(user_value, ConstantValue::Bool(_)) {
+                        return Err(anyhow!(
+                            "specialization constant {} of type {} cannot be specialized with {:?}",
+                            spec_id,
+                            ty,
+                            user_value
+                        ));
+                    }
                     Constant::new(name, ty, user_value)
                 } else {
-                    let value = match opcode {
-                        Op::SpecConstantTrue => ConstantValue::from(true),
-                        Op::SpecConstantFalse => ConstantValue::from(false),
-                        Op::SpecConstant => ConstantValue::from(op.value).to_typed(&ty)?,
-                        _ => unreachable!(),
-                    };
-                    Constant::new_spec(name, ty, value, spec_id)
+                    self.spec_dependent_ids.insert(op.const_id);
+                    Constant::new_spec(name, ty, default_value, spec_id)
                 };
                 self.interp.set(op.const_id, constant)?;
                 Ok(())
             }
             // `SpecId` decorations will be specified to each of the constituents so we don't have to register a `Constant` for the composite of them. `Constant` is registered only for those will be interacting with Vulkan.
-            Op::SpecConstantComposite => Ok(()),
+            Op::SpecConstantComposite => {
+                let (const_id, constituent_ids) = read_composite_constituents(instr)?;
+                if constituent_ids
+                    .iter()
+                    .any(|x| self.spec_dependent_ids.contains(x))
+                {
+                    self.spec_dependent_ids.insert(const_id);
+                }
+                self.composite_constituents
+                    .insert(const_id, constituent_ids);
+                Ok(())
+            }
             Op::SpecConstantOp => {
                 let op = OpSpecConstantHeadSPQ::try_from(instr)?;
                 let opcode = Op::from_u32(op.opcode)
                     .ok_or_else(|| anyhow!("invalid specialization constant op opcode"))?;
                 let result_id = op.spec_const_id;
                 let result_ty = self.ty_reg.get(op.ty_id)?;
-                self.interp
-                    .interpret(opcode, result_id, result_ty, &instr.as_ref()[4..])?;
+                let operand_ids = &instr.as_ref()[4..];
+                if opcode == Op::CompositeExtract {
+                    return self.extract_spec_const(result_id, result_ty.clone(), operand_ids);
+                }
+                let operands = operand_ids
+                    .iter()
+                    .filter_map(|x| self.interp.get_value(*x).ok().cloned())
+                    .collect::<Vec<_>>();
+                check_spec_const_op_operands(opcode, &operands)?;
+                if operand_ids
+                    .iter()
+                    .any(|x| self.spec_dependent_ids.contains(x))
+                {
+                    self.spec_dependent_ids.insert(result_id);
+                }
+                let folded = if operands.len() == operand_ids.len() {
+                    fold_logical_spec_const_op(opcode, &operands)
+                } else {
+                    None
+                };
+                if let Some(value) = folded {
+                    self.interp
+                        .set(result_id, Constant::new_itm(result_ty.clone(), value))?;
+                } else {
+                    self.interp
+                        .interpret(opcode, result_id, result_ty, operand_ids)?;
+                }
                 Ok(())
             }
             _ => Err(anyhow!("unexpected opcode {:?}", instr.op())),
         }
     }
+    /// Resolve `OpSpecConstantOp CompositeExtract` to the extracted scalar
+    /// constituent. Composites are not registered to the evaluator so this is
+    /// done out of it.
+    fn extract_spec_const(
+        &mut self,
+        result_id: ConstantId,
+        result_ty: Type,
+        operands: &[u32],
+    ) -> Result<()> {
+        let (mut const_id, indices) = operands
+            .split_first()
+            .ok_or_else(|| anyhow!("composite extract has no composite operand"))?;
+        for i in indices {
+            const_id = self
+                .composite_constituents
+                .get(const_id)
+                .and_then(|x| x.get(*i as usize))
+                .ok_or_else(|| anyhow!("cannot extract constituent {} of {}", i, const_id))?;
+        }
+        if self.spec_dependent_ids.contains(const_id) {
+            self.spec_dependent_ids.insert(result_id);
+        }
+        let value = self.interp.get_value(*const_id)?.clone();
+        self.interp
+            .set(result_id, Constant::new_itm(result_ty, value))?;
+        Ok(())
+    }
+    fn inherit_image_access_qualifier(&mut self, id: InstrId, src_id: InstrId) {
+        if let Some(access) = self.image_access_qualifiers.get(&src_id) {
+            self.image_access_qualifiers.insert(id, *access);
+        }
+    }
+    fn add_builtin(&mut self, id: InstrId, params: &[u32]) -> Result<()> {
+        let builtin = params
+            .first()
+            .and_then(|&x| spirv::BuiltIn::from_u32(x))
+            .ok_or_else(|| anyhow!("invalid built-in at id {}", id))?;
+        self.builtins.entry(id).or_default().push(builtin);
+        Ok(())
+    }
+    fn add_member_builtin(&mut self, id: InstrId, member_idx: u32, params: &[u32]) -> Result<()> {
+        self.add_builtin(id, params)?;
+        if let Some(builtin) = self.builtins.get(&id).and_then(|x| x.last()) {
+            let builtin = *builtin;
+            self.member_builtins
+                .entry(id)
+                .or_default()
+                .push((member_idx, builtin));
+        }
+        Ok(())
+    }
+    fn inherit_builtins(&mut self, id: InstrId, src_id: InstrId) {
+        if let Some(builtins) = self.builtins.get(&src_id) {
+            let builtins = builtins.clone();
+            self.builtins.insert(id, builtins);
+        }
+        if let Some(member_builtins) = self.member_builtins.get(&src_id) {
+            let member_builtins = member_builtins.clone();
+            self.member_builtins.insert(id, member_builtins);
+        }
+    }
+    /// Record the addresses of forward pointers in `src_id`, or `src_id`
+    /// itself if it's a forward pointer, as reached from `id` by `step`.
+    fn inherit_forward_ptr_slots(&mut self, id: TypeId, step: u32, src_id: TypeId) {
+        let mut slots = self
+            .forward_ptr_slots
+            .get(&src_id)
+            .into_iter()
+            .flatten()
+            .map(|x| ForwardPointerSlot {
+                path: std::iter::once(step)
+                    .chain(x.path.iter().copied())
+                    .collect(),
+                ptr_id: x.ptr_id,
+                origin: x.origin,
+            })
+            .collect::<Vec<_>>();
+        if let Ok(Type::DeviceAddress(_)) = self.ty_reg.get(src_id) {
+            slots.push(ForwardPointerSlot {
+                path: vec![step],
+                ptr_id: src_id,
+                origin: id,
+            });
+        }
+        if !slots.is_empty() {
+            self.forward_ptr_slots.entry(id).or_default().extend(slots);
+        }
+    }
+    /// Replace the addresses of the forward pointer `ptr_id` in the types
+    /// declared before it with the pointer to `pointee_id`. Types are owned
+    /// trees, so the addresses in structs the pointee contains are cyclic and
+    /// left as is. Variables and constants declared before the pointer keep
+    /// the addresses.
+    fn backpatch_forward_ptr(&mut self, ptr_id: TypeId, pointee_id: TypeId) -> Result<()> {
+        let ptr_ty = self.ty_reg.get(ptr_id)?.clone();
+        let cyclic_origins = self
+            .forward_ptr_slots
+            .get(&pointee_id)
+            .into_iter()
+            .flatten()
+            .filter(|x| x.ptr_id == ptr_id)
+            .map(|x| x.origin)
+            .collect::<HashSet<_>>();
+        let mut ty_reg = TypeRegistry::default();
+        for (id, ty) in self.ty_reg.iter() {
+            let mut ty = ty.clone();
+            let slots = self.forward_ptr_slots.get(id).into_iter().flatten();
+            for slot in slots {
+                if slot.ptr_id != ptr_id || cyclic_origins.contains(&slot.origin) {
+                    continue;
+                }
+                if let Some(x) = forward_ptr_slot_mut(&mut ty, &slot.path) {
+                    *x = ptr_ty.clone();
+                }
+            }
+            ty_reg.set(*id, ty)?;
+        }
+        self.ty_reg = ty_reg;
+        for slots in self.forward_ptr_slots.values_mut() {
+            slots.retain(|x| x.ptr_id != ptr_id);
+        }
+        Ok(())
+    }
     fn populate_one_var(&mut self, instr: &Instr) -> Result<()> {
         let op = OpVariable::try_from(instr)?;
+        if self.spec_dependent_ids.contains(&op.ty_id) {
+            self.spec_dependent_ids.insert(op.var_id);
+        }
+        self.inherit_image_access_qualifier(op.var_id, op.ty_id);
+        self.inherit_builtins(op.var_id, op.ty_id);
         let ptr_ty = if let Ok(ty) = self.ty_reg.get(op.ty_id) {
             match ty {
                 Type::DevicePointer(ptr_ty) => ptr_ty.clone(),
@@ -623,35 +1154,119 @@ impl Inspector for FunctionInspector {
                 }
                 self.cur_func = None;
             }
+            Op::ExtInst if itm.is_non_semantic_ext_instr(instr)? => {
+                // Non-semantic instructions like `DebugPrintf` may appear in
+                // and between functions but have nothing to reflect.
+            }
+            Op::FunctionParameter => {
+                let op = OpFunctionParameter::try_from(instr)?;
+                if let Some((func_id, _)) = self.cur_func.as_ref() {
+                    itm.func_params
+                        .entry(*func_id)
+                        .or_default()
+                        .push(op.param_id);
+                } else {
+                    return Err(anyhow!("unexpected OpFunctionParameter"));
+                }
+            }
             Op::FunctionCall => {
                 let op = OpFunctionCall::try_from(instr)?;
-                if let Some((_, func)) = self.cur_func.as_mut() {
+                let access_chain_map = &self.access_chain_map;
+                if let Some((func_id, func)) = self.cur_func.as_mut() {
                     func.callees.insert(op.func_id);
+                    // Resolve access chain, so that pointers into descriptors
+                    // passed as arguments can be traced back to them.
+                    let arg_ids = op
+                        .arg_ids
+                        .iter()
+                        .map(|x| *access_chain_map.get(x).unwrap_or(x))
+                        .collect();
+                    itm.func_calls.push((*func_id, op.func_id, arg_ids));
                 } else {
                     return Err(anyhow!("unexpected OpFunctionCall"));
                 }
             }
             _ => {
-                if let Some((_func_id, func)) = self.cur_func.as_mut() {
+                if let Some((func_id, func)) = self.cur_func.as_mut() {
                     let op = instr.op();
                     if op == Op::AccessChain {
                         let op = OpAccessChain::try_from(instr)?;
+                        // Resolve access chain, in case of access chains
+                        // based on other access chains.
+                        let accessed_var_id = *self
+                            .access_chain_map
+                            .get(&op.accessed_var_id)
+                            .unwrap_or(&op.accessed_var_id);
+                        let index_ids = &instr.as_ref()[4..];
+                        let is_nonuniform = std::iter::once(&op.var_id)
+                            .chain(index_ids)
+                            .any(|x| itm.deco_reg.contains(*x, spirv::Decoration::NonUniform));
+                        if is_nonuniform {
+                            itm.nonuniform_indexed_vars
+                                .entry(*func_id)
+                                .or_default()
+                                .insert(accessed_var_id);
+                        }
                         if self
                             .access_chain_map
-                            .insert(op.var_id, op.accessed_var_id)
+                            .insert(op.var_id, accessed_var_id)
                             .is_some()
                         {
                             return Err(anyhow!("duplicate access chain at a same id"));
                         }
-                    } else if op == Op::Load || is_atomic_load_op(op) {
-                        let op = OpLoad::try_from(instr)?;
-                        let mut var_id = op.var_id;
+                    } else if let Op::CopyObject | Op::CopyLogical | Op::Bitcast = op {
+                        // Pointers copied or reinterpreted still point to
+                        // the same variable.
+                        let op = OpCopyObjectCommonSPQ::try_from(instr)?;
+                        let copied_var_id = *self
+                            .access_chain_map
+                            .get(&op.copied_var_id)
+                            .unwrap_or(&op.copied_var_id);
+                        if self
+                            .access_chain_map
+                            .insert(op.var_id, copied_var_id)
+                            .is_some()
+                        {
+                            return Err(anyhow!("duplicate access chain at a same id"));
+                        }
+                    } else if op == Op::ImageTexelPointer {
+                        // Atomics on texel pointers access the image.
+                        let op = OpImageTexelPointer::try_from(instr)?;
+                        let image_var_id = *self
+                            .access_chain_map
+                            .get(&op.image_var_id)
+                            .unwrap_or(&op.image_var_id);
+                        if self
+                            .access_chain_map
+                            .insert(op.texel_ptr_id, image_var_id)
+                            .is_some()
+                        {
+                            return Err(anyhow!("duplicate access chain at a same id"));
+                        }
+                    } else if op == Op::Load || is_atomic_load_op(op) || is_coop_mat_load_op(op) {
+                        let load = OpLoad::try_from(instr)?;
+                        let mut var_id = load.var_id;
                         // Resolve access chain.
                         if let Some(&x) = self.access_chain_map.get(&var_id) {
                             var_id = x
                         }
                         func.accessed_vars.insert(var_id);
-                    } else if op == Op::Store || is_atomic_store_op(op) {
+                        if is_atomic_load_op(op) {
+                            itm.add_atomic_access(*func_id, var_id);
+                        }
+                        if op == Op::Load && itm.is_image_var(var_id) {
+                            // Loading an image handle doesn't access the
+                            // texels, so trace the handle to the variable
+                            // for image reads and writes.
+                            self.access_chain_map.insert(load.return_id, var_id);
+                        } else if op == Op::Load || op == Op::AtomicLoad || is_coop_mat_load_op(op)
+                        {
+                            itm.add_var_usage(*func_id, var_id, AccessType::ReadOnly);
+                        } else {
+                            itm.add_var_usage(*func_id, var_id, AccessType::ReadWrite);
+                        }
+                    } else if op == Op::Store || is_atomic_store_op(op) || is_coop_mat_store_op(op)
+                    {
                         let op = OpStore::try_from(instr)?;
                         let mut var_id = op.var_id;
                         // Resolve access chain.
@@ -659,6 +1274,24 @@ impl Inspector for FunctionInspector {
                             var_id = x
                         }
                         func.accessed_vars.insert(var_id);
+                        if is_atomic_store_op(instr.op()) {
+                            itm.add_atomic_access(*func_id, var_id);
+                        }
+                        itm.add_var_usage(*func_id, var_id, AccessType::WriteOnly);
+                    } else if let Op::ImageRead | Op::ImageSparseRead = op {
+                        let op = OpImageReadCommonSPQ::try_from(instr)?;
+                        let var_id = *self
+                            .access_chain_map
+                            .get(&op.image_id)
+                            .unwrap_or(&op.image_id);
+                        itm.add_var_usage(*func_id, var_id, AccessType::ReadOnly);
+                    } else if op == Op::ImageWrite {
+                        let op = OpImageWrite::try_from(instr)?;
+                        let var_id = *self
+                            .access_chain_map
+                            .get(&op.image_id)
+                            .unwrap_or(&op.image_id);
+                        itm.add_var_usage(*func_id, var_id, AccessType::WriteOnly);
                     }
                 } else {
                     return Err(anyhow!("unexpected opcode {:?}", instr.op()));
@@ -672,12 +1305,38 @@ impl Inspector for FunctionInspector {
 pub fn reflect<'a, I: Inspector>(
     itm: &mut ReflectIntermediate<'a>,
     instrs: &mut Instrs<'a>,
-    mut inspector: I,
+    inspector: I,
 ) -> Result<Vec<EntryPoint>> {
+    reflect_module(itm, instrs, inspector).map(|x| x.entry_points)
+}
+pub fn reflect_with_usage<'a, I: Inspector>(
+    itm: &mut ReflectIntermediate<'a>,
+    instrs: &mut Instrs<'a>,
+    mut inspector: I,
+) -> Result<Vec<(EntryPoint, UsageInfo)>> {
     itm.parse_global_declrs(instrs)?;
     itm.parse_functions(instrs, &mut inspector)?;
+    itm.propagate_ptr_args()?;
 
-    itm.collect_entry_points()
+    let entry_points = itm.collect_entry_points()?;
+    let usage_infos = itm.collect_usage_infos();
+    Ok(entry_points.into_iter().zip(usage_infos).collect())
+}
+pub fn reflect_module<'a, I: Inspector>(
+    itm: &mut ReflectIntermediate<'a>,
+    instrs: &mut Instrs<'a>,
+    mut inspector: I,
+) -> Result<ReflectedModule> {
+    itm.parse_global_declrs(instrs)?;
+    itm.parse_functions(instrs, &mut inspector)?;
+    itm.propagate_ptr_args()?;
+
+    let module = ReflectedModule {
+        info: itm.collect_module_info(),
+        metadata: itm.collect_module_metadata(),
+        entry_points: itm.collect_entry_points()?,
+    };
+    Ok(module)
 }
 
 fn make_desc_var(
@@ -686,6 +1345,8 @@ fn make_desc_var(
     var_id: VariableId,
     ptr_ty: &PointerType,
     ty: &Type,
+    conservative_access: bool,
+    access_qualifier: Option<AccessType>,
 ) -> Option<Variable> {
     // Unwrap multi-binding.
     let (nbind, ty) = match ty {
@@ -726,16 +1387,24 @@ fn make_desc_var(
         _ => ty.clone(),
     };
 
+    let get_access_ty = |ty: &Type| {
+        if conservative_access {
+            AccessType::ReadWrite
+        } else if let Some(access) = access_qualifier {
+            access
+        } else {
+            deco_reg
+                .get_desc_access_ty(var_id, ty)
+                .unwrap_or(AccessType::ReadWrite)
+        }
+    };
     let desc_bind = deco_reg.get_var_desc_bind_or_default(var_id);
     let desc_ty = match &ty {
         Type::Struct(_) => {
             // Compatibility for SPIR-V <= 1.3 is done when
             // extracting storage class deco for pointer types.
             if ptr_ty.store_cls == StorageClass::StorageBuffer {
-                let access = deco_reg
-                    .get_desc_access_ty(var_id, &ty)
-                    .unwrap_or(AccessType::ReadWrite);
-                DescriptorType::StorageBuffer(access)
+                DescriptorType::StorageBuffer(get_access_ty(&ty))
             } else {
                 DescriptorType::UniformBuffer()
             }
@@ -745,9 +1414,7 @@ fn make_desc_var(
             _ => DescriptorType::SampledImage(),
         },
         Type::StorageImage(store_image_ty) => {
-            let access = deco_reg
-                .get_desc_access_ty(var_id, &ty)
-                .unwrap_or(AccessType::ReadWrite);
+            let access = get_access_ty(&ty);
             match store_image_ty.dim {
                 spirv::Dim::DimBuffer => DescriptorType::StorageTexelBuffer(access),
                 _ => DescriptorType::StorageImage(access),
@@ -781,6 +1448,8 @@ fn make_var<'a>(
     name: Option<String>,
     var_id: VariableId,
     var_alloc: &VariableAlloc,
+    conservative_access: bool,
+    access_qualifier: Option<AccessType>,
 ) -> Option<Variable> {
     let ptr_ty = &var_alloc.ptr_ty;
     let ty = &*ptr_ty.pointee_ty;
@@ -831,7 +1500,15 @@ fn make_var<'a>(
             }
         }
         StorageClass::Uniform | StorageClass::StorageBuffer | StorageClass::UniformConstant => {
-            let var = make_desc_var(&deco_reg, name, var_id, &ptr_ty, ty)?;
+            let var = make_desc_var(
+                &deco_reg,
+                name,
+                var_id,
+                &ptr_ty,
+                ty,
+                conservative_access,
+                access_qualifier,
+            )?;
             Some(var)
         }
         _ => {
@@ -848,6 +1525,12 @@ impl<'a> ReflectIntermediate<'a> {
         // 1. All OpCapability instructions.
         while let Some(instr) = instrs.peek() {
             if instr.op() == Op::Capability {
+                let op = OpCapability::try_from(instr)?;
+                if let Some(capability) = spirv::Capability::from_u32(op.capability) {
+                    self.capabilities.push(capability);
+                } else {
+                    self.unknown_capabilities.push(op.capability);
+                }
                 instrs.next()?;
             } else {
                 break;
@@ -856,6 +1539,8 @@ impl<'a> ReflectIntermediate<'a> {
         // 2. Optional OpExtension instructions (extensions to SPIR-V).
         while let Some(instr) = instrs.peek() {
             if instr.op() == Op::Extension {
+                let op = OpExtension::try_from(instr)?;
+                self.extensions.push(op.name);
                 instrs.next()?;
             } else {
                 break;
@@ -878,16 +1563,26 @@ impl<'a> ReflectIntermediate<'a> {
         if let Some(instr) = instrs.peek() {
             if instr.op() == Op::MemoryModel {
                 let op = OpMemoryModel::try_from(instr)?;
+                // OpenCL kernels are not supported, see the crate-level
+                // documentation. Name the capability so that it's clear why
+                // the models are refused.
+                let note = if self.capabilities.contains(&spirv::Capability::Kernel) {
+                    " of capability Kernel"
+                } else {
+                    ""
+                };
                 match op.addr_model {
                     spirv::AddressingModel::Logical => {}
                     spirv::AddressingModel::PhysicalStorageBuffer64 => {}
-                    _ => return Err(anyhow!("unsupported addressing model")),
+                    x => return Err(anyhow!("unsupported addressing model {:?}{}", x, note)),
                 }
                 match op.mem_model {
                     spirv::MemoryModel::GLSL450 => {}
                     spirv::MemoryModel::Vulkan => {}
-                    _ => return Err(anyhow!("unsupported memory model")),
+                    x => return Err(anyhow!("unsupported memory model {:?}{}", x, note)),
                 }
+                self.addressing_model = Some(op.addr_model);
+                self.memory_model = Some(op.mem_model);
                 instrs.next()?;
             }
         }
@@ -899,6 +1594,7 @@ impl<'a> ReflectIntermediate<'a> {
                     exec_model: op.exec_model,
                     name: op.name,
                     exec_modes: Default::default(),
+                    interface_var_ids: op.interface_var_ids,
                 };
                 use std::collections::hash_map::Entry;
                 match self.entry_point_declrs.entry(op.func_id) {
@@ -958,11 +1654,21 @@ impl<'a> ReflectIntermediate<'a> {
         //   c. All OpModuleProcessed instructions.
         while let Some(instr) = instrs.peek() {
             match instr.op() {
-                Op::String
-                | Op::SourceExtension
-                | Op::Source
-                | Op::SourceContinued
-                | Op::ModuleProcessed => {
+                Op::String => {
+                    let op = OpString::try_from(instr)?;
+                    self.strings.insert(op.string_id, op.string);
+                    instrs.next()?;
+                }
+                Op::Source => {
+                    // Source language and version are followed by an optional
+                    // file name string.
+                    let mut operands = instr.operands().skip(2);
+                    if let Some(file_id) = operands.next() {
+                        self.add_source_file(file_id);
+                    }
+                    instrs.next()?;
+                }
+                Op::SourceExtension | Op::SourceContinued | Op::ModuleProcessed => {
                     instrs.next()?;
                 }
                 Op::Name => {
@@ -970,6 +1676,7 @@ impl<'a> ReflectIntermediate<'a> {
                     if !op.name.is_empty() {
                         // Ignore empty names.
                         self.name_reg.set(op.target_id, op.name);
+                        self.debug_names.insert(op.name);
                     }
                     instrs.next()?;
                 }
@@ -978,6 +1685,7 @@ impl<'a> ReflectIntermediate<'a> {
                     if !op.name.is_empty() {
                         self.name_reg
                             .set_member(op.target_id, op.member_idx, op.name);
+                        self.debug_names.insert(op.name);
                     }
                     instrs.next()?;
                 }
@@ -991,22 +1699,38 @@ impl<'a> ReflectIntermediate<'a> {
                 Op::Decorate => {
                     let op = OpDecorate::try_from(instr)?;
                     let deco = op.deco;
+                    if deco == spirv::Decoration::BuiltIn {
+                        self.add_builtin(op.target_id, op.params)?;
+                    }
                     self.deco_reg.set(op.target_id, deco, op.params)?;
                     instrs.next()?;
                 }
                 Op::MemberDecorate => {
                     let op = OpMemberDecorate::try_from(instr)?;
                     let deco = op.deco;
+                    if deco == spirv::Decoration::BuiltIn {
+                        self.add_member_builtin(op.target_id, op.member_idx, op.params)?;
+                    }
                     self.deco_reg
                         .set_member(op.target_id, op.member_idx, deco, op.params)?;
                     instrs.next()?;
                 }
+                Op::DecorateString => {
+                    // Also known as `OpDecorateStringGOOGLE` in
+                    // `SPV_GOOGLE_hlsl_functionality1`.
+                    let op = OpDecorateString::try_from(instr)?;
+                    if op.deco == spirv::Decoration::UserSemantic as u32 {
+                        self.user_semantics.insert(op.target_id, op.value);
+                    }
+                    instrs.next()?;
+                }
                 Op::DecorationGroup
                 | Op::GroupDecorate
                 | Op::GroupMemberDecorate
                 | Op::DecorateId
-                | Op::DecorateString
                 | Op::MemberDecorateString => {
+                    self.cfg
+                        .diagnose(Diagnostic::SkippedInstr { opcode: instr.op() });
                     instrs.next()?;
                 }
                 _ => break,
@@ -1023,7 +1747,13 @@ impl<'a> ReflectIntermediate<'a> {
         //   b. Non-semantic instructions with OpExtInst.
         while let Some(instr) = instrs.peek() {
             let opcode = instr.op();
+            if opcode == Op::Line {
+                self.add_source_file(instr.operands().read_id()?);
+            }
             if let Op::Line | Op::NoLine | Op::ExtInst | Op::Undef = opcode {
+                if opcode == Op::ExtInst {
+                    self.cfg.diagnose(Diagnostic::SkippedInstr { opcode });
+                }
                 instrs.next()?;
                 continue;
             }
@@ -1063,7 +1793,13 @@ impl<'a> ReflectIntermediate<'a> {
         //   f. Function end, using OpFunctionEnd.
 
         while let Some(instr) = instrs.peek() {
+            if inspector.is_done() {
+                break;
+            }
             let opcode = instr.op();
+            if opcode == Op::Line {
+                self.add_source_file(instr.operands().read_id()?);
+            }
             if let Op::Line | Op::NoLine = opcode {
                 instrs.next()?;
                 continue;
@@ -1075,6 +1811,149 @@ impl<'a> ReflectIntermediate<'a> {
         Ok(())
     }
 
+    /// Mark arguments as accessed by the callers if the corresponding
+    /// parameters are accessed by the callees, so that variables accessed
+    /// only through pointers passed to other functions are still considered
+    /// used. Pointers can be passed down multiple levels of calls, so this is
+    /// repeated until no more variable is marked.
+    fn propagate_ptr_args(&mut self) -> Result<()> {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (caller_id, callee_id, arg_ids) in self.func_calls.iter() {
+                let param_ids = match self.func_params.get(callee_id) {
+                    Some(x) => x,
+                    None => continue,
+                };
+                // Functions are missing if function parsing is stopped early
+                // by the inspector.
+                let callee = match self.func_reg.get(*callee_id) {
+                    Ok(x) => x,
+                    Err(_) => continue,
+                };
+                let accessed_arg_ids = param_ids
+                    .iter()
+                    .zip(arg_ids.iter())
+                    .filter(|(param_id, _)| callee.accessed_vars.contains(param_id))
+                    .map(|(_, arg_id)| *arg_id)
+                    .collect::<Vec<_>>();
+                let caller = match self.func_reg.get_mut(*caller_id) {
+                    Ok(x) => x,
+                    Err(_) => continue,
+                };
+                for arg_id in accessed_arg_ids {
+                    changed |= caller.accessed_vars.insert(arg_id);
+                }
+                let nonuniform_arg_ids = match self.nonuniform_indexed_vars.get(callee_id) {
+                    Some(var_ids) => param_ids
+                        .iter()
+                        .zip(arg_ids.iter())
+                        .filter(|(param_id, _)| var_ids.contains(param_id))
+                        .map(|(_, arg_id)| *arg_id)
+                        .collect::<Vec<_>>(),
+                    None => Vec::new(),
+                };
+                if !nonuniform_arg_ids.is_empty() {
+                    let caller_var_ids =
+                        self.nonuniform_indexed_vars.entry(*caller_id).or_default();
+                    for arg_id in nonuniform_arg_ids {
+                        changed |= caller_var_ids.insert(arg_id);
+                    }
+                }
+                let atomic_arg_ids = match self.atomic_accessed_vars.get(callee_id) {
+                    Some(var_ids) => param_ids
+                        .iter()
+                        .zip(arg_ids.iter())
+                        .filter(|(param_id, _)| var_ids.contains(param_id))
+                        .map(|(_, arg_id)| *arg_id)
+                        .collect::<Vec<_>>(),
+                    None => Vec::new(),
+                };
+                if !atomic_arg_ids.is_empty() {
+                    let caller_var_ids = self.atomic_accessed_vars.entry(*caller_id).or_default();
+                    for arg_id in atomic_arg_ids {
+                        changed |= caller_var_ids.insert(arg_id);
+                    }
+                }
+                let arg_usages = match self.var_usages.get(callee_id) {
+                    Some(usages) => param_ids
+                        .iter()
+                        .zip(arg_ids.iter())
+                        .filter_map(|(param_id, arg_id)| Some((*arg_id, *usages.get(param_id)?)))
+                        .collect::<Vec<_>>(),
+                    None => continue,
+                };
+                let caller_usages = self.var_usages.entry(*caller_id).or_default();
+                for (arg_id, usage) in arg_usages {
+                    let caller_usage = caller_usages.entry(arg_id).or_insert(usage);
+                    let merged = *caller_usage | usage;
+                    changed |= merged != *caller_usage;
+                    *caller_usage = merged;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Name generated from `stem` for an object without debug name, if
+    /// [`ReflectConfig::gen_unique_names`] is set. Stems are made of the kind
+    /// and the ID of the object, so generated names are stable across runs
+    /// and unique among themselves. Underscores are appended until the name
+    /// doesn't collide with any debug name; no stem ends with an underscore.
+    fn gen_unique_name(&self, stem: String) -> Option<String> {
+        if !self.cfg.gen_unique_names {
+            return None;
+        }
+        let mut name = stem;
+        while self.debug_names.contains(name.as_str()) {
+            name.push('_');
+        }
+        Some(name)
+    }
+    fn add_var_usage(&mut self, func_id: FunctionId, var_id: VariableId, usage: AccessType) {
+        self.var_usages
+            .entry(func_id)
+            .or_default()
+            .entry(var_id)
+            .and_modify(|x| *x = *x | usage)
+            .or_insert(usage);
+    }
+    fn add_atomic_access(&mut self, func_id: FunctionId, var_id: VariableId) {
+        self.atomic_accessed_vars
+            .entry(func_id)
+            .or_default()
+            .insert(var_id);
+    }
+    /// Returns true if `var_id` is a variable of images or of arrays of
+    /// images, i.e., whose loads give image handles rather than texels.
+    fn is_image_var(&self, var_id: VariableId) -> bool {
+        let mut ty = match self.var_reg.get(var_id) {
+            Ok(var_alloc) => &*var_alloc.ptr_ty.pointee_ty,
+            Err(_) => return false,
+        };
+        while let Type::Array(arr_ty) = ty {
+            ty = &*arr_ty.element_ty;
+        }
+        matches!(ty, Type::Image(_))
+    }
+
+    /// Returns true if `instr` is an `OpExtInst` of an extended instruction
+    /// set imported by a name starting with `NonSemantic.`.
+    fn is_non_semantic_ext_instr(&mut self, instr: &Instr) -> Result<bool> {
+        let mut operands = instr.operands();
+        let _result_ty_id = operands.read_id()?;
+        let _result_id = operands.read_id()?;
+        let instr_set_id = operands.read_id()?;
+        let name = self.interp.get_ext_instr_set_name(instr_set_id)?;
+        Ok(name.starts_with("NonSemantic."))
+    }
+
+    fn add_source_file(&mut self, file_id: InstrId) {
+        if !self.source_file_ids.contains(&file_id) {
+            self.source_file_ids.push(file_id);
+        }
+    }
+
     fn collect_vars_impl(&self) -> BTreeMap<VariableId, Variable> {
         // `BTreeMap` to ensure a stable order.
         let mut vars = BTreeMap::new();
@@ -1083,44 +1962,333 @@ impl<'a> ReflectIntermediate<'a> {
                 .name_reg
                 .get(*var_id)
                 .map(ToOwned::to_owned)
-                .or_else(|| {
-                    if self.cfg.gen_unique_names {
-                        Some(format!("var_{}", var_id))
-                    } else {
-                        None
-                    }
-                });
-            if let Some(var) = make_var(&self.deco_reg, name, *var_id, var_alloc) {
+                .or_else(|| self.gen_unique_name(format!("var_{}", var_id)));
+            if let Some(var) = make_var(
+                &self.deco_reg,
+                name,
+                *var_id,
+                var_alloc,
+                self.cfg.conservative_access,
+                self.image_access_qualifiers.get(var_id).copied(),
+            ) {
                 vars.insert(*var_id, var);
             }
         }
         vars
     }
-    fn collect_vars(&self) -> Vec<Variable> {
+    fn collect_entry_point_vars_impl(&self, func_id: FunctionId) -> BTreeMap<VariableId, Variable> {
+        let accessed_var_ids = self
+            .func_reg
+            .collect_fn_vars(func_id)
+            .into_iter()
+            .collect::<HashSet<_>>();
         self.collect_vars_impl()
             .into_iter()
-            .map(|(_, var)| var)
+            .filter(|(var_id, _)| accessed_var_ids.contains(var_id))
             .collect()
     }
-
-    fn collect_entry_point_vars(&self, func_id: FunctionId) -> Vec<Variable> {
+    /// The function `func_id` and all the functions it calls, directly or
+    /// indirectly.
+    fn collect_reachable_funcs(&self, func_id: FunctionId) -> Vec<FunctionId> {
+        let mut out = Vec::new();
+        let mut visited = HashSet::default();
+        let mut stack = vec![func_id];
+        while let Some(func_id) = stack.pop() {
+            if !visited.insert(func_id) {
+                continue;
+            }
+            out.push(func_id);
+            if let Ok(func) = self.func_reg.get(func_id) {
+                stack.extend(func.callees.iter().copied());
+            }
+        }
+        out
+    }
+    /// Kinds of access to variables by the function `func_id` and all the
+    /// functions it calls.
+    fn collect_var_usages(&self, func_id: FunctionId) -> HashMap<VariableId, AccessType> {
+        let mut out = HashMap::<VariableId, AccessType>::default();
+        for func_id in self.collect_reachable_funcs(func_id) {
+            if let Some(usages) = self.var_usages.get(&func_id) {
+                for (var_id, usage) in usages.iter() {
+                    out.entry(*var_id)
+                        .and_modify(|x| *x = *x | *usage)
+                        .or_insert(*usage);
+                }
+            }
+        }
+        out
+    }
+    fn narrow_access_by_usage(
+        &self,
+        func_id: FunctionId,
+        vars: &mut BTreeMap<VariableId, Variable>,
+    ) {
+        let usages = self.collect_var_usages(func_id);
+        for (var_id, var) in vars.iter_mut() {
+            let access = match var {
+                Variable::Descriptor {
+                    desc_ty:
+                        DescriptorType::StorageBuffer(access)
+                        | DescriptorType::StorageImage(access)
+                        | DescriptorType::StorageTexelBuffer(access),
+                    ..
+                } => access,
+                _ => continue,
+            };
+            // Decorated access types are kept as they are.
+            if *access == AccessType::ReadWrite {
+                if let Some(usage) = usages.get(var_id) {
+                    *access = *usage;
+                }
+            }
+        }
+    }
+    fn collect_per_primitive_outputs(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<InterfaceLocation> {
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Output { location, .. }
+                    if self
+                        .deco_reg
+                        .contains(*var_id, spirv::Decoration::PerPrimitiveNV) =>
+                {
+                    Some(*location)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    /// Outputs decorated with `Index` and their indices.
+    fn collect_output_indices(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<(Variable, u32)> {
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Output { .. } => {
+                    let index = self
+                        .deco_reg
+                        .get_u32(*var_id, spirv::Decoration::Index)
+                        .ok()?;
+                    Some((var.clone(), index))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    fn collect_xfb_outputs(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<(InterfaceLocation, XfbInfo)> {
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Output { location, ty, .. } => {
+                    let buffer = self
+                        .deco_reg
+                        .get_u32(*var_id, spirv::Decoration::XfbBuffer)
+                        .ok()?;
+                    let stride = self
+                        .deco_reg
+                        .get_u32(*var_id, spirv::Decoration::XfbStride)
+                        .ok()?;
+                    let offset = self
+                        .deco_reg
+                        .get_u32(*var_id, spirv::Decoration::Offset)
+                        .ok()
+                        .or_else(|| {
+                            let struct_ty = ty.as_struct()?;
+                            let offset = struct_ty.members.iter().filter_map(|x| x.offset).min()?;
+                            Some(offset as u32)
+                        })
+                        .unwrap_or(0);
+                    let xfb = XfbInfo {
+                        buffer,
+                        stride,
+                        offset,
+                    };
+                    Some((*location, xfb))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    fn collect_binding_arrays(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<DescriptorBinding> {
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Descriptor { desc_bind, .. } => {
+                    let var_alloc = self.var_reg.get(*var_id).ok()?;
+                    if var_alloc.ptr_ty.pointee_ty.is_array() {
+                        Some(*desc_bind)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    fn collect_nonuniform_indexed(
+        &self,
+        func_id: FunctionId,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<DescriptorBinding> {
+        let var_ids = self
+            .collect_reachable_funcs(func_id)
+            .into_iter()
+            .filter_map(|x| self.nonuniform_indexed_vars.get(&x))
+            .flatten()
+            .collect::<HashSet<_>>();
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Descriptor { desc_bind, .. } if var_ids.contains(var_id) => {
+                    Some(*desc_bind)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    fn collect_atomic_accessed(
+        &self,
+        func_id: FunctionId,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<DescriptorBinding> {
+        let var_ids = self
+            .collect_reachable_funcs(func_id)
+            .into_iter()
+            .filter_map(|x| self.atomic_accessed_vars.get(&x))
+            .flatten()
+            .collect::<HashSet<_>>();
+        vars.iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Descriptor { desc_bind, .. } if var_ids.contains(var_id) => {
+                    Some(*desc_bind)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    fn collect_relaxed_precision_vars(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<Locator> {
+        vars.iter()
+            .filter(|(var_id, _)| {
+                self.deco_reg
+                    .contains(**var_id, spirv::Decoration::RelaxedPrecision)
+            })
+            .map(|(_, var)| Locator::from(var))
+            .collect()
+    }
+    /// Total size of `Workgroup` variables of an entry point, laid out by the
+    /// standard storage buffer layout as the upper bound allowed by the Vulkan
+    /// specification.
+    fn collect_shared_memory_bytes(&self, func_id: FunctionId) -> Option<usize> {
+        let accessed_var_ids = if self.cfg.ref_all_rscs {
+            None
+        } else {
+            Some(
+                self.func_reg
+                    .collect_fn_vars(func_id)
+                    .into_iter()
+                    .collect::<HashSet<_>>(),
+            )
+        };
+        let mut nbyte: usize = 0;
+        // `BTreeMap` to ensure the variables are laid out in declaration order.
+        for (var_id, var_alloc) in self.var_reg.iter().collect::<BTreeMap<_, _>>() {
+            if var_alloc.store_cls != StorageClass::Workgroup {
+                continue;
+            }
+            if let Some(accessed_var_ids) = &accessed_var_ids {
+                if !accessed_var_ids.contains(var_id) {
+                    continue;
+                }
+            }
+            if !self.cfg.assume_spec_default && self.spec_dependent_ids.contains(var_id) {
+                return None;
+            }
+            let ty = &*var_alloc.ptr_ty.pointee_ty;
+            let align = LayoutRule::Std430.align_of(ty)?;
+            nbyte = nbyte.div_ceil(align) * align + LayoutRule::Std430.size_of(ty)?;
+        }
+        Some(nbyte)
+    }
+    fn collect_user_semantics(
+        &self,
+        vars: &BTreeMap<VariableId, Variable>,
+    ) -> Vec<(Locator, String)> {
+        vars.iter()
+            .filter(|(_, var)| matches!(var, Variable::Input { .. } | Variable::Output { .. }))
+            .filter_map(|(var_id, var)| {
+                let semantic = self.user_semantics.get(var_id)?;
+                Some((Locator::from(var), semantic.to_string()))
+            })
+            .collect()
+    }
+    /// Built-in inputs and outputs used by the entry point, or declared in the
+    /// module if `ref_all_rscs` is set, in the order of variable IDs.
+    fn collect_builtins(&self, func_id: FunctionId) -> Vec<(StorageClass, spirv::BuiltIn)> {
+        self.collect_interface_var_ids(func_id)
+            .into_iter()
+            .flat_map(|(var_id, store_cls)| {
+                self.builtins
+                    .get(&var_id)
+                    .into_iter()
+                    .flatten()
+                    .map(move |builtin| (store_cls, *builtin))
+            })
+            .collect()
+    }
+    /// Built-in members of interface blocks used by the entry point, or
+    /// declared in the module if `ref_all_rscs` is set, in the order of
+    /// variable IDs and then member indices.
+    fn collect_builtin_members(
+        &self,
+        func_id: FunctionId,
+    ) -> Vec<(StorageClass, u32, spirv::BuiltIn)> {
+        self.collect_interface_var_ids(func_id)
+            .into_iter()
+            .flat_map(|(var_id, store_cls)| {
+                let mut member_builtins = self
+                    .member_builtins
+                    .get(&var_id)
+                    .cloned()
+                    .unwrap_or_default();
+                member_builtins.sort_by_key(|(member_idx, _)| *member_idx);
+                member_builtins
+                    .into_iter()
+                    .map(move |(member_idx, builtin)| (store_cls, member_idx, builtin))
+            })
+            .collect()
+    }
+    /// Input and output variables used by the entry point, or declared in the
+    /// module if `ref_all_rscs` is set, sorted by variable IDs.
+    fn collect_interface_var_ids(&self, func_id: FunctionId) -> Vec<(VariableId, StorageClass)> {
         let accessed_var_ids = self
             .func_reg
             .collect_fn_vars(func_id)
             .into_iter()
             .collect::<HashSet<_>>();
-        let vars = self
-            .collect_vars_impl()
-            .into_iter()
-            .filter_map(|(var_id, var)| {
-                if accessed_var_ids.contains(&var_id) {
-                    Some(var)
-                } else {
-                    None
-                }
+        let mut var_ids = self
+            .var_reg
+            .iter()
+            .filter(|(var_id, _)| self.cfg.ref_all_rscs || accessed_var_ids.contains(var_id))
+            .filter(|(_, var_alloc)| {
+                matches!(
+                    var_alloc.store_cls,
+                    StorageClass::Input | StorageClass::Output
+                )
             })
+            .map(|(var_id, var_alloc)| (*var_id, var_alloc.store_cls))
             .collect::<Vec<_>>();
-        vars
+        var_ids.sort_by_key(|(var_id, _)| *var_id);
+        var_ids
     }
     fn collect_entry_point_specs(&self) -> Result<Vec<Variable>> {
         // TODO: (penguinlion) Report only specialization constants that have
@@ -1139,6 +2307,48 @@ impl<'a> ReflectIntermediate<'a> {
         }
         Ok(vars)
     }
+    /// Workgroup size of a compute-like entry point. A constant decorated
+    /// with `BuiltIn WorkgroupSize` takes precedence over the `LocalSize` and
+    /// `LocalSizeId` execution modes.
+    fn collect_local_size(
+        &self,
+        exec_model: spirv::ExecutionModel,
+        exec_modes: &[ExecutionMode],
+    ) -> Result<Option<[Constant; 3]>> {
+        use spirv::ExecutionModel::*;
+        if !matches!(
+            exec_model,
+            GLCompute | Kernel | TaskNV | MeshNV | TaskEXT | MeshEXT
+        ) {
+            return Ok(None);
+        }
+        let workgroup_size_id = self.composite_constituents.keys().find(|&&id| {
+            self.deco_reg.get_u32(id, spirv::Decoration::BuiltIn).ok()
+                == Some(spirv::BuiltIn::WorkgroupSize as u32)
+        });
+        let local_size = if let Some(id) = workgroup_size_id {
+            let constituent_ids = self.composite_constituents[id];
+            if constituent_ids.len() != 3 {
+                return Err(anyhow!("workgroup size must have 3 components"));
+            }
+            constituent_ids
+                .iter()
+                .map(|&x| self.interp.get(x).cloned())
+                .collect::<Result<Vec<_>>>()?
+        } else if let Some(exec_mode) = exec_modes.iter().find(|x| {
+            matches!(
+                x.exec_mode,
+                spirv::ExecutionMode::LocalSize | spirv::ExecutionMode::LocalSizeId
+            )
+        }) {
+            exec_mode.operands.clone()
+        } else {
+            return Ok(None);
+        };
+        let local_size = <[Constant; 3]>::try_from(local_size)
+            .map_err(|_| anyhow!("workgroup size must have 3 components"))?;
+        Ok(Some(local_size))
+    }
     fn collect_exec_modes(
         &self,
         func_id: FunctionId,
@@ -1272,26 +2482,241 @@ fn combine_img_samplers(vars: Vec<Variable>) -> Vec<Variable> {
     out_vars
 }
 
+/// Split `DescriptorType::CombinedImageSampler` into a
+/// `DescriptorType::SampledImage` at the same binding point, and a
+/// `DescriptorType::Sampler` at the binding point offset by
+/// `sampler_bind_offset`. Samplers split from binding arrays are added to
+/// `binding_arrays`, and those split from non-uniformly indexed descriptors
+/// are added to `nonuniform_indexed`.
+fn split_combined_img_samplers(
+    vars: Vec<Variable>,
+    sampler_bind_offset: u32,
+    binding_arrays: &mut Vec<DescriptorBinding>,
+    nonuniform_indexed: &mut Vec<DescriptorBinding>,
+) -> Result<Vec<Variable>> {
+    let mut out_vars = Vec::<Variable>::with_capacity(vars.len());
+    let mut samplers = Vec::<Variable>::new();
+
+    for var in vars {
+        match var {
+            Variable::Descriptor {
+                name,
+                desc_bind,
+                desc_ty: DescriptorType::CombinedImageSampler(),
+                ty: Type::CombinedImageSampler(combined_img_sampler_ty),
+                nbind,
+            } => {
+                let sampler_desc_bind = DescriptorBinding::new(
+                    desc_bind.set(),
+                    desc_bind
+                        .bind()
+                        .checked_add(sampler_bind_offset)
+                        .ok_or_else(|| anyhow!("sampler binding of {} overflows", desc_bind))?,
+                );
+                out_vars.push(Variable::Descriptor {
+                    name: name.clone(),
+                    desc_bind,
+                    desc_ty: DescriptorType::SampledImage(),
+                    ty: Type::SampledImage(combined_img_sampler_ty.sampled_image_ty),
+                    nbind,
+                });
+                samplers.push(Variable::Descriptor {
+                    name,
+                    desc_bind: sampler_desc_bind,
+                    desc_ty: DescriptorType::Sampler(),
+                    ty: Type::Sampler(SamplerType {}),
+                    nbind,
+                });
+                if binding_arrays.contains(&desc_bind)
+                    && !binding_arrays.contains(&sampler_desc_bind)
+                {
+                    binding_arrays.push(sampler_desc_bind);
+                }
+                if nonuniform_indexed.contains(&desc_bind)
+                    && !nonuniform_indexed.contains(&sampler_desc_bind)
+                {
+                    nonuniform_indexed.push(sampler_desc_bind);
+                }
+            }
+            _ => out_vars.push(var),
+        }
+    }
+
+    if sampler_bind_offset != 0 {
+        // Binding points `[bind, bind + nbind)` of a descriptor. Runtime
+        // binding arrays are regarded to take a single binding point.
+        let bind_range = |var: &Variable| match var {
+            Variable::Descriptor {
+                desc_bind, nbind, ..
+            } => Some((
+                desc_bind.set(),
+                desc_bind.bind()..desc_bind.bind() + (*nbind).max(1),
+            )),
+            _ => None,
+        };
+        for sampler_var in samplers.iter() {
+            let (set, sampler_range) = bind_range(sampler_var).unwrap();
+            let collision = out_vars.iter().chain(samplers.iter()).find(|var| {
+                !std::ptr::eq(*var, sampler_var)
+                    && bind_range(var).is_some_and(|(x, range)| {
+                        x == set
+                            && range.start < sampler_range.end
+                            && sampler_range.start < range.end
+                    })
+            });
+            if let Some(var) = collision {
+                return Err(anyhow!(
+                    "split sampler at {} collides with descriptor {}",
+                    DescriptorBinding::new(set, sampler_range.start),
+                    Locator::from(var)
+                ));
+            }
+        }
+    }
+
+    out_vars.extend(samplers);
+    Ok(out_vars)
+}
+
 impl<'a> ReflectIntermediate<'a> {
+    pub fn collect_module_metadata(&self) -> ModuleMetadata {
+        let source_files = self
+            .source_file_ids
+            .iter()
+            .filter_map(|file_id| self.strings.get(file_id))
+            .map(|x| x.to_string())
+            .collect();
+        ModuleMetadata { source_files }
+    }
+    pub fn collect_module_info(&self) -> ModuleInfo {
+        ModuleInfo {
+            version: self.version,
+            capabilities: self.capabilities.clone(),
+            unknown_capabilities: self.unknown_capabilities.clone(),
+            extensions: self.extensions.iter().map(|x| x.to_string()).collect(),
+            // The memory model instruction is required by the specification
+            // but we don't complain about its absence; fall back to the
+            // models Vulkan shaders are most commonly compiled with.
+            addressing_model: self
+                .addressing_model
+                .unwrap_or(spirv::AddressingModel::Logical),
+            memory_model: self.memory_model.unwrap_or(spirv::MemoryModel::GLSL450),
+        }
+    }
+    /// Entry point declarations selected by [`ReflectConfig::entry_point`].
+    fn selected_entry_point_declrs(
+        &self,
+    ) -> impl Iterator<Item = (&FunctionId, &EntryPointDeclaration<'a>)> {
+        let name = self.cfg.entry_point_name.as_ref();
+        self.entry_point_declrs
+            .iter()
+            .filter(move |(_, declr)| match name {
+                Some(name) => declr.name == name,
+                None => true,
+            })
+    }
+    /// Reachability of all descriptors from each of the entry points, in
+    /// the same order as [`ReflectIntermediate::collect_entry_points`].
+    pub fn collect_usage_infos(&self) -> Vec<UsageInfo> {
+        let descs = self
+            .collect_vars_impl()
+            .into_iter()
+            .filter_map(|(var_id, var)| match var {
+                Variable::Descriptor { desc_bind, .. } => Some((var_id, desc_bind)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut out = Vec::new();
+        for (id, _) in self.selected_entry_point_declrs() {
+            let mut func_ids = self.collect_reachable_funcs(*id);
+            func_ids.sort_unstable();
+            let mut usages = BTreeMap::<DescriptorBinding, DescriptorUsage>::new();
+            for (var_id, desc_bind) in descs.iter() {
+                // Combined image samplers and split samplers can share a
+                // binding point with another variable.
+                let usage = usages.entry(*desc_bind).or_default();
+                for func_id in func_ids.iter() {
+                    let is_accessed = self
+                        .func_reg
+                        .get(*func_id)
+                        .is_ok_and(|func| func.accessed_vars.contains(var_id));
+                    if is_accessed && !usage.func_ids.contains(func_id) {
+                        usage.func_ids.push(*func_id);
+                    }
+                }
+                usage.func_ids.sort_unstable();
+                usage.is_reachable = !usage.func_ids.is_empty();
+            }
+            let descriptors = usages.into_iter().collect();
+            out.push(UsageInfo { descriptors });
+        }
+        out
+    }
     pub fn collect_entry_points(&self) -> Result<Vec<EntryPoint>> {
         let mut entry_points = Vec::with_capacity(self.entry_point_declrs.len());
-        for (id, entry_point_declr) in self.entry_point_declrs.iter() {
+        for (id, entry_point_declr) in self.selected_entry_point_declrs() {
             let mut vars = if self.cfg.ref_all_rscs {
-                self.collect_vars()
+                self.collect_vars_impl()
             } else {
-                self.collect_entry_point_vars(*id)
+                self.collect_entry_point_vars_impl(*id)
             };
+            if self.cfg.infer_access_from_usage && !self.cfg.conservative_access {
+                self.narrow_access_by_usage(*id, &mut vars);
+            }
+            let per_primitive_outputs = self.collect_per_primitive_outputs(&vars);
+            let output_indices = self.collect_output_indices(&vars);
+            let xfb_outputs = self.collect_xfb_outputs(&vars);
+            let relaxed_precision_vars = self.collect_relaxed_precision_vars(&vars);
+            let mut binding_arrays = self.collect_binding_arrays(&vars);
+            let mut nonuniform_indexed = self.collect_nonuniform_indexed(*id, &vars);
+            let atomic_accessed = self.collect_atomic_accessed(*id, &vars);
+            let user_semantics = self.collect_user_semantics(&vars);
+            let mut vars = vars.into_iter().map(|(_, var)| var).collect::<Vec<_>>();
             if self.cfg.combine_img_samplers {
                 vars = combine_img_samplers(vars);
             }
+            if self.cfg.split_combined_img_samplers {
+                vars = split_combined_img_samplers(
+                    vars,
+                    self.cfg.split_sampler_bind_offset,
+                    &mut binding_arrays,
+                    &mut nonuniform_indexed,
+                )?;
+            }
             let specs = self.collect_entry_point_specs()?;
             vars.extend(specs);
             let exec_modes = self.collect_exec_modes(*id, &entry_point_declr.exec_modes)?;
+            let shared_memory_bytes = self.collect_shared_memory_bytes(*id);
+            let local_size = self.collect_local_size(entry_point_declr.exec_model, &exec_modes)?;
+            let builtins = self.collect_builtins(*id);
+            let builtin_members = self.collect_builtin_members(*id);
+            let mut spec_defaults = self
+                .spec_defaults
+                .iter()
+                .map(|(spec_id, value)| (*spec_id, value.clone()))
+                .collect::<Vec<_>>();
+            spec_defaults.sort_by_key(|(spec_id, _)| *spec_id);
             let entry_point = EntryPoint {
                 name: entry_point_declr.name.to_owned(),
                 exec_model: entry_point_declr.exec_model,
                 vars,
                 exec_modes,
+                info: EntryPointInfo {
+                    per_primitive_outputs,
+                    output_indices,
+                    xfb_outputs,
+                    relaxed_precision_vars,
+                    binding_arrays,
+                    nonuniform_indexed,
+                    atomic_accessed,
+                    shared_memory_bytes,
+                    user_semantics,
+                    spec_defaults,
+                    local_size,
+                    builtins,
+                    builtin_members,
+                    interface_var_ids: entry_point_declr.interface_var_ids.to_owned(),
+                },
             };
             entry_points.push(entry_point);
         }
@@ -1,32 +1,106 @@
+use std::{ops::ControlFlow, sync::Arc};
+
 use fnv::FnvHashMap as HashMap;
 
 use crate::{
     constant::ConstantValue,
+    diagnostic::Diagnostic,
     entry_point::EntryPoint,
-    error::Result,
-    inspect::{FnInspector, Inspector},
+    error::{anyhow, Result},
+    inspect::{FnInspector, Inspector, TryFnInspector},
+    module::{merge_descriptors, merge_push_constant_ranges, Pipeline, ReflectedModule, UsageInfo},
     parse::{Instr, SpirvBinary},
-    reflect::{reflect, FunctionInspector, ReflectIntermediate},
+    reflect::{
+        checked_instrs, reflect, reflect_module, reflect_with_usage, FunctionInspector,
+        ReflectIntermediate,
+    },
     var::SpecId,
 };
 
+/// SPIR-V words to be reflected, either owned by the config or shared with
+/// other configs.
+#[derive(Clone)]
+pub(crate) enum SpvWords {
+    Owned(SpirvBinary),
+    Shared(Arc<[u32]>),
+}
+impl SpvWords {
+    pub(crate) fn words(&self) -> &[u32] {
+        match self {
+            SpvWords::Owned(spv) => spv.words(),
+            SpvWords::Shared(words) => words,
+        }
+    }
+}
+
 /// Reflection configuration builder.
 #[derive(Default, Clone)]
 pub struct ReflectConfig {
-    pub(crate) spv: Option<SpirvBinary>,
+    pub(crate) spv: Option<SpvWords>,
+    pub(crate) extra_spvs: Vec<SpvWords>,
     pub(crate) ref_all_rscs: bool,
     pub(crate) combine_img_samplers: bool,
+    pub(crate) split_combined_img_samplers: bool,
+    pub(crate) split_sampler_bind_offset: u32,
     pub(crate) gen_unique_names: bool,
+    pub(crate) conservative_access: bool,
+    pub(crate) infer_access_from_usage: bool,
+    pub(crate) assume_spec_default: bool,
     pub(crate) spec_values: HashMap<SpecId, ConstantValue>,
+    pub(crate) entry_point_name: Option<String>,
+    pub(crate) diagnostic_sink: Option<fn(Diagnostic)>,
 }
 impl ReflectConfig {
     pub fn new() -> Self {
         Default::default()
     }
+    pub(crate) fn diagnose(&self, diag: Diagnostic) {
+        if let Some(sink) = self.diagnostic_sink {
+            sink(diag);
+        }
+    }
 
-    /// SPIR-V binary to be reflected.
+    fn spv_words_or_empty(&self) -> &[u32] {
+        self.spv.as_ref().map(SpvWords::words).unwrap_or_default()
+    }
+    /// Words of the binary set by [`ReflectConfig::spv`] followed by those
+    /// added by [`ReflectConfig::add_spv`]. An empty binary is returned if
+    /// none is given, so that reflection fails as usual.
+    fn all_spv_words(&self) -> Vec<&[u32]> {
+        if self.extra_spvs.is_empty() {
+            vec![self.spv_words_or_empty()]
+        } else {
+            self.spv
+                .iter()
+                .chain(self.extra_spvs.iter())
+                .map(SpvWords::words)
+                .collect()
+        }
+    }
+
+    /// SPIR-V binary to be reflected. The binary is kept after reflection, so
+    /// that a same config can be reflected repeatedly, e.g., with different
+    /// specializations, without copying the words again.
     pub fn spv<Spv: Into<SpirvBinary>>(&mut self, x: Spv) -> &mut Self {
-        self.spv = Some(x.into());
+        self.spv = Some(SpvWords::Owned(x.into()));
+        self
+    }
+    /// Additional SPIR-V binary to be reflected along with the one set by
+    /// [`ReflectConfig::spv`], e.g., the individual stages of a ray-tracing
+    /// pipeline or a shader library. Each binary is reflected independently
+    /// and entry points are returned in the order the binaries are added.
+    /// Only [`ReflectConfig::reflect_module`] refuses the added binaries,
+    /// as module-wide information is per binary.
+    pub fn add_spv<Spv: Into<SpirvBinary>>(&mut self, x: Spv) -> &mut Self {
+        self.extra_spvs.push(SpvWords::Owned(x.into()));
+        self
+    }
+    /// SPIR-V words to be reflected, shared rather than copied. Useful for
+    /// reflecting a same binary with many configs, e.g., different
+    /// specializations in parallel threads; cloning the config only clones
+    /// the `Arc`.
+    pub fn spv_words(&mut self, x: Arc<[u32]>) -> &mut Self {
+        self.spv = Some(SpvWords::Shared(x));
         self
     }
     /// Reference all defined resources even the resource is not used by an
@@ -47,6 +121,27 @@ impl ReflectConfig {
         self.combine_img_samplers = x;
         self
     }
+    /// Split combined image sampler descriptors to sampled image descriptors
+    /// and sampler descriptors, for backends without combined image samplers.
+    /// The sampled image keeps the binding point of the combined image
+    /// sampler, and the sampler is bound to the binding point offset by
+    /// [`ReflectConfig::split_sampler_bind_offset`] in the same descriptor
+    /// set. Splitting is done after [`ReflectConfig::combine_img_samplers`]
+    /// if both are enabled.
+    pub fn split_combined_img_samplers(&mut self, x: bool) -> &mut Self {
+        self.split_combined_img_samplers = x;
+        self
+    }
+    /// Offset added to the bindings of samplers split from combined image
+    /// samplers. By default the offset is zero and samplers alias the
+    /// sampled images at the same binding points, as is consumed by
+    /// [`ReflectConfig::combine_img_samplers`]. Reflection fails if a
+    /// non-zero offset moves a sampler onto a binding point used by another
+    /// descriptor.
+    pub fn split_sampler_bind_offset(&mut self, x: u32) -> &mut Self {
+        self.split_sampler_bind_offset = x;
+        self
+    }
     /// Generate unique names for types and struct fields to help further
     /// processing of the reflection data. Otherwise, the debug names are
     /// assigned.
@@ -54,6 +149,50 @@ impl ReflectConfig {
         self.gen_unique_names = x;
         self
     }
+    /// Report all storage buffers, storage images and storage texel buffers as
+    /// `ReadWrite` regardless of their `NonWritable` and `NonReadable`
+    /// decorations. Useful for conservative synchronization. Otherwise and by
+    /// default, the access types are precisely detected.
+    pub fn conservative_access(&mut self, x: bool) -> &mut Self {
+        self.conservative_access = x;
+        self
+    }
+    /// Narrow the access types of storage buffers, storage images and storage
+    /// texel buffers not decorated `NonWritable` or `NonReadable` to
+    /// `ReadOnly` or `WriteOnly`, if the entry point is only observed to load
+    /// from or only to store to them, including image reads, image writes and
+    /// atomics. Compilers often omit the decorations so everything is
+    /// otherwise reported `ReadWrite`. Ignored if
+    /// [`ReflectConfig::conservative_access`] is set.
+    pub fn infer_access_from_usage(&mut self, x: bool) -> &mut Self {
+        self.infer_access_from_usage = x;
+        self
+    }
+    /// Assume unspecialized specialization constants keep their default values
+    /// when sizes depending on them are queried, e.g.,
+    /// [`EntryPoint::shared_memory_bytes`]. Otherwise and by default, such
+    /// sizes are reported unknown.
+    ///
+    /// [`EntryPoint::shared_memory_bytes`]: crate::entry_point::EntryPoint::shared_memory_bytes
+    pub fn assume_spec_default(&mut self, x: bool) -> &mut Self {
+        self.assume_spec_default = x;
+        self
+    }
+    /// Only reflect entry points of the given name. Other entry points are
+    /// not included in the result, and nothing will be returned if no entry
+    /// point is named so. Entry points of different execution models can
+    /// share a same name, in which case all of them are returned.
+    pub fn entry_point<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        self.entry_point_name = Some(name.into());
+        self
+    }
+    /// Report non-fatal observations made during reflection to `sink`, e.g.,
+    /// skipped instructions and missing layout decorations. Otherwise and by
+    /// default, diagnostics are discarded.
+    pub fn on_diagnostic(&mut self, sink: fn(Diagnostic)) -> &mut Self {
+        self.diagnostic_sink = Some(sink);
+        self
+    }
     /// Use the provided value for specialization constant at `spec_id`.
     pub fn specialize(&mut self, spec_id: SpecId, value: ConstantValue) -> &mut Self {
         self.spec_values.insert(spec_id, value);
@@ -62,22 +201,82 @@ impl ReflectConfig {
 
     /// Reflect the SPIR-V binary and extract all entry points.
     pub fn reflect(&mut self) -> Result<Vec<EntryPoint>> {
-        let spv = self.spv.take().unwrap_or_default();
+        let mut out = Vec::new();
+        for words in self.all_spv_words() {
+            let mut itm = ReflectIntermediate::new(self)?;
+            let inspector = FunctionInspector::new();
+            out.extend(reflect(&mut itm, &mut checked_instrs(words)?, inspector)?);
+        }
+        Ok(out)
+    }
+    /// Reflect all the SPIR-V binaries as stages of a single pipeline, and
+    /// merge the descriptors used by the entry points by their binding
+    /// points, and their push constant ranges by stages. Resources aliasing
+    /// a binding point are all kept, see [`MergedDescriptor`]. An error is
+    /// returned only if the entry points disagree on the number of bindings
+    /// of a binding point.
+    ///
+    /// [`MergedDescriptor`]: crate::module::MergedDescriptor
+    pub fn reflect_merged(&mut self) -> Result<Pipeline> {
+        let entry_points = self.reflect()?;
+        let descriptors = merge_descriptors(&entry_points)?;
+        let push_constant_ranges = merge_push_constant_ranges(&entry_points);
+        Ok(Pipeline {
+            entry_points,
+            descriptors,
+            push_constant_ranges,
+        })
+    }
+    /// Reflect the SPIR-V binary and extract all entry points, each along
+    /// with the reachability of all descriptors in the module from it.
+    /// Useful to find out why a descriptor is pruned when
+    /// [`ReflectConfig::ref_all_rscs`] is not set.
+    pub fn reflect_with_usage(&mut self) -> Result<Vec<(EntryPoint, UsageInfo)>> {
+        let mut out = Vec::new();
+        for words in self.all_spv_words() {
+            let mut itm = ReflectIntermediate::new(self)?;
+            let inspector = FunctionInspector::new();
+            out.extend(reflect_with_usage(
+                &mut itm,
+                &mut checked_instrs(words)?,
+                inspector,
+            )?);
+        }
+        Ok(out)
+    }
+    /// Reflect the SPIR-V binary and extract all entry points along with
+    /// module-wide information. Fails if any binary is added by
+    /// [`ReflectConfig::add_spv`]; reflect them with separate configs
+    /// instead.
+    pub fn reflect_module(&mut self) -> Result<ReflectedModule> {
+        if !self.extra_spvs.is_empty() {
+            return Err(anyhow!(
+                "cannot reflect {} binaries added by add_spv as one module",
+                self.extra_spvs.len()
+            ));
+        }
+        let words = self.spv_words_or_empty();
         let mut itm = ReflectIntermediate::new(self)?;
+        if let Some(version) = words.get(1) {
+            itm.version = ((version >> 16) as u8, (version >> 8) as u8);
+        }
         let inspector = FunctionInspector::new();
-        reflect(&mut itm, &mut spv.instrs()?, inspector)
+        reflect_module(&mut itm, &mut checked_instrs(words)?, inspector)
     }
     /// Reflect the SPIR-V binary and extract all entry points with an inspector
     /// for customized reflection subroutines.
     pub fn reflect_inspect<I: Inspector>(&mut self, inspector: &mut I) -> Result<Vec<EntryPoint>> {
-        let spv = self.spv.take().unwrap_or_default();
-        let mut itm = ReflectIntermediate::new(self)?;
-        let mut func_inspector = FunctionInspector::new();
-        reflect(
-            &mut itm,
-            &mut spv.instrs()?,
-            func_inspector.chain(inspector),
-        )
+        let mut out = Vec::new();
+        for words in self.all_spv_words() {
+            let mut itm = ReflectIntermediate::new(self)?;
+            let mut func_inspector = FunctionInspector::new();
+            out.extend(reflect(
+                &mut itm,
+                &mut checked_instrs(words)?,
+                func_inspector.chain(inspector),
+            )?);
+        }
+        Ok(out)
     }
     /// Reflect the SPIR-V binary and extract all entry points with an inspector
     /// function for customized reflection subroutines.
@@ -88,4 +287,22 @@ impl ReflectConfig {
         let mut inspector = FnInspector::<F>(inspector);
         self.reflect_inspect(&mut inspector)
     }
+    /// Reflect the SPIR-V binary with an inspector function like
+    /// [`ReflectConfig::reflect_inspect_by`], but stop parsing functions once
+    /// the inspector function returns `ControlFlow::Break`. Entry points are
+    /// still returned, but the function being parsed and all the following
+    /// ones are skipped, so resources and execution details only known from
+    /// them may be missing from the result.
+    pub fn reflect_inspect_try_by<
+        F: FnMut(&mut ReflectIntermediate<'_>, &Instr) -> ControlFlow<()>,
+    >(
+        &mut self,
+        inspector: F,
+    ) -> Result<Vec<EntryPoint>> {
+        let mut inspector = TryFnInspector {
+            f: inspector,
+            is_done: false,
+        };
+        self.reflect_inspect(&mut inspector)
+    }
 }
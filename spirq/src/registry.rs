@@ -0,0 +1,36 @@
+//! Lookups in the registries of [`ReflectIntermediate`], e.g., from an
+//! inspector.
+//!
+//! [`ReflectIntermediate`]: crate::reflect::ReflectIntermediate
+use crate::ty::{Type, TypeRegistry};
+
+/// Name-based lookups of types. Names come from `OpName` which is debug info,
+/// so they can be absent when the SPIR-V is stripped, unless
+/// [`ReflectConfig::gen_unique_names`] is set. Only struct types carry names.
+///
+/// [`ReflectConfig::gen_unique_names`]: crate::ReflectConfig::gen_unique_names
+pub trait TypeRegistryExt {
+    /// The type named `name`. If multiple types share a same name, the one of
+    /// the smallest type ID is returned.
+    fn resolve_by_name(&self, name: &str) -> Option<&Type>;
+    /// Named types and their names, ordered by type ID.
+    fn iter_named(&self) -> Box<dyn Iterator<Item = (&str, &Type)> + '_>;
+}
+impl TypeRegistryExt for TypeRegistry {
+    fn resolve_by_name(&self, name: &str) -> Option<&Type> {
+        self.iter_named()
+            .find(|(x, _)| *x == name)
+            .map(|(_, ty)| ty)
+    }
+    fn iter_named(&self) -> Box<dyn Iterator<Item = (&str, &Type)> + '_> {
+        let mut named = self
+            .iter()
+            .filter_map(|(id, ty)| match ty {
+                Type::Struct(struct_ty) => struct_ty.name().map(|name| (*id, name, ty)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        named.sort_by_key(|(id, _, _)| *id);
+        Box::new(named.into_iter().map(|(_, name, ty)| (name, ty)))
+    }
+}
@@ -583,7 +583,7 @@ fn test_linked_list() {
 }
 #[test]
 fn test_issue_84() {
-    let _entry: EntryPoint = gen_one_entry!(
+    let entry: EntryPoint = gen_one_entry!(
         comp,
         r#"
         #version 450
@@ -601,6 +601,20 @@ fn test_issue_84() {
         }
     "#
     );
+    // The matrix layout is decorated on the member of the inner struct `Foo`
+    // rather than on the block member.
+    let ty = entry
+        .vars
+        .iter()
+        .find_map(|x| match x {
+            Variable::Descriptor { ty, .. } => Some(ty),
+            _ => None,
+        })
+        .unwrap();
+    let foo_ty = ty.as_struct().unwrap().members[0].ty.as_struct().unwrap();
+    let mat_ty = foo_ty.members[0].ty.as_matrix().unwrap();
+    assert_eq!(mat_ty.stride, Some(16));
+    assert_eq!(mat_ty.axis_order, Some(ty::MatrixAxisOrder::ColumnMajor));
 }
 #[test]
 fn test_matrix_stride() {
@@ -722,3 +736,5631 @@ fn test_resource_in_chained_call() {
     // Ensure the unreferenced one is not in the map.
     assert_eq!(desc_binds.get(&DescriptorBinding::new(1, 3)), None);
 }
+#[test]
+fn test_mesh_per_primitive_outputs() {
+    let entry = gen_one_entry!(
+        mesh,
+        r#"
+        #version 450
+        #extension GL_EXT_mesh_shader : require
+        layout(local_size_x = 1) in;
+        layout(triangles, max_vertices = 3, max_primitives = 1) out;
+        layout(location = 0) out vec4 v_color[];
+        layout(location = 1) perprimitiveEXT out vec4 p_color[];
+        void main() {
+            SetMeshOutputsEXT(3, 1);
+            v_color[gl_LocalInvocationIndex] = vec4(1.0);
+            p_color[gl_LocalInvocationIndex] = vec4(0.0);
+        }
+    "#
+    );
+    assert!(!entry.is_per_primitive(InterfaceLocation::new(0, 0)));
+    assert!(entry.is_per_primitive(InterfaceLocation::new(1, 0)));
+    let vec4 = Type::Vector(ty::VectorType {
+        scalar_ty: ty::ScalarType::Float { bits: 32 },
+        nscalar: 4,
+    });
+    let base_tys = entry
+        .vars
+        .iter()
+        .filter_map(|x| {
+            if let Variable::Output { location, .. } = x {
+                Some((*location, entry.output_base_ty(x).unwrap()))
+            } else {
+                None
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    assert_eq!(base_tys[&InterfaceLocation::new(0, 0)], (&vec4, Some(3)));
+    assert_eq!(base_tys[&InterfaceLocation::new(1, 0)], (&vec4, Some(1)));
+}
+#[test]
+fn test_relaxed_precision() {
+    let entry = gen_one_entry!(
+        frag,
+        r#"
+        #version 450
+        layout(location = 0) in mediump float a;
+        layout(location = 1) in highp float b;
+        layout(location = 0) out mediump vec4 c;
+        void main() {
+            c = vec4(a, b, 0.0, 1.0);
+        }
+    "#
+    );
+    let relaxed = entry
+        .vars
+        .iter()
+        .filter(|x| entry.is_relaxed_precision(x))
+        .map(Locator::from)
+        .collect::<HashSet<_>>();
+    assert_eq!(relaxed.len(), 2);
+    assert!(relaxed.contains(&Locator::Input(InterfaceLocation::new(0, 0))));
+    assert!(relaxed.contains(&Locator::Output(InterfaceLocation::new(0, 0))));
+}
+#[test]
+fn test_malformed_spv() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        #version 450
+        layout(constant_id = 0) const int a = 1;
+        layout(constant_id = 1) const int b = 0;
+        const int c = a / b;
+        layout(binding = 0) uniform Data { int x[c]; } u;
+        layout(location = 0) out vec4 color;
+        void main() { color = vec4(u.x[0]); }
+    "#,
+        frag,
+        glsl,
+        vulkan1_2
+    );
+    // Empty, headerless and wrong magic number.
+    assert!(ReflectConfig::new().reflect().is_err());
+    assert!(ReflectConfig::new().spv(&SPV[..3]).reflect().is_err());
+    assert!(ReflectConfig::new()
+        .spv(&[0xdeadbeef_u32, 0, 0, 0, 0][..])
+        .reflect()
+        .is_err());
+    // Truncated in the middle of an instruction.
+    assert!(ReflectConfig::new()
+        .spv(&SPV[..SPV.len() - 1])
+        .reflect()
+        .is_err());
+    // Division by zero in specialization constant folding.
+    assert!(ReflectConfig::new().spv(SPV).reflect().is_err());
+    // Corrupted words must never panic.
+    for i in 5..SPV.len() {
+        for x in [0, 0xffff, 0xffffffff] {
+            let mut spv = SPV.to_vec();
+            spv[i] = x;
+            let _ = ReflectConfig::new().spv(spv).ref_all_rscs(true).reflect();
+        }
+    }
+}
+#[test]
+fn test_walk_access_chain() {
+    let entry = gen_one_entry!(
+        vert,
+        r#"
+        #version 450
+        struct Bone {
+            vec4 pos;
+            mat4 transform;
+        };
+        layout(binding = 0) uniform Skeleton {
+            float scale;
+            Bone bones[8];
+        } u;
+        void main() {
+            gl_Position = u.bones[4].transform * u.bones[4].pos * u.scale;
+        }
+    "#
+    );
+    let var = entry.vars.iter().find(|x| x.name() == Some("u")).unwrap();
+    let route = crate::walk::Walk::new(var.ty())
+        .find(|x| format!("{:?}", x.sym) == "[bones, 4, transform]")
+        .unwrap();
+    use crate::walk::AccessChainSegment::*;
+    assert_eq!(route.access_chain, vec![Member(1), Index(4), Member(1)]);
+    assert_eq!(route.offset, 16 + 80 * 4 + 16);
+}
+#[test]
+fn test_exec_model_names() {
+    use std::str::FromStr;
+    let exec_model = ExecutionModel::from_str("Fragment").unwrap();
+    assert_eq!(exec_model, ExecutionModel::Fragment);
+    assert_eq!(
+        ExecutionModel::from_u32(exec_model.to_u32()),
+        Some(exec_model)
+    );
+    assert_eq!(
+        ExecutionModel::from_name("vert"),
+        Some(ExecutionModel::Vertex)
+    );
+    assert_eq!(
+        ExecutionModel::from_name("GLCompute"),
+        Some(ExecutionModel::GLCompute)
+    );
+    assert_eq!(ExecutionModel::from_name("fragment"), None);
+    for x in [
+        ExecutionModel::TessellationEvaluation,
+        ExecutionModel::RayGenerationKHR,
+        ExecutionModel::MeshEXT,
+    ] {
+        assert_eq!(ExecutionModel::from_name(x.name()), Some(x));
+    }
+}
+#[test]
+fn test_array_stride_validation() {
+    use crate::layout::{ArrayTypeExt, LayoutRule};
+    let arr_ty = |element_ty: Type, stride: usize| ty::ArrayType {
+        element_ty: Box::new(element_ty),
+        nelement: Some(4),
+        stride: Some(stride),
+    };
+    let float = Type::Scalar(ty::ScalarType::f32());
+    let vec3 = Type::Vector(ty::VectorType {
+        scalar_ty: ty::ScalarType::f32(),
+        nscalar: 3,
+    });
+    assert!(arr_ty(float.clone(), 4)
+        .validate_stride(LayoutRule::Std430)
+        .is_ok());
+    assert!(arr_ty(float.clone(), 4)
+        .validate_stride(LayoutRule::Std140)
+        .is_err());
+    assert!(arr_ty(float, 16)
+        .validate_stride(LayoutRule::Std140)
+        .is_ok());
+    // Too small for the element.
+    assert!(arr_ty(vec3.clone(), 8)
+        .validate_stride(LayoutRule::Scalar)
+        .is_err());
+    assert!(arr_ty(vec3.clone(), 12)
+        .validate_stride(LayoutRule::Scalar)
+        .is_ok());
+    assert!(arr_ty(vec3, 12)
+        .validate_stride(LayoutRule::Std430)
+        .is_err());
+}
+#[test]
+fn test_binding_arrays() {
+    let entry = gen_one_entry!(
+        frag,
+        r#"
+        #version 450 core
+        #extension GL_EXT_nonuniform_qualifier: enable
+        layout(binding = 0) uniform sampler2D arr_dyn[];
+        layout(binding = 1) uniform sampler2D arr[1];
+        layout(binding = 2) uniform sampler2D tex;
+        layout(binding = 3) uniform Data { float x[4]; } u;
+        layout(location = 0) in flat uint xx;
+        layout(location = 0) out vec4 color;
+        void main() {
+            color = texture(arr[0], vec2(0,0)) + texture(arr_dyn[nonuniformEXT(xx)], vec2(0,0))
+                + texture(tex, vec2(0,0)) + u.x[xx];
+        }
+    "#
+    );
+    let descs = entry
+        .vars
+        .iter()
+        .filter_map(|x| {
+            if let Variable::Descriptor { desc_bind, .. } = x {
+                let bind = desc_bind.bind();
+                Some((
+                    bind,
+                    (entry.is_binding_array(x), entry.is_runtime_binding_array(x)),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    assert_eq!(descs[&0], (true, true));
+    assert_eq!(descs[&1], (true, false));
+    assert_eq!(descs[&2], (false, false));
+    assert_eq!(descs[&3], (false, false));
+}
+#[test]
+fn test_source_files() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        %main_file = OpString "main.comp"
+        %header_file = OpString "common.h"
+        %unused = OpString "not a file name"
+        OpSource GLSL 450 %main_file
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpLine %header_file 3 0
+        OpLine %main_file 10 0
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new().spv(SPV).reflect_module().unwrap();
+    assert_eq!(module.metadata.source_files, vec!["main.comp", "common.h"]);
+    assert_eq!(module.entry_points.len(), 1);
+}
+#[test]
+fn test_entry_point_filter() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %vert "vert" %pos
+        OpEntryPoint Fragment %frag "frag" %color
+        OpExecutionMode %frag OriginUpperLeft
+        OpDecorate %pos BuiltIn Position
+        OpDecorate %color Location 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %one = OpConstant %f32 1
+        %white = OpConstantComposite %v4f32 %one %one %one %one
+        %ptr_out = OpTypePointer Output %v4f32
+        %pos = OpVariable %ptr_out Output
+        %color = OpVariable %ptr_out Output
+        %vert = OpFunction %void None %fn
+        %vert_label = OpLabel
+        OpStore %pos %white
+        OpReturn
+        OpFunctionEnd
+        %frag = OpFunction %void None %fn
+        %frag_label = OpLabel
+        OpStore %color %white
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let entries = ReflectConfig::new()
+        .spv(SPV)
+        .entry_point("frag")
+        .reflect()
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].exec_model, ExecutionModel::Fragment);
+    assert_eq!(entries[0].vars.len(), 1);
+    let entries = ReflectConfig::new()
+        .spv(SPV)
+        .entry_point("main")
+        .reflect()
+        .unwrap();
+    assert!(entries.is_empty());
+}
+#[test]
+fn test_conservative_access() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        #version 450 core
+        layout(set=0, binding=0) readonly buffer X {
+            uint a[];
+        } x;
+        layout(set=0, binding=1, r32f) writeonly uniform image2D img;
+        layout(set=0, binding=2) buffer Y {
+            uint b[];
+        } y;
+        void main() {
+            y.b[0] = x.a[0];
+            imageStore(img, ivec2(0, 0), vec4(0));
+        }
+    "#,
+        comp,
+        glsl,
+        vulkan1_2
+    );
+    let get_desc_tys = |conservative_access: bool| {
+        ReflectConfig::new()
+            .spv(SPV)
+            .conservative_access(conservative_access)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+            .vars
+            .into_iter()
+            .filter_map(|x| {
+                if let Variable::Descriptor {
+                    desc_bind, desc_ty, ..
+                } = x
+                {
+                    Some((desc_bind.bind(), desc_ty))
+                } else {
+                    None
+                }
+            })
+            .collect::<HashMap<_, _>>()
+    };
+    let desc_tys = get_desc_tys(false);
+    assert_eq!(
+        desc_tys[&0],
+        DescriptorType::StorageBuffer(AccessType::ReadOnly)
+    );
+    assert_eq!(
+        desc_tys[&1],
+        DescriptorType::StorageImage(AccessType::WriteOnly)
+    );
+    let desc_tys = get_desc_tys(true);
+    assert_eq!(
+        desc_tys[&0],
+        DescriptorType::StorageBuffer(AccessType::ReadWrite)
+    );
+    assert_eq!(
+        desc_tys[&1],
+        DescriptorType::StorageImage(AccessType::ReadWrite)
+    );
+    assert_eq!(
+        desc_tys[&2],
+        DescriptorType::StorageBuffer(AccessType::ReadWrite)
+    );
+}
+#[test]
+fn test_push_constant_range() {
+    let entry = gen_one_entry_hlsl!(
+        frag,
+        r#"
+        struct PushConsts {
+            [[vk::offset(16)]] float4 color;
+            float scale;
+        };
+        [[vk::push_constant]]
+        PushConsts pc;
+        float4 main() : SV_TARGET { return pc.color * pc.scale; }
+    "#
+    );
+    assert_eq!(entry.push_constant_byte_range(), Some(16..36));
+    let push_const_rng = entry.push_constant_range().unwrap();
+    assert_eq!(push_const_rng.stage_flags, 0x10);
+    assert_eq!(push_const_rng.offset, 16);
+    assert_eq!(push_const_rng.size, 20);
+}
+#[test]
+fn test_spv_from_reader() {
+    let words = [0x07230203_u32, 0x00010000, 0, 1, 0];
+    let le = words
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<u8>>();
+    let be = words
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect::<Vec<u8>>();
+    let spv = SpirvBinary::from_reader(le.as_slice()).unwrap();
+    assert_eq!(spv.words(), &words);
+    let spv = SpirvBinary::from_reader(be.as_slice()).unwrap();
+    assert_eq!(spv.words(), &words);
+    // Misaligned, empty and wrong magic number.
+    assert!(SpirvBinary::from_reader(&le[..le.len() - 1]).is_err());
+    assert!(SpirvBinary::from_reader(&[][..]).is_err());
+    assert!(SpirvBinary::from_reader(&[0xef, 0xbe, 0xad, 0xde][..]).is_err());
+    assert!(SpirvBinary::from_file("nonexistent.spv").is_err());
+}
+#[test]
+fn test_walk_indices() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %U "U"
+        OpMemberName %U 0 "s"
+        OpMemberName %U 1 "a"
+        OpName %A "A"
+        OpMemberName %A 0 "t"
+        OpMemberName %A 1 "b"
+        OpName %u "u"
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %A 0 Offset 0
+        OpMemberDecorate %A 1 Offset 4
+        OpMemberDecorate %U 0 Offset 0
+        OpMemberDecorate %U 1 Offset 16
+        OpDecorate %U Block
+        OpDecorate %u DescriptorSet 0
+        OpDecorate %u Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %four = OpConstant %u32 4
+        %arr = OpTypeArray %f32 %four
+        %A = OpTypeStruct %f32 %arr
+        %U = OpTypeStruct %f32 %A
+        %ptr_u = OpTypePointer StorageBuffer %U
+        %u = OpVariable %ptr_u StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let var = entry.vars.iter().find(|x| x.name() == Some("u")).unwrap();
+    let route = crate::walk::Walk::new(var.ty())
+        .find(|x| format!("{:?}", x.sym) == "[a, b, 2]")
+        .unwrap();
+    assert_eq!(route.indices, vec![1, 1, 2]);
+    assert_eq!(route.offset, 16 + 4 + 4 * 2);
+}
+#[test]
+fn test_shared_memory_bytes() {
+    let entry = gen_one_entry!(
+        comp,
+        r#"
+        #version 450
+        layout(local_size_x = 256) in;
+        shared float data[256];
+        layout(binding = 0) buffer Data { float x[]; } b;
+        void main() {
+            data[gl_LocalInvocationIndex] = b.x[gl_LocalInvocationIndex];
+            barrier();
+            b.x[gl_LocalInvocationIndex] = data[255 - gl_LocalInvocationIndex];
+        }
+    "#
+    );
+    assert_eq!(entry.shared_memory_bytes(), Some(1024));
+
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        #version 450
+        layout(local_size_x = 16) in;
+        layout(constant_id = 0) const int N = 16;
+        shared float data[256];
+        shared vec4 tiles[N];
+        layout(binding = 0) buffer Data { float x[]; } b;
+        void main() {
+            tiles[gl_LocalInvocationIndex] = vec4(b.x[gl_LocalInvocationIndex]);
+            data[gl_LocalInvocationIndex] = tiles[0].x;
+            barrier();
+            b.x[gl_LocalInvocationIndex] = data[0];
+        }
+    "#,
+        comp,
+        glsl,
+        vulkan1_2
+    );
+    let get_shared_memory_bytes =
+        |cfg: &mut ReflectConfig| cfg.spv(SPV).reflect().unwrap()[0].shared_memory_bytes();
+    assert_eq!(get_shared_memory_bytes(&mut ReflectConfig::new()), None);
+    assert_eq!(
+        get_shared_memory_bytes(ReflectConfig::new().assume_spec_default(true)),
+        Some(1024 + 16 * 16)
+    );
+    assert_eq!(
+        get_shared_memory_bytes(ReflectConfig::new().specialize(0, ConstantValue::S32(4))),
+        Some(1024 + 16 * 4)
+    );
+}
+#[test]
+fn test_spv_try_from_bytes() {
+    let words = [0x07230203_u32, 0x00010000, 0, 1, 0];
+    let le = words
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect::<Vec<u8>>();
+    let spv = SpirvBinary::try_from_bytes(&le).unwrap();
+    assert_eq!(spv.words(), &words);
+    // Pseudo-random bytes without a SPIR-V header.
+    let mut seed = 0x12345678_u32;
+    let random = (0..64)
+        .map(|_| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as u8
+        })
+        .collect::<Vec<u8>>();
+    let err = SpirvBinary::try_from_bytes(&random).unwrap_err();
+    assert!(err.to_string().contains("magic number"), "{}", err);
+    let err = SpirvBinary::try_from_bytes(&le[..8]).unwrap_err();
+    assert!(err.to_string().contains("truncated"), "{}", err);
+    let err = SpirvBinary::try_from_bytes(&le[..7]).unwrap_err();
+    assert!(err.to_string().contains("align"), "{}", err);
+}
+#[test]
+fn test_module_descriptors() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %vert "vert"
+        OpEntryPoint Fragment %frag "frag"
+        OpExecutionMode %frag OriginUpperLeft
+        OpMemberDecorate %U 0 Offset 0
+        OpDecorate %U Block
+        OpDecorate %u DescriptorSet 0
+        OpDecorate %u Binding 0
+        OpDecorate %img DescriptorSet 0
+        OpDecorate %img Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %U = OpTypeStruct %v4f32
+        %ptr_u = OpTypePointer Uniform %U
+        %ptr_v4f32 = OpTypePointer Uniform %v4f32
+        %u = OpVariable %ptr_u Uniform
+        %img_ty = OpTypeImage %f32 2D 0 0 0 1 Unknown
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %img = OpVariable %ptr_img UniformConstant
+        %i32 = OpTypeInt 32 1
+        %zero = OpConstant %i32 0
+        %vert = OpFunction %void None %fn
+        %vert_label = OpLabel
+        %vert_ptr = OpAccessChain %ptr_v4f32 %u %zero
+        %vert_value = OpLoad %v4f32 %vert_ptr
+        OpReturn
+        OpFunctionEnd
+        %frag = OpFunction %void None %fn
+        %frag_label = OpLabel
+        %frag_ptr = OpAccessChain %ptr_v4f32 %u %zero
+        %frag_value = OpLoad %v4f32 %frag_ptr
+        %frag_img = OpLoad %img_ty %img
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new().spv(SPV).reflect_module().unwrap();
+    let descs = module.descriptors().unwrap();
+    assert_eq!(descs.len(), 2);
+    assert_eq!(descs[0].desc_bind, DescriptorBinding::new(0, 0));
+    assert_eq!(descs[0].desc_tys, vec![DescriptorType::UniformBuffer()]);
+    assert_eq!(descs[0].stage_flags(), 0x1 | 0x10);
+    assert_eq!(descs[1].desc_bind, DescriptorBinding::new(0, 1));
+    assert_eq!(descs[1].desc_tys, vec![DescriptorType::SampledImage()]);
+    assert_eq!(descs[1].exec_models, vec![ExecutionModel::Fragment]);
+}
+#[test]
+fn test_module_descriptors_aliased() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %A Block
+        OpMemberDecorate %A 0 Offset 0
+        OpDecorate %B Block
+        OpMemberDecorate %B 0 Offset 0
+        OpMemberDecorate %B 1 Offset 16
+        OpDecorate %a DescriptorSet 0
+        OpDecorate %a Binding 0
+        OpDecorate %b DescriptorSet 0
+        OpDecorate %b Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %u32 = OpTypeInt 32 0
+        %zero = OpConstant %u32 0
+        %A = OpTypeStruct %v4f32
+        %B = OpTypeStruct %u32 %v4f32
+        %ptr_A = OpTypePointer StorageBuffer %A
+        %ptr_B = OpTypePointer StorageBuffer %B
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %a = OpVariable %ptr_A StorageBuffer
+        %b = OpVariable %ptr_B StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %count_ptr = OpAccessChain %ptr_u32 %b %zero
+        %count = OpLoad %u32 %count_ptr
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect_module()
+        .unwrap();
+    let descs = module.descriptors().unwrap();
+    assert_eq!(descs.len(), 1);
+    assert_eq!(descs[0].desc_bind, DescriptorBinding::new(0, 0));
+    assert_eq!(descs[0].tys.len(), 2);
+}
+#[test]
+fn test_module_descriptors_aliased_image() {
+    // HLSL `Texture2D` and `SamplerState` declared at a same binding, and
+    // sampled and storage views of one image at another.
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %tex DescriptorSet 0
+        OpDecorate %tex Binding 0
+        OpDecorate %smp DescriptorSet 0
+        OpDecorate %smp Binding 0
+        OpDecorate %sampled DescriptorSet 0
+        OpDecorate %sampled Binding 1
+        OpDecorate %storage DescriptorSet 0
+        OpDecorate %storage Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %tex_ty = OpTypeImage %f32 2D 0 0 0 1 Unknown
+        %smp_ty = OpTypeSampler
+        %storage_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba32f
+        %ptr_tex = OpTypePointer UniformConstant %tex_ty
+        %ptr_smp = OpTypePointer UniformConstant %smp_ty
+        %ptr_storage = OpTypePointer UniformConstant %storage_ty
+        %tex = OpVariable %ptr_tex UniformConstant
+        %smp = OpVariable %ptr_smp UniformConstant
+        %sampled = OpVariable %ptr_tex UniformConstant
+        %storage = OpVariable %ptr_storage UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect_module()
+        .unwrap();
+    let descs = module.descriptors().unwrap();
+    assert_eq!(descs.len(), 2);
+    assert_eq!(
+        descs[0].desc_tys,
+        vec![DescriptorType::SampledImage(), DescriptorType::Sampler()]
+    );
+    assert_eq!(descs[0].tys.len(), 2);
+    assert_eq!(descs[1].desc_tys.len(), 2);
+    assert_eq!(descs[1].desc_tys[0], DescriptorType::SampledImage());
+    assert!(matches!(
+        descs[1].desc_tys[1],
+        DescriptorType::StorageImage(_)
+    ));
+}
+#[test]
+fn test_reflect_merged_renamed_block() {
+    static VERT: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main"
+        OpName %U "VertUniforms"
+        OpMemberName %U 0 "color"
+        OpDecorate %U Block
+        OpMemberDecorate %U 0 Offset 0
+        OpDecorate %u DescriptorSet 0
+        OpDecorate %u Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %U = OpTypeStruct %v4f32
+        %ptr_U = OpTypePointer Uniform %U
+        %u = OpVariable %ptr_U Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    static FRAG: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main"
+        OpExecutionMode %main OriginUpperLeft
+        OpName %U "FragUniforms"
+        OpMemberName %U 0 "tint"
+        OpDecorate %U Block
+        OpMemberDecorate %U 0 Offset 0
+        OpDecorate %u DescriptorSet 0
+        OpDecorate %u Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %U = OpTypeStruct %v4f32
+        %ptr_U = OpTypePointer Uniform %U
+        %u = OpVariable %ptr_U Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let pipeline = ReflectConfig::new()
+        .spv(VERT)
+        .add_spv(FRAG)
+        .ref_all_rscs(true)
+        .reflect_merged()
+        .unwrap();
+    assert_eq!(pipeline.descriptors.len(), 1);
+    let desc = &pipeline.descriptors[0];
+    assert_eq!(desc.stage_flags(), 0x1 | 0x10);
+    // Blocks of the same layout are the same resource whatever their names.
+    assert_eq!(desc.tys.len(), 1);
+    assert_eq!(
+        desc.tys[0].as_struct().unwrap().name.as_deref(),
+        Some("VertUniforms")
+    );
+}
+#[test]
+fn test_user_semantics() {
+    // Generated by DXC with `-fspv-reflect` from:
+    //
+    // float4 main(float4 pos : POSITION) : SV_Target0 { return pos; }
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpExtension "SPV_GOOGLE_hlsl_functionality1"
+        OpExtension "SPV_GOOGLE_user_type"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %in_var_POSITION %out_var_SV_Target0
+        OpExecutionMode %main OriginUpperLeft
+        OpName %in_var_POSITION "in.var.POSITION"
+        OpName %out_var_SV_Target0 "out.var.SV_Target0"
+        OpDecorateString %in_var_POSITION UserSemantic "POSITION"
+        OpDecorateString %out_var_SV_Target0 UserSemantic "SV_Target0"
+        OpDecorate %in_var_POSITION Location 0
+        OpDecorate %out_var_SV_Target0 Location 0
+        %float = OpTypeFloat 32
+        %v4float = OpTypeVector %float 4
+        %_ptr_Input_v4float = OpTypePointer Input %v4float
+        %_ptr_Output_v4float = OpTypePointer Output %v4float
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %in_var_POSITION = OpVariable %_ptr_Input_v4float Input
+        %out_var_SV_Target0 = OpVariable %_ptr_Output_v4float Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %pos = OpLoad %v4float %in_var_POSITION
+        OpStore %out_var_SV_Target0 %pos
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let input = entry
+        .vars
+        .iter()
+        .find(|x| matches!(x, Variable::Input { .. }))
+        .unwrap();
+    let output = entry
+        .vars
+        .iter()
+        .find(|x| matches!(x, Variable::Output { .. }))
+        .unwrap();
+    assert_eq!(entry.semantic(input), Some("POSITION"));
+    assert_eq!(entry.semantic(output), Some("SV_Target0"));
+
+    // String decorations unknown to `spirv` are ignored. Insert
+    // `OpDecorateString %in_var_POSITION 0xfffe "x"` before the first string
+    // decoration.
+    let mut spv = SPV.to_vec();
+    let mut i = 5;
+    while spv[i] & 0xffff != crate::spirv::Op::DecorateString as u32 {
+        i += (spv[i] >> 16) as usize;
+    }
+    let target_id = spv[i + 1];
+    spv.splice(
+        i..i,
+        [
+            (4 << 16) | crate::spirv::Op::DecorateString as u32,
+            target_id,
+            0xfffe,
+            u32::from_le_bytes(*b"x\0\0\0"),
+        ],
+    );
+    let entry = ReflectConfig::new()
+        .spv(spv)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let input = entry
+        .vars
+        .iter()
+        .find(|x| matches!(x, Variable::Input { .. }))
+        .unwrap();
+    assert_eq!(entry.semantic(input), Some("POSITION"));
+}
+#[test]
+fn test_image_access_qualifier() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %rw_img DescriptorSet 0
+        OpDecorate %rw_img Binding 0
+        OpDecorate %rw_img NonWritable
+        OpDecorate %ro_imgs DescriptorSet 0
+        OpDecorate %ro_imgs Binding 1
+        OpDecorate %deco_img DescriptorSet 0
+        OpDecorate %deco_img Binding 2
+        OpDecorate %deco_img NonReadable
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %four = OpConstant %u32 4
+        %rw_img_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba32f ReadWrite
+        %ro_img_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba32f ReadOnly
+        %img_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba32f
+        %ro_imgs_ty = OpTypeArray %ro_img_ty %four
+        %ptr_rw_img = OpTypePointer UniformConstant %rw_img_ty
+        %ptr_ro_imgs = OpTypePointer UniformConstant %ro_imgs_ty
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %rw_img = OpVariable %ptr_rw_img UniformConstant
+        %ro_imgs = OpVariable %ptr_ro_imgs UniformConstant
+        %deco_img = OpVariable %ptr_img UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let desc_tys = entry
+        .vars
+        .iter()
+        .filter_map(|x| {
+            if let Variable::Descriptor {
+                desc_bind, desc_ty, ..
+            } = x
+            {
+                Some((desc_bind.bind(), desc_ty.clone()))
+            } else {
+                None
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    // The access qualifier takes precedence over decorations.
+    assert_eq!(
+        desc_tys[&0],
+        DescriptorType::StorageImage(AccessType::ReadWrite)
+    );
+    assert_eq!(
+        desc_tys[&1],
+        DescriptorType::StorageImage(AccessType::ReadOnly)
+    );
+    assert_eq!(
+        desc_tys[&2],
+        DescriptorType::StorageImage(AccessType::WriteOnly)
+    );
+}
+#[test]
+fn test_spec_array_len_64bit() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability Int64
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %n SpecId 0
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u64 = OpTypeInt 64 0
+        ; 64-bit literals are given in two words, low-order word first.
+        %n = OpSpecConstant %u64 4 0
+        %arr = OpTypeArray %f32 %n
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let get_nelement = |cfg: &mut ReflectConfig| {
+        let entry = cfg
+            .spv(SPV)
+            .ref_all_rscs(true)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap();
+        let var = entry
+            .vars
+            .iter()
+            .find(|x| matches!(x, Variable::Descriptor { .. }))
+            .unwrap();
+        match var.ty() {
+            Type::Struct(struct_ty) => match &struct_ty.members[0].ty {
+                Type::Array(arr_ty) => arr_ty.nelement,
+                _ => None,
+            },
+            _ => None,
+        }
+    };
+    assert_eq!(get_nelement(&mut ReflectConfig::new()), Some(4));
+    assert_eq!(
+        get_nelement(ReflectConfig::new().specialize(0, ConstantValue::U64(8))),
+        Some(8)
+    );
+    assert_eq!(
+        get_nelement(
+            ReflectConfig::new().specialize(0, ConstantValue::from(&16_u64.to_le_bytes()[..]))
+        ),
+        Some(16)
+    );
+}
+#[test]
+fn test_array_len_from_constants() {
+    let entry = gen_one_entry!(
+        comp,
+        r#"
+        #version 450
+        const int N = 6;
+        const int M = N * 2;
+        layout(binding = 0) buffer Data { float x[M]; } u;
+        void main() { u.x[0] = 1.0; }
+    "#
+    );
+    let get_nelement = |entry: &EntryPoint| {
+        let var = entry
+            .vars
+            .iter()
+            .find(|x| matches!(x, Variable::Descriptor { .. }))
+            .unwrap();
+        match var.ty() {
+            Type::Struct(struct_ty) => match &struct_ty.members[0].ty {
+                Type::Array(arr_ty) => arr_ty.nelement,
+                _ => None,
+            },
+            _ => None,
+        }
+    };
+    assert_eq!(get_nelement(&entry), Some(12));
+
+    // `float x[gl_WorkGroupSize.x]`.
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpDecorate %x SpecId 0
+        OpDecorate %wgsize BuiltIn WorkgroupSize
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %v3u32 = OpTypeVector %u32 3
+        %x = OpSpecConstant %u32 8
+        %one = OpConstant %u32 1
+        %wgsize = OpSpecConstantComposite %v3u32 %x %one %one
+        ; CompositeExtract.
+        %len = OpSpecConstantOp %u32 81 %wgsize 0
+        %arr = OpTypeArray %f32 %len
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .specialize(0, ConstantValue::U32(32))
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(get_nelement(&entry), Some(32));
+}
+#[test]
+fn test_diagnostics() {
+    use crate::diagnostic::Diagnostic;
+    use std::sync::Mutex;
+    static DIAGS: Mutex<Vec<Diagnostic>> = Mutex::new(Vec::new());
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpMemberDecorate %Data 0 Offset 0
+        OpMemberDecorate %Data 0 ColMajor
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %m4f32 = OpTypeMatrix %v4f32 4
+        %Data = OpTypeStruct %m4f32
+        %ptr_data = OpTypePointer Uniform %Data
+        %data = OpVariable %ptr_data Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    ReflectConfig::new()
+        .spv(SPV)
+        .on_diagnostic(|diag| DIAGS.lock().unwrap().push(diag))
+        .reflect()
+        .unwrap();
+    let diags = DIAGS.lock().unwrap();
+    assert!(
+        diags
+            .iter()
+            .any(|x| matches!(x, Diagnostic::MissingMatrixStride { member_idx: 0, .. })),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn test_spec_const_op_local_size() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionModeId %main LocalSizeId %x %one %one
+        OpDecorate %a SpecId 0
+        OpDecorate %b SpecId 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %a = OpSpecConstant %u32 4
+        %b = OpSpecConstant %u32 8
+        %one = OpConstant %u32 1
+        ; 132 is the opcode of OpIMul.
+        %x = OpSpecConstantOp %u32 132 %a %b
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let get_local_size_x = |cfg: &mut ReflectConfig| {
+        let entry = cfg.spv(SPV).reflect().unwrap().pop().unwrap();
+        let exec_mode = entry
+            .exec_modes
+            .iter()
+            .find(|x| x.exec_mode == crate::spirv::ExecutionMode::LocalSizeId)
+            .unwrap();
+        exec_mode.operands[0].value.clone()
+    };
+    assert_eq!(
+        get_local_size_x(&mut ReflectConfig::new()),
+        ConstantValue::U32(32)
+    );
+    assert_eq!(
+        get_local_size_x(
+            ReflectConfig::new()
+                .specialize(0, ConstantValue::U32(2))
+                .specialize(1, ConstantValue::U32(3))
+        ),
+        ConstantValue::U32(6)
+    );
+}
+#[test]
+fn test_is_depth_image() {
+    let entry = gen_one_entry!(
+        frag,
+        r#"
+        #version 450 core
+        layout(binding=0) uniform sampler2DShadow shadow_map;
+        layout(binding=1) uniform sampler2D albedo;
+        layout(binding=2) uniform Data { vec4 x; } data;
+        layout(location=0) out vec4 color;
+        void main() {
+            color = texture(albedo, vec2(0)) * texture(shadow_map, vec3(0)) + data.x;
+        }
+    "#
+    );
+    let is_depth_image = |binding: u32| {
+        entry
+            .vars
+            .iter()
+            .find(|x| match x {
+                Variable::Descriptor { desc_bind, .. } => desc_bind.bind() == binding,
+                _ => false,
+            })
+            .unwrap()
+            .is_depth_image()
+    };
+    assert_eq!(is_depth_image(0), Some(true));
+    assert_eq!(is_depth_image(1), Some(false));
+    assert_eq!(is_depth_image(2), None);
+}
+#[test]
+fn test_module_info() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability RayTracingKHR
+        OpExtension "SPV_KHR_ray_tracing"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint RayGenerationKHR %main "main"
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        rgen,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new().spv(SPV).reflect_module().unwrap();
+    // Vulkan 1.2 targets SPIR-V 1.5.
+    assert_eq!(module.info.version, (1, 5));
+    assert_eq!(
+        module.info.capabilities,
+        vec![crate::spirv::Capability::RayTracingKHR]
+    );
+    assert_eq!(module.info.extensions, vec!["SPV_KHR_ray_tracing"]);
+    assert_eq!(
+        module.info.addressing_model,
+        crate::spirv::AddressingModel::Logical
+    );
+    assert_eq!(module.info.memory_model, crate::spirv::MemoryModel::GLSL450);
+    assert!(module.info.unknown_capabilities.is_empty());
+
+    // Capabilities from SPIR-V newer than the `spirv` crate don't fail
+    // reflection. Insert `OpCapability 0xfffe` right after the header.
+    let mut spv = SPV.to_vec();
+    spv.splice(
+        5..5,
+        [(2 << 16) | crate::spirv::Op::Capability as u32, 0xfffe],
+    );
+    let module = ReflectConfig::new().spv(spv).reflect_module().unwrap();
+    assert_eq!(
+        module.info.capabilities,
+        vec![crate::spirv::Capability::RayTracingKHR]
+    );
+    assert_eq!(module.info.unknown_capabilities, vec![0xfffe]);
+    assert_eq!(module.entry_points.len(), 1);
+}
+#[test]
+fn test_workgroup_size_spec_composite() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %x SpecId 0
+        OpDecorate %y SpecId 1
+        OpDecorate %z SpecId 2
+        OpDecorate %wgsize BuiltIn WorkgroupSize
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %v3u32 = OpTypeVector %u32 3
+        %x = OpSpecConstant %u32 8
+        %y = OpSpecConstant %u32 4
+        %z = OpSpecConstant %u32 2
+        %wgsize = OpSpecConstantComposite %v3u32 %x %y %z
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let get_local_size = |cfg: &mut ReflectConfig| {
+        let entry = cfg.spv(SPV).reflect().unwrap().pop().unwrap();
+        entry
+            .local_size()
+            .unwrap()
+            .iter()
+            .map(|x| (x.spec_id, x.value.clone()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        get_local_size(&mut ReflectConfig::new()),
+        vec![
+            (Some(0), ConstantValue::U32(8)),
+            (Some(1), ConstantValue::U32(4)),
+            (Some(2), ConstantValue::U32(2)),
+        ]
+    );
+    assert_eq!(
+        get_local_size(ReflectConfig::new().specialize(1, ConstantValue::U32(16))),
+        vec![
+            (Some(0), ConstantValue::U32(8)),
+            (None, ConstantValue::U32(16)),
+            (Some(2), ConstantValue::U32(2)),
+        ]
+    );
+}
+#[test]
+fn test_instr_normalized_eq() {
+    use crate::instruction::InstrExt;
+    use crate::parse::Instruction;
+    use crate::spirv::Op;
+    let build_int = |ty_id: u32, signedness: u32| {
+        Instruction::builder(Op::TypeInt)
+            .push(ty_id)
+            .push(32)
+            .push(signedness)
+            .build()
+    };
+    let a = build_int(1, 0);
+    let b = build_int(1, 0);
+    let c = build_int(1, 1);
+    assert!(a.normalized_eq(&b));
+    assert!(!a.normalized_eq(&c));
+    let instrs = [&a, &b, &c]
+        .iter()
+        .map(|x| x.normalized())
+        .collect::<HashSet<_>>();
+    assert_eq!(instrs.len(), 2);
+}
+#[test]
+fn test_non_semantic_ext_instr() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpExtension "SPV_KHR_non_semantic_info"
+        %printf = OpExtInstImport "NonSemantic.DebugPrintf"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        %fmt = OpString "hello"
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %global_print = OpExtInst %void %printf 1 %fmt
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %print = OpExtInst %void %printf 1 %fmt
+        OpReturn
+        OpFunctionEnd
+        %trailing_print = OpExtInst %void %printf 1 %fmt
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.name, "main");
+    assert!(entry
+        .vars
+        .iter()
+        .any(|x| matches!(x, Variable::Descriptor { .. })));
+}
+#[test]
+fn test_descriptors_by_set() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %a DescriptorSet 2
+        OpDecorate %a Binding 3
+        OpDecorate %b DescriptorSet 0
+        OpDecorate %b Binding 1
+        OpDecorate %c DescriptorSet 2
+        OpDecorate %c Binding 0
+        OpDecorate %d DescriptorSet 0
+        OpDecorate %d Binding 0
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %a = OpVariable %ptr_data StorageBuffer
+        %b = OpVariable %ptr_data StorageBuffer
+        %c = OpVariable %ptr_data StorageBuffer
+        %d = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.descriptors().count(), 4);
+    let binds = entry
+        .descriptors_by_set()
+        .into_iter()
+        .map(|(set, descs)| {
+            let binds = descs
+                .into_iter()
+                .map(|x| match x {
+                    Variable::Descriptor { desc_bind, .. } => desc_bind.bind(),
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>();
+            (set, binds)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(binds, vec![(0, vec![0, 1]), (2, vec![0, 3])]);
+}
+#[test]
+fn test_validate_builtin_usage() {
+    use crate::spirv::{BuiltIn, StorageClass};
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main" %frag_coord %per_vertex
+        OpDecorate %frag_coord BuiltIn FragCoord
+        OpMemberDecorate %PerVertex 0 BuiltIn Position
+        OpMemberDecorate %PerVertex 1 BuiltIn PointSize
+        OpDecorate %PerVertex Block
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %i32 = OpTypeInt 32 1
+        %zero = OpConstant %i32 0
+        %PerVertex = OpTypeStruct %v4f32 %f32
+        %ptr_in_v4f32 = OpTypePointer Input %v4f32
+        %ptr_out_v4f32 = OpTypePointer Output %v4f32
+        %ptr_out_per_vertex = OpTypePointer Output %PerVertex
+        %frag_coord = OpVariable %ptr_in_v4f32 Input
+        %per_vertex = OpVariable %ptr_out_per_vertex Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %value = OpLoad %v4f32 %frag_coord
+        %position = OpAccessChain %ptr_out_v4f32 %per_vertex %zero
+        OpStore %position %value
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(
+        entry.info.builtins,
+        vec![
+            (StorageClass::Input, BuiltIn::FragCoord),
+            (StorageClass::Output, BuiltIn::Position),
+            (StorageClass::Output, BuiltIn::PointSize),
+        ]
+    );
+    let issues = entry.validate_builtin_usage();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].builtin, BuiltIn::FragCoord);
+    assert_eq!(issues[0].store_cls, StorageClass::Input);
+    assert_eq!(issues[0].exec_model, ExecutionModel::Vertex);
+}
+#[test]
+fn test_spv_words_shared() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %n SpecId 0
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %n = OpSpecConstant %u32 4
+        %arr = OpTypeArray %u32 %n
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let words: std::sync::Arc<[u32]> = SPV.into();
+    let mut cfg = ReflectConfig::new();
+    cfg.spv_words(words.clone())
+        .ref_all_rscs(true)
+        .specialize(0, ConstantValue::U32(8));
+    let threads = (0..2)
+        .map(|_| {
+            let mut cfg = cfg.clone();
+            std::thread::spawn(move || cfg.reflect().unwrap())
+        })
+        .collect::<Vec<_>>();
+    // The configs share the words with the caller.
+    assert!(std::sync::Arc::strong_count(&words) >= 2);
+    let results = threads
+        .into_iter()
+        .map(|x| x.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(results[0], results[1]);
+    assert_eq!(results[0], cfg.reflect().unwrap());
+    // Only the config kept for later reflection still shares the words.
+    assert_eq!(std::sync::Arc::strong_count(&words), 2);
+}
+#[test]
+fn test_cooperative_matrix() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability VulkanMemoryModel
+        OpCapability CooperativeMatrixKHR
+        OpExtension "SPV_KHR_cooperative_matrix"
+        OpMemoryModel Logical Vulkan
+        OpEntryPoint GLCompute %main "main" %data
+        OpExecutionMode %main LocalSize 32 1 1
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %subgroup = OpConstant %u32 3
+        %sixteen = OpConstant %u32 16
+        %zero = OpConstant %u32 0
+        %use_a = OpConstant %u32 0
+        %mat = OpTypeCooperativeMatrixKHR %f32 %subgroup %sixteen %sixteen %use_a
+        %ptr_mat = OpTypePointer Function %mat
+        %arr = OpTypeRuntimeArray %f32
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %ptr_f32 = OpTypePointer StorageBuffer %f32
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %m = OpVariable %ptr_mat Function
+        %p = OpAccessChain %ptr_f32 %data %zero %zero
+        %loaded = OpCooperativeMatrixLoadKHR %mat %p %zero %sixteen
+        OpStore %m %loaded
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    // Cooperative matrices have no type representation and are skipped, but
+    // the buffer they are loaded from is still reflected.
+    let descs = entry.descriptors().collect::<Vec<_>>();
+    assert_eq!(descs.len(), 1);
+    assert!(matches!(
+        descs[0],
+        Variable::Descriptor {
+            desc_ty: DescriptorType::StorageBuffer(_),
+            ..
+        }
+    ));
+}
+#[test]
+fn test_type_layout_helpers() {
+    use crate::layout::{LayoutRule, TypeLayoutExt};
+    let float = Type::Scalar(ty::ScalarType::f32());
+    let vec4 = Type::Vector(ty::VectorType {
+        scalar_ty: ty::ScalarType::f32(),
+        nscalar: 4,
+    });
+    let arr = vec4.array_of(3, LayoutRule::Std430).unwrap();
+    let arr_ty = arr.as_array().unwrap();
+    assert_eq!(arr_ty.nelement, Some(3));
+    assert_eq!(arr_ty.stride, Some(16));
+    // Scalar arrays are padded to 16 bytes only in std140.
+    let stride_of = |rule| float.array_of(2, rule).unwrap().as_array().unwrap().stride;
+    assert_eq!(stride_of(LayoutRule::Std140), Some(16));
+    assert_eq!(stride_of(LayoutRule::Std430), Some(4));
+
+    let struct_ty = |offsets: [Option<usize>; 2], arr: Type| {
+        let member = |name: &str, offset, ty| ty::StructMember {
+            name: Some(name.to_owned()),
+            offset,
+            ty,
+            access_ty: AccessType::ReadWrite,
+        };
+        Type::Struct(ty::StructType {
+            name: None,
+            members: vec![
+                member("a", offsets[0], float.clone()),
+                member("b", offsets[1], arr),
+            ],
+        })
+    };
+    let unlaid = struct_ty([None, None], vec4.array_of(3, LayoutRule::Scalar).unwrap());
+    let laid = struct_ty([Some(0), Some(16)], arr.clone());
+    assert_eq!(unlaid.with_offsets_from(&laid).unwrap(), laid);
+    let other = struct_ty(
+        [Some(0), Some(16)],
+        float.array_of(3, LayoutRule::Std430).unwrap(),
+    );
+    assert!(unlaid.with_offsets_from(&other).is_err());
+}
+#[test]
+fn test_vertex_input_attributes() {
+    use crate::interop::vulkan::{vertex_input_attributes, VertexAttr};
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability Float64
+        OpCapability Float16
+        OpCapability StorageInputOutput16
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main" %pos %idx %half %dbl %mat %vert_idx
+        OpDecorate %pos Location 0
+        OpDecorate %idx Location 1
+        OpDecorate %half Location 2
+        OpDecorate %dbl Location 3
+        OpDecorate %mat Location 5
+        OpDecorate %vert_idx BuiltIn VertexIndex
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f16 = OpTypeFloat 16
+        %f32 = OpTypeFloat 32
+        %f64 = OpTypeFloat 64
+        %i32 = OpTypeInt 32 1
+        %v3f32 = OpTypeVector %f32 3
+        %v2i32 = OpTypeVector %i32 2
+        %v4f16 = OpTypeVector %f16 4
+        %v4f64 = OpTypeVector %f64 4
+        %v2f32 = OpTypeVector %f32 2
+        %m2f32 = OpTypeMatrix %v2f32 2
+        %ptr_v3f32 = OpTypePointer Input %v3f32
+        %ptr_v2i32 = OpTypePointer Input %v2i32
+        %ptr_v4f16 = OpTypePointer Input %v4f16
+        %ptr_v4f64 = OpTypePointer Input %v4f64
+        %ptr_m2f32 = OpTypePointer Input %m2f32
+        %ptr_i32 = OpTypePointer Input %i32
+        %pos = OpVariable %ptr_v3f32 Input
+        %idx = OpVariable %ptr_v2i32 Input
+        %half = OpVariable %ptr_v4f16 Input
+        %dbl = OpVariable %ptr_v4f64 Input
+        %mat = OpVariable %ptr_m2f32 Input
+        %vert_idx = OpVariable %ptr_i32 Input
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let attr = |location, format, byte_size| VertexAttr {
+        location,
+        component: 0,
+        format,
+        byte_size,
+    };
+    assert_eq!(
+        vertex_input_attributes(&entry),
+        vec![
+            // VK_FORMAT_R32G32B32_SFLOAT
+            attr(0, 106, 12),
+            // VK_FORMAT_R32G32_SINT
+            attr(1, 102, 8),
+            // VK_FORMAT_R16G16B16A16_SFLOAT
+            attr(2, 97, 8),
+            // VK_FORMAT_R64G64B64A64_SFLOAT
+            attr(3, 121, 32),
+            // VK_FORMAT_R32G32_SFLOAT for each column.
+            attr(5, 103, 8),
+            attr(6, 103, 8),
+        ]
+    );
+}
+#[test]
+fn test_instrs_filter_op() {
+    use crate::instruction::InstrsExt;
+    use crate::spirv::{Decoration, Op};
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let spv = SpirvBinary::from(SPV);
+    let decos = spv
+        .instrs()
+        .unwrap()
+        .filter_op(Op::Decorate)
+        .map(|x| x.as_ref()[2])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        decos,
+        vec![
+            Decoration::Block as u32,
+            Decoration::DescriptorSet as u32,
+            Decoration::Binding as u32,
+        ]
+    );
+    let entry_point = spv.instrs().unwrap().find_first(Op::EntryPoint).unwrap();
+    assert_eq!(entry_point.op(), Op::EntryPoint);
+    assert!(spv.instrs().unwrap().find_first(Op::TypeFloat).is_none());
+}
+#[test]
+fn test_subgroup_requirements() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability GroupNonUniform
+        OpExtension "SPV_KHR_subgroup_uniform_control_flow"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %lane
+        OpExecutionMode %main LocalSize 32 1 1
+        OpExecutionMode %main SubgroupUniformControlFlowKHR
+        OpDecorate %lane BuiltIn SubgroupLocalInvocationId
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %ptr_u32 = OpTypePointer Input %u32
+        %lane = OpVariable %ptr_u32 Input
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %u32 %lane
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let subgroup_reqs = entry.subgroup_requirements();
+    assert!(subgroup_reqs.uniform_control_flow);
+    assert!(subgroup_reqs.uses_subgroup_builtins);
+    assert_eq!(subgroup_reqs.subgroup_size, None);
+    assert_eq!(subgroup_reqs.subgroups_per_workgroup, None);
+}
+#[test]
+fn test_descriptor_accessed_via_ptr_arg() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability VariablePointersStorageBuffer
+        OpExtension "SPV_KHR_variable_pointers"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %data %unused
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        OpDecorate %unused DescriptorSet 0
+        OpDecorate %unused Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %zero = OpConstant %u32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %fn_load = OpTypeFunction %u32 %ptr_u32
+        %fn_fwd = OpTypeFunction %u32 %ptr_u32
+        %data = OpVariable %ptr_data StorageBuffer
+        %unused = OpVariable %ptr_data StorageBuffer
+        %load = OpFunction %u32 None %fn_load
+        %p = OpFunctionParameter %ptr_u32
+        %load_label = OpLabel
+        %x = OpLoad %u32 %p
+        OpReturnValue %x
+        OpFunctionEnd
+        %fwd = OpFunction %u32 None %fn_fwd
+        %q = OpFunctionParameter %ptr_u32
+        %fwd_label = OpLabel
+        %y = OpFunctionCall %u32 %load %q
+        OpReturnValue %y
+        OpFunctionEnd
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %ptr = OpAccessChain %ptr_u32 %data %zero
+        %z = OpFunctionCall %u32 %fwd %ptr
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let desc_binds = entry
+        .descriptors()
+        .filter_map(|x| match x {
+            Variable::Descriptor { desc_bind, .. } => Some(*desc_bind),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(desc_binds, vec![DescriptorBinding::new(0, 0)]);
+}
+#[test]
+fn test_input_attachments() {
+    use crate::descriptor::DescriptorVariableExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability InputAttachment
+        OpCapability SampleRateShading
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %color %depth %out
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %color DescriptorSet 0
+        OpDecorate %color Binding 0
+        OpDecorate %color InputAttachmentIndex 0
+        OpDecorate %depth DescriptorSet 0
+        OpDecorate %depth Binding 1
+        OpDecorate %depth InputAttachmentIndex 1
+        OpDecorate %out Location 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %i32 = OpTypeInt 32 1
+        %v2i32 = OpTypeVector %i32 2
+        %v4f32 = OpTypeVector %f32 4
+        %zero = OpConstant %i32 0
+        %coord = OpConstantComposite %v2i32 %zero %zero
+        %subpass = OpTypeImage %f32 SubpassData 0 0 0 2 Unknown
+        %subpass_ms = OpTypeImage %f32 SubpassData 0 0 1 2 Unknown
+        %ptr_subpass = OpTypePointer UniformConstant %subpass
+        %ptr_subpass_ms = OpTypePointer UniformConstant %subpass_ms
+        %ptr_out = OpTypePointer Output %v4f32
+        %color = OpVariable %ptr_subpass UniformConstant
+        %depth = OpVariable %ptr_subpass_ms UniformConstant
+        %out = OpVariable %ptr_out Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %color_img = OpLoad %subpass %color
+        %color_texel = OpImageRead %v4f32 %color_img %coord
+        %depth_img = OpLoad %subpass_ms %depth
+        %depth_texel = OpImageRead %v4f32 %depth_img %coord Sample %zero
+        %sum = OpFAdd %v4f32 %color_texel %depth_texel
+        OpStore %out %sum
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let descs = entry
+        .descriptors()
+        .map(|x| match x {
+            Variable::Descriptor { desc_bind, .. } => (
+                *desc_bind,
+                x.input_attachment_index(),
+                x.is_multisampled_subpass_data(),
+            ),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        descs,
+        vec![
+            (DescriptorBinding::new(0, 0), Some(0), Some(false)),
+            (DescriptorBinding::new(0, 1), Some(1), Some(true)),
+        ]
+    );
+}
+#[test]
+fn test_binding_location_tuples() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %data %color
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 1
+        OpDecorate %color Location 2
+        OpDecorate %color Component 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %Data = OpTypeStruct %f32
+        %ptr_data = OpTypePointer Uniform %Data
+        %ptr_f32 = OpTypePointer Input %f32
+        %data = OpVariable %ptr_data Uniform
+        %color = OpVariable %ptr_f32 Input
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %Data %data
+        %y = OpLoad %f32 %color
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let mut desc_binds = Vec::new();
+    let mut locations = Vec::new();
+    for var in entry.vars.iter() {
+        match var {
+            Variable::Descriptor { desc_bind, .. } => desc_binds.push(*desc_bind),
+            Variable::Input { location, .. } => locations.push(*location),
+            _ => {}
+        }
+    }
+    assert_eq!(desc_binds, vec![DescriptorBinding::new(0, 1)]);
+    assert_eq!(desc_binds[0].into_inner(), (0, 1));
+    assert_eq!(locations, vec![InterfaceLocation::new(2, 1)]);
+    assert_eq!(locations[0].into_inner(), (2, 1));
+}
+#[cfg(feature = "serde")]
+#[test]
+fn test_gallery_json() {
+    let spv = include_bytes!("../../assets/gallery.frag.spv");
+    let expected = include_str!("../../assets/gallery.frag.spv.json");
+    let entry = ReflectConfig::new()
+        .spv(SpirvBinary::try_from_bytes(&spv[..]).unwrap())
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let expected: serde_json::Value = serde_json::from_str(expected).unwrap();
+    assert_eq!(crate::json::to_json(&entry), expected);
+
+    let mut streamed = Vec::new();
+    crate::json::write_json(&entry, &mut streamed).unwrap();
+    let pretty = serde_json::to_string_pretty(&expected).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), pretty);
+}
+#[test]
+fn test_resolve_ty_by_name() {
+    use crate::registry::TypeRegistryExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %foo %bar
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %FooData "FooData"
+        OpDecorate %FooData Block
+        OpMemberDecorate %FooData 0 Offset 0
+        OpDecorate %Unnamed Block
+        OpMemberDecorate %Unnamed 0 Offset 0
+        OpDecorate %bar DescriptorSet 0
+        OpDecorate %bar Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %FooData = OpTypeStruct %u32
+        %Unnamed = OpTypeStruct %u32 %u32
+        %ptr_foo = OpTypePointer PushConstant %FooData
+        %ptr_bar = OpTypePointer StorageBuffer %Unnamed
+        %foo = OpVariable %ptr_foo PushConstant
+        %bar = OpVariable %ptr_bar StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut foo_data = None;
+    let mut names = Vec::new();
+    ReflectConfig::new()
+        .spv(SPV)
+        .gen_unique_names(true)
+        .reflect_inspect_by(|itm, instr| {
+            if instr.op() == crate::spirv::Op::FunctionEnd {
+                foo_data = itm.ty_reg.resolve_by_name("FooData").cloned();
+                names = itm
+                    .ty_reg
+                    .iter_named()
+                    .map(|(name, _)| name.to_owned())
+                    .collect();
+            }
+        })
+        .unwrap();
+    match foo_data {
+        Some(Type::Struct(struct_ty)) => assert_eq!(struct_ty.members.len(), 1),
+        _ => panic!("FooData is not resolved"),
+    }
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[0], "FooData");
+    assert!(names[1].starts_with("type_"));
+}
+#[test]
+fn test_pointer_decorations() {
+    use crate::reflect::PointerDecorations;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability PhysicalStorageBufferAddresses
+        OpExtension "SPV_KHR_physical_storage_buffer"
+        OpMemoryModel PhysicalStorageBuffer64 GLSL450
+        OpEntryPoint GLCompute %main "main" %data
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        OpDecorate %p Restrict
+        OpDecorate %p Alignment 16
+        OpDecorate %p MaxByteOffset 256
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %zero = OpConstant %u32 0
+        %ptr_psb_u32 = OpTypePointer PhysicalStorageBuffer %u32
+        %Data = OpTypeStruct %ptr_psb_u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %ptr_ptr = OpTypePointer StorageBuffer %ptr_psb_u32
+        %fn_load = OpTypeFunction %u32 %ptr_psb_u32
+        %data = OpVariable %ptr_data StorageBuffer
+        %load = OpFunction %u32 None %fn_load
+        %p = OpFunctionParameter %ptr_psb_u32
+        %load_label = OpLabel
+        %x = OpLoad %u32 %p Aligned 16
+        OpReturnValue %x
+        OpFunctionEnd
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %ptr = OpAccessChain %ptr_ptr %data %zero
+        %addr = OpLoad %ptr_psb_u32 %ptr
+        %y = OpFunctionCall %u32 %load %addr
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut ptr_decos = Vec::new();
+    ReflectConfig::new()
+        .spv(SPV)
+        .reflect_inspect_by(|itm, instr| {
+            if instr.op() == crate::spirv::Op::FunctionParameter {
+                let param_id = instr.as_ref()[2];
+                ptr_decos.push(itm.pointer_decorations(param_id));
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        ptr_decos,
+        vec![PointerDecorations {
+            alignment: Some(16),
+            max_byte_offset: Some(256),
+        }]
+    );
+}
+#[test]
+fn test_color_outputs() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %depth %blend_src1 %color1 %color0
+        OpExecutionMode %main OriginUpperLeft
+        OpExecutionMode %main DepthReplacing
+        OpName %blend_src1 "blend_src1"
+        OpDecorate %depth BuiltIn FragDepth
+        OpDecorate %color0 Location 0
+        OpDecorate %blend_src1 Location 0
+        OpDecorate %blend_src1 Index 1
+        OpDecorate %color1 Location 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %one = OpConstant %f32 1
+        %white = OpConstantComposite %v4f32 %one %one %one %one
+        %ptr_f32 = OpTypePointer Output %f32
+        %ptr_v4f32 = OpTypePointer Output %v4f32
+        %depth = OpVariable %ptr_f32 Output
+        %blend_src1 = OpVariable %ptr_v4f32 Output
+        %color1 = OpVariable %ptr_v4f32 Output
+        %color0 = OpVariable %ptr_v4f32 Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpStore %depth %one
+        OpStore %blend_src1 %white
+        OpStore %color1 %white
+        OpStore %color0 %white
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let color_outputs = entry
+        .color_outputs()
+        .into_iter()
+        .map(|(var, index)| match var {
+            Variable::Output { location, .. } => (location.loc(), index),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(color_outputs, vec![(0, 0), (0, 1), (1, 0)]);
+    // Indices are recorded by variable, so the two outputs at location 0 are
+    // never mixed up, even if `vars` is reordered.
+    let output_indices = entry
+        .info
+        .output_indices
+        .iter()
+        .map(|(var, index)| (var.name(), *index))
+        .collect::<Vec<_>>();
+    assert_eq!(output_indices, vec![(Some("blend_src1"), 1)]);
+    let mut reordered = entry.clone();
+    reordered.vars.reverse();
+    let color_outputs = reordered
+        .color_outputs()
+        .into_iter()
+        .map(|(var, index)| (var.name(), index))
+        .collect::<Vec<_>>();
+    assert_eq!(color_outputs[1], (Some("blend_src1"), 1));
+    assert!(entry.info.builtins.contains(&(
+        crate::spirv::StorageClass::Output,
+        crate::spirv::BuiltIn::FragDepth
+    )));
+}
+#[test]
+fn test_type_pack_unpack() {
+    use crate::layout::TypePackExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %data
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpMemberDecorate %Data 1 Offset 16
+        OpMemberDecorate %Data 2 Offset 32
+        OpDecorate %arr ArrayStride 16
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %two = OpConstant %u32 2
+        %v3f32 = OpTypeVector %f32 3
+        %arr = OpTypeArray %f32 %two
+        %Data = OpTypeStruct %v3f32 %v3f32 %arr
+        %ptr_data = OpTypePointer Uniform %Data
+        %data = OpVariable %ptr_data Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %Data %data
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let ty = entry.vars[0].ty();
+    assert_eq!(ty.packed_nbyte().unwrap(), 32);
+    let host = (0..32).collect::<Vec<u8>>();
+    let gpu = ty.pack(&host).unwrap();
+    assert_eq!(gpu.len(), 64);
+    assert_eq!(&gpu[0..12], &host[0..12]);
+    assert_eq!(&gpu[12..16], &[0; 4]);
+    assert_eq!(&gpu[16..28], &host[12..24]);
+    assert_eq!(&gpu[32..36], &host[24..28]);
+    assert_eq!(&gpu[48..52], &host[28..32]);
+    assert_eq!(ty.unpack(&gpu).unwrap(), host);
+    assert!(ty.pack(&host[..31]).is_err());
+    assert!(ty.unpack(&gpu[..51]).is_err());
+    assert_eq!(ty.unpack(&gpu[..52]).unwrap(), host);
+}
+#[test]
+fn test_exec_mode_accessors() {
+    static GEOM: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Geometry
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Geometry %main "main"
+        OpExecutionMode %main Triangles
+        OpExecutionMode %main Invocations 2
+        OpExecutionMode %main OutputTriangleStrip
+        OpExecutionMode %main OutputVertices 3
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        geom,
+        spvasm,
+        vulkan1_2
+    );
+    static FRAG: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main"
+        OpExecutionMode %main OriginUpperLeft
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    static COMP: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionModeId %main LocalSizeId %x %y %z
+        OpDecorate %x SpecId 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %x = OpSpecConstant %u32 64
+        %y = OpConstant %u32 2
+        %z = OpConstant %u32 1
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let reflect = |spv: &[u32]| {
+        ReflectConfig::new()
+            .spv(spv)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    let geom = reflect(GEOM);
+    assert_eq!(geom.invocations(), Some(2));
+    assert_eq!(geom.output_vertices(), Some(3));
+    assert!(!geom.origin_upper_left());
+    assert_eq!(geom.local_size_u32(), None);
+
+    let frag = reflect(FRAG);
+    assert!(frag.origin_upper_left());
+    assert_eq!(frag.invocations(), None);
+    assert_eq!(frag.output_vertices(), None);
+
+    let comp = reflect(COMP);
+    assert_eq!(comp.local_size_u32(), Some([64, 2, 1]));
+    let comp = ReflectConfig::new()
+        .spv(COMP)
+        .specialize(0, ConstantValue::from(8u32))
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(comp.local_size_u32(), Some([8, 2, 1]));
+}
+#[test]
+fn test_split_combined_img_samplers() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %tex %data
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %tex DescriptorSet 0
+        OpDecorate %tex Binding 0
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %img = OpTypeImage %f32 2D 0 0 0 1 Unknown
+        %sampled_img = OpTypeSampledImage %img
+        %ptr_tex = OpTypePointer UniformConstant %sampled_img
+        %tex = OpVariable %ptr_tex UniformConstant
+        %Data = OpTypeStruct %f32
+        %ptr_data = OpTypePointer Uniform %Data
+        %data = OpVariable %ptr_data Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %sampled_img %tex
+        %y = OpLoad %Data %data
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let descs = |offset: u32| {
+        ReflectConfig::new()
+            .spv(SPV)
+            .split_combined_img_samplers(true)
+            .split_sampler_bind_offset(offset)
+            .reflect()
+            .map(|mut x| {
+                x.pop()
+                    .unwrap()
+                    .descriptors()
+                    .map(|x| match x {
+                        Variable::Descriptor {
+                            desc_bind, desc_ty, ..
+                        } => (*desc_bind, desc_ty.clone()),
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+    };
+    let mut split = descs(1).unwrap();
+    split.sort_by_key(|(desc_bind, _)| *desc_bind);
+    assert_eq!(
+        split,
+        vec![
+            (DescriptorBinding::new(0, 0), DescriptorType::SampledImage()),
+            (DescriptorBinding::new(0, 1), DescriptorType::Sampler()),
+            (
+                DescriptorBinding::new(0, 2),
+                DescriptorType::UniformBuffer()
+            ),
+        ]
+    );
+    let aliased = descs(0).unwrap();
+    assert!(aliased.contains(&(DescriptorBinding::new(0, 0), DescriptorType::Sampler())));
+    assert!(aliased.contains(&(DescriptorBinding::new(0, 0), DescriptorType::SampledImage())));
+    assert!(descs(2).is_err());
+    // Samplers split onto the binding of their images alias the images in
+    // merged pipelines too.
+    let pipeline = ReflectConfig::new()
+        .spv(SPV)
+        .split_combined_img_samplers(true)
+        .reflect_merged()
+        .unwrap();
+    assert_eq!(pipeline.descriptors.len(), 2);
+    let tex = &pipeline.descriptors[0];
+    assert_eq!(tex.desc_bind, DescriptorBinding::new(0, 0));
+    assert_eq!(tex.desc_tys.len(), 2);
+    assert!(tex.desc_tys.contains(&DescriptorType::SampledImage()));
+    assert!(tex.desc_tys.contains(&DescriptorType::Sampler()));
+}
+#[test]
+fn test_descriptor_type_name() {
+    use crate::descriptor::DescriptorVariableExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %data
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %Data "Data"
+        OpName %data "data"
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer Uniform %Data
+        %data = OpVariable %ptr_data Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %Data %data
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let var = entry.descriptors().next().unwrap();
+    assert_eq!(var.name(), Some("data"));
+    assert_eq!(var.type_name(), Some("Data"));
+}
+#[test]
+fn test_descriptor_accessed_via_copied_ptr() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main" %data %unused
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        OpDecorate %unused DescriptorSet 0
+        OpDecorate %unused Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %zero = OpConstant %u32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %data = OpVariable %ptr_data StorageBuffer
+        %unused = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %copy = OpCopyObject %ptr_data %data
+        %ptr = OpAccessChain %ptr_u32 %copy %zero
+        %ptr_copy = OpCopyObject %ptr_u32 %ptr
+        OpStore %ptr_copy %zero
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let desc_binds = entry
+        .descriptors()
+        .filter_map(|x| match x {
+            Variable::Descriptor { desc_bind, .. } => Some(*desc_bind),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(desc_binds, vec![DescriptorBinding::new(0, 0)]);
+}
+#[test]
+fn test_forward_pointer_linked_list() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability PhysicalStorageBufferAddresses
+        OpExtension "SPV_KHR_physical_storage_buffer"
+        OpMemoryModel PhysicalStorageBuffer64 GLSL450
+        OpEntryPoint GLCompute %main "main" %root
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Node Block
+        OpMemberDecorate %Node 0 Offset 0
+        OpMemberDecorate %Node 1 Offset 8
+        OpDecorate %Root Block
+        OpMemberDecorate %Root 0 Offset 0
+        OpDecorate %root DescriptorSet 0
+        OpDecorate %root Binding 0
+        OpTypeForwardPointer %ptr_node PhysicalStorageBuffer
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %i32 = OpTypeInt 32 1
+        %Node = OpTypeStruct %i32 %ptr_node
+        %ptr_node = OpTypePointer PhysicalStorageBuffer %Node
+        %Root = OpTypeStruct %ptr_node
+        %ptr_root = OpTypePointer StorageBuffer %Root
+        %root = OpVariable %ptr_root StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %Root %root
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let root_ty = match entry.vars[0].ty() {
+        Type::Struct(struct_ty) => struct_ty,
+        _ => panic!("root is not a struct"),
+    };
+    // The pointer in the root block is resolved to the node struct.
+    let node_ty = match &root_ty.members[0].ty {
+        Type::DevicePointer(ptr_ty) => match &*ptr_ty.pointee_ty {
+            Type::Struct(struct_ty) => struct_ty,
+            _ => panic!("pointee is not a struct"),
+        },
+        _ => panic!("root member is not a pointer"),
+    };
+    // The self-reference in the node is cyclic and stays an address.
+    assert!(matches!(node_ty.members[1].ty, Type::DeviceAddress(_)));
+}
+#[test]
+fn test_forward_pointer_acyclic() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability PhysicalStorageBufferAddresses
+        OpExtension "SPV_KHR_physical_storage_buffer"
+        OpMemoryModel PhysicalStorageBuffer64 GLSL450
+        OpEntryPoint GLCompute %main "main" %root
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %arr ArrayStride 8
+        OpMemberDecorate %Head 0 Offset 0
+        OpMemberDecorate %Head 1 Offset 8
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Root Block
+        OpMemberDecorate %Root 0 Offset 0
+        OpDecorate %root DescriptorSet 0
+        OpDecorate %root Binding 0
+        OpTypeForwardPointer %ptr_data PhysicalStorageBuffer
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %u32_2 = OpConstant %u32 2
+        %arr = OpTypeArray %ptr_data %u32_2
+        %Head = OpTypeStruct %ptr_data %arr
+        %Data = OpTypeStruct %f32
+        %ptr_data = OpTypePointer PhysicalStorageBuffer %Data
+        %Root = OpTypeStruct %Head
+        %ptr_root = OpTypePointer StorageBuffer %Root
+        %root = OpVariable %ptr_root StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %x = OpLoad %Root %root
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let head_ty = match entry.vars[0].ty() {
+        Type::Struct(struct_ty) => match &struct_ty.members[0].ty {
+            Type::Struct(struct_ty) => struct_ty,
+            _ => panic!("root member is not a struct"),
+        },
+        _ => panic!("root is not a struct"),
+    };
+    // Pointers used before their declaration are back-patched, also in
+    // arrays.
+    let is_data_ptr = |ty: &Type| match ty {
+        Type::DevicePointer(ptr_ty) => matches!(*ptr_ty.pointee_ty, Type::Struct(_)),
+        _ => false,
+    };
+    assert!(is_data_ptr(&head_ty.members[0].ty));
+    match &head_ty.members[1].ty {
+        Type::Array(arr_ty) => assert!(is_data_ptr(&arr_ty.element_ty)),
+        _ => panic!("head member is not an array"),
+    }
+}
+#[test]
+fn test_consumed_locations() {
+    use crate::interface::InterfaceVariableExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability Float64
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main" %mat_out %dvec_out %vec_out
+        OpDecorate %mat_out Location 0
+        OpDecorate %dvec_out Location 3
+        OpDecorate %dvec_out Flat
+        OpDecorate %vec_out Location 5
+        OpDecorate %vec_out Component 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %f64 = OpTypeFloat 64
+        %v2f32 = OpTypeVector %f32 2
+        %v3f32 = OpTypeVector %f32 3
+        %v3f64 = OpTypeVector %f64 3
+        %mat3 = OpTypeMatrix %v3f32 3
+        %ptr_mat3 = OpTypePointer Output %mat3
+        %ptr_v3f64 = OpTypePointer Output %v3f64
+        %ptr_v2f32 = OpTypePointer Output %v2f32
+        %mat_out = OpVariable %ptr_mat3 Output
+        %dvec_out = OpVariable %ptr_v3f64 Output
+        %vec_out = OpVariable %ptr_v2f32 Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let consumed = |loc: u32| {
+        let var = entry
+            .vars
+            .iter()
+            .find(|x| matches!(x, Variable::Output { location, .. } if location.loc() == loc))
+            .unwrap();
+        var.consumed_locations()
+            .into_iter()
+            .map(|x| x.into_inner())
+            .collect::<Vec<_>>()
+    };
+    let mat_locs = consumed(0);
+    assert_eq!(mat_locs.len(), 9);
+    assert_eq!(mat_locs[..3], [(0, 0), (0, 1), (0, 2)]);
+    assert_eq!(mat_locs[3..6], [(1, 0), (1, 1), (1, 2)]);
+    assert_eq!(mat_locs[6..], [(2, 0), (2, 1), (2, 2)]);
+    assert_eq!(
+        consumed(3),
+        vec![(3, 0), (3, 1), (3, 2), (3, 3), (4, 0), (4, 1)]
+    );
+    assert_eq!(consumed(5), vec![(5, 1), (5, 2)]);
+}
+#[test]
+fn test_truncated_spv() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %main "main"
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %buf DescriptorSet 0
+        OpDecorate %buf Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %buf_ty = OpTypeStruct %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %buf = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let spv = SpirvBinary::from(SPV);
+    assert!(spv.checked_instrs().is_ok());
+    // Instruction boundaries, where truncation leaves a well-formed stream.
+    let mut bounds = vec![5];
+    let mut instrs = spv.instrs().unwrap();
+    while let Some(instr) = instrs.next().unwrap() {
+        bounds.push(bounds.last().unwrap() + instr.word_count());
+    }
+    assert_eq!(*bounds.last().unwrap(), SPV.len());
+    for len in 0..SPV.len() {
+        let spv = SpirvBinary::from(&SPV[..len]);
+        if len < 5 || !bounds.contains(&len) {
+            let err = spv.checked_instrs().err().unwrap();
+            assert!(err.to_string().contains("truncated") || len < 5, "{}", err);
+            assert!(ReflectConfig::new().spv(spv).reflect().is_err());
+        } else {
+            // Reflection may fail but must not panic.
+            let _ = ReflectConfig::new().spv(spv).ref_all_rscs(true).reflect();
+        }
+    }
+    // Word count shorter than the operands of the instruction.
+    let mut shortened = SPV.to_vec();
+    let ivar = bounds
+        .windows(2)
+        .find(|x| SPV[x[0]] & 0xffff == crate::spirv::Op::Variable as u32)
+        .unwrap()[0];
+    shortened.remove(ivar + 3);
+    shortened[ivar] = (3 << 16) | crate::spirv::Op::Variable as u32;
+    assert!(ReflectConfig::new()
+        .spv(shortened)
+        .ref_all_rscs(true)
+        .reflect()
+        .is_err());
+}
+#[test]
+fn test_infer_access_from_usage() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %dst DescriptorSet 0
+        OpDecorate %dst Binding 0
+        OpDecorate %src DescriptorSet 0
+        OpDecorate %src Binding 1
+        OpDecorate %img DescriptorSet 0
+        OpDecorate %img Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %i32 = OpTypeInt 32 1
+        %v2i32 = OpTypeVector %i32 2
+        %v4u32 = OpTypeVector %u32 4
+        %u32_0 = OpConstant %u32 0
+        %i32_0 = OpConstant %i32 0
+        %coord = OpConstantComposite %v2i32 %i32_0 %i32_0
+        %buf_ty = OpTypeStruct %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %img_ty = OpTypeImage %u32 2D 0 0 0 2 R32ui
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %dst = OpVariable %ptr_buf StorageBuffer
+        %src = OpVariable %ptr_buf StorageBuffer
+        %img = OpVariable %ptr_img UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %src_ptr = OpAccessChain %ptr_u32 %src %i32_0
+        %x = OpLoad %u32 %src_ptr
+        %dst_ptr = OpAccessChain %ptr_u32 %dst %i32_0
+        OpStore %dst_ptr %x
+        %img_handle = OpLoad %img_ty %img
+        %texel = OpCompositeConstruct %v4u32 %x %x %x %x
+        OpImageWrite %img_handle %coord %texel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let get_desc_tys = |infer_access_from_usage: bool| {
+        let entry = ReflectConfig::new()
+            .spv(SPV)
+            .infer_access_from_usage(infer_access_from_usage)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap();
+        entry
+            .vars
+            .iter()
+            .filter_map(|x| match x {
+                Variable::Descriptor {
+                    desc_bind, desc_ty, ..
+                } => Some((desc_bind.bind(), desc_ty.clone())),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>()
+    };
+    let desc_tys = get_desc_tys(false);
+    assert_eq!(
+        desc_tys[&0],
+        DescriptorType::StorageBuffer(AccessType::ReadWrite)
+    );
+    assert_eq!(
+        desc_tys[&1],
+        DescriptorType::StorageBuffer(AccessType::ReadWrite)
+    );
+    assert_eq!(
+        desc_tys[&2],
+        DescriptorType::StorageImage(AccessType::ReadWrite)
+    );
+    let desc_tys = get_desc_tys(true);
+    assert_eq!(
+        desc_tys[&0],
+        DescriptorType::StorageBuffer(AccessType::WriteOnly)
+    );
+    assert_eq!(
+        desc_tys[&1],
+        DescriptorType::StorageBuffer(AccessType::ReadOnly)
+    );
+    assert_eq!(
+        desc_tys[&2],
+        DescriptorType::StorageImage(AccessType::WriteOnly)
+    );
+}
+#[test]
+fn test_diff_entry_points() {
+    use crate::diff::{diff, LayoutChange};
+    static SPV_OLD: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %a DescriptorSet 0
+        OpDecorate %a Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %buf_ty = OpTypeStruct %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %a = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    static SPV_NEW: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %buf_ty "Renamed"
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %a DescriptorSet 0
+        OpDecorate %a Binding 0
+        OpDecorate %b DescriptorSet 0
+        OpDecorate %b Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %buf_ty = OpTypeStruct %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %a = OpVariable %ptr_buf StorageBuffer
+        %b = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let reflect = |spv: &'static [u32]| {
+        ReflectConfig::new()
+            .spv(spv)
+            .ref_all_rscs(true)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let old = reflect(SPV_OLD);
+    let new = reflect(SPV_NEW);
+    assert!(diff(&old, &old).is_empty());
+    assert_eq!(
+        diff(&old, &new),
+        vec![LayoutChange::DescriptorAdded(DescriptorBinding::new(0, 1))]
+    );
+    assert_eq!(
+        diff(&new, &old),
+        vec![LayoutChange::DescriptorRemoved(DescriptorBinding::new(
+            0, 1
+        ))]
+    );
+}
+#[test]
+fn test_matrix_dimensions() {
+    use crate::layout::MatrixTypeExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpMemberDecorate %buf_ty 0 ColMajor
+        OpMemberDecorate %buf_ty 0 MatrixStride 16
+        OpMemberDecorate %buf_ty 1 Offset 48
+        OpMemberDecorate %buf_ty 1 RowMajor
+        OpMemberDecorate %buf_ty 1 MatrixStride 16
+        OpDecorate %buf DescriptorSet 0
+        OpDecorate %buf Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %mat3x4 = OpTypeMatrix %v4f32 3
+        %buf_ty = OpTypeStruct %mat3x4 %mat3x4
+        %ptr_buf = OpTypePointer Uniform %buf_ty
+        %buf = OpVariable %ptr_buf Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let desc_var = entry.descriptors().next().unwrap();
+    let members = match desc_var.ty() {
+        Type::Struct(struct_ty) => struct_ty.members.clone(),
+        _ => panic!("expected a struct"),
+    };
+    let mats = members
+        .iter()
+        .map(|x| match &x.ty {
+            Type::Matrix(matrix_ty) => matrix_ty.clone(),
+            _ => panic!("expected a matrix"),
+        })
+        .collect::<Vec<_>>();
+    for mat in mats.iter() {
+        assert_eq!(mat.dimensions(), (4, 3));
+        assert_eq!(mat.column_type().nscalar, 4);
+        assert_eq!(mat.row_type().nscalar, 3);
+    }
+    let (col_major, row_major) = (&mats[0], &mats[1]);
+    assert_eq!(col_major.major_type(), col_major.column_type());
+    assert_eq!(col_major.nmajor(), 3);
+    assert_eq!(row_major.major_type(), row_major.row_type());
+    assert_eq!(row_major.nmajor(), 4);
+}
+#[test]
+fn test_local_size_id() {
+    static SPV_LITERAL: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 8 4 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    static SPV_ID: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionModeId %main LocalSizeId %x %y %z
+        OpDecorate %x SpecId 0
+        OpDecorate %y SpecId 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %x = OpSpecConstant %u32 8
+        %y = OpSpecConstant %u32 4
+        %z = OpConstant %u32 1
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let reflect = |spv: &'static [u32]| {
+        ReflectConfig::new()
+            .spv(spv)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let literal = reflect(SPV_LITERAL);
+    let id = reflect(SPV_ID);
+    assert_eq!(literal.local_size_u32(), Some([8, 4, 1]));
+    assert_eq!(id.local_size_u32(), literal.local_size_u32());
+    let spec_ids = id
+        .local_size()
+        .unwrap()
+        .iter()
+        .map(|x| x.spec_id)
+        .collect::<Vec<_>>();
+    assert_eq!(spec_ids, vec![Some(0), Some(1), None]);
+    assert!(literal
+        .local_size()
+        .unwrap()
+        .iter()
+        .all(|x| x.spec_id.is_none()));
+}
+#[test]
+fn test_strip_debug_info() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        %file = OpString "main.comp"
+        OpSource GLSL 450 %file
+        OpSourceExtension "GL_EXT_debug_printf"
+        OpName %main "main"
+        OpName %buf_ty "Data"
+        OpMemberName %buf_ty 0 "x"
+        OpName %buf "data"
+        OpModuleProcessed "client vulkan100"
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %buf DescriptorSet 1
+        OpDecorate %buf Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %buf_ty = OpTypeStruct %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %buf = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpLine %file 1 1
+        OpNoLine
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let spv = SpirvBinary::from(SPV);
+    let stripped = spv.strip_debug_info().unwrap();
+    assert_eq!(stripped.words()[..5], SPV[..5]);
+    assert!(stripped.words().len() < SPV.len());
+    let mut instrs = stripped.instrs().unwrap();
+    while let Some(instr) = instrs.next().unwrap() {
+        use crate::spirv::Op;
+        assert!(
+            !matches!(
+                instr.op(),
+                Op::Name
+                    | Op::MemberName
+                    | Op::Source
+                    | Op::SourceExtension
+                    | Op::Line
+                    | Op::NoLine
+                    | Op::ModuleProcessed
+                    | Op::String
+            ),
+            "{:?}",
+            instr
+        );
+    }
+    assert_eq!(
+        stripped.strip_debug_info().unwrap().words(),
+        stripped.words()
+    );
+
+    let reflect = |spv: SpirvBinary| {
+        ReflectConfig::new()
+            .spv(spv)
+            .ref_all_rscs(true)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let entry = reflect(spv);
+    let stripped_entry = reflect(stripped);
+    let desc = entry.descriptors().next().unwrap();
+    let stripped_desc = stripped_entry.descriptors().next().unwrap();
+    assert_eq!(desc.name(), Some("data"));
+    assert_eq!(stripped_desc.name(), None);
+    assert!(crate::diff::diff(&entry, &stripped_entry).is_empty());
+    match (desc, stripped_desc) {
+        (
+            Variable::Descriptor {
+                desc_bind, desc_ty, ..
+            },
+            Variable::Descriptor {
+                desc_bind: stripped_desc_bind,
+                desc_ty: stripped_desc_ty,
+                ty: stripped_ty,
+                ..
+            },
+        ) => {
+            assert_eq!(desc_bind, stripped_desc_bind);
+            assert_eq!(desc_ty, stripped_desc_ty);
+            match stripped_ty {
+                Type::Struct(struct_ty) => {
+                    assert_eq!(struct_ty.name(), None);
+                    assert_eq!(struct_ty.members[0].name, None);
+                }
+                _ => panic!("expected a struct"),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+#[test]
+fn test_image_format_to_vk() {
+    use crate::interop::vulkan::image_format_to_vk;
+    use crate::spirv::ImageFormat;
+    let mut vk_fmts = HashSet::default();
+    let mut nfmt = 0;
+    while let Some(fmt) = ImageFormat::from_u32(nfmt) {
+        nfmt += 1;
+        let vk_fmt = image_format_to_vk(fmt);
+        if fmt == ImageFormat::Unknown {
+            assert_eq!(vk_fmt, None);
+        } else {
+            let vk_fmt = vk_fmt.unwrap_or_else(|| panic!("{:?} has no vulkan format", fmt));
+            assert!(vk_fmts.insert(vk_fmt), "{:?} is mapped twice", vk_fmt);
+        }
+    }
+    assert_eq!(vk_fmts.len() as u32, nfmt - 1);
+    // VK_FORMAT_R16G16B16A16_SFLOAT
+    assert_eq!(image_format_to_vk(ImageFormat::Rgba16f), Some(97));
+    // VK_FORMAT_R32_UINT
+    assert_eq!(image_format_to_vk(ImageFormat::R32ui), Some(98));
+}
+#[test]
+fn test_reflect_repeatedly() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %n SpecId 0
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %n = OpSpecConstant %u32 4
+        %arr = OpTypeArray %u32 %n
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut cfg = ReflectConfig::new();
+    cfg.spv(SPV).ref_all_rscs(true);
+    let words_ptr = cfg.spv.as_ref().unwrap().words().as_ptr();
+    for n in [1, 2, 3] {
+        let entry = cfg
+            .specialize(0, ConstantValue::U32(n))
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap();
+        let desc = entry.descriptors().next().unwrap();
+        assert_eq!(desc.ty().nbyte(), Some(4 * n as usize));
+        // The words are neither taken nor copied by reflection.
+        assert_eq!(cfg.spv.as_ref().unwrap().words().as_ptr(), words_ptr);
+    }
+}
+#[test]
+fn test_primitive_topology() {
+    use crate::entry_point::{PrimitiveTopology, TessellationSpacing};
+    static TESE: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Tessellation
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint TessellationEvaluation %main "main"
+        OpExecutionMode %main Quads
+        OpExecutionMode %main SpacingFractionalOdd
+        OpExecutionMode %main VertexOrderCw
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        tese,
+        spvasm,
+        vulkan1_2
+    );
+    static GEOM: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Geometry
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Geometry %main "main"
+        OpExecutionMode %main InputLinesAdjacency
+        OpExecutionMode %main Invocations 1
+        OpExecutionMode %main OutputLineStrip
+        OpExecutionMode %main OutputVertices 4
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        geom,
+        spvasm,
+        vulkan1_2
+    );
+    let reflect = |spv: &'static [u32]| {
+        ReflectConfig::new()
+            .spv(spv)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let tese = reflect(TESE);
+    assert_eq!(tese.primitive_topology(), Some(PrimitiveTopology::Quads));
+    assert_eq!(tese.output_topology(), Some(PrimitiveTopology::Triangles));
+    assert_eq!(
+        tese.tessellation_spacing(),
+        Some(TessellationSpacing::FractionalOdd)
+    );
+    let geom = reflect(GEOM);
+    assert_eq!(
+        geom.primitive_topology(),
+        Some(PrimitiveTopology::LinesAdjacency)
+    );
+    assert_eq!(geom.output_topology(), Some(PrimitiveTopology::LineStrip));
+    assert_eq!(geom.tessellation_spacing(), None);
+}
+#[cfg(feature = "serde")]
+#[test]
+fn test_member_access_json() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpMemberDecorate %buf_ty 0 NonWritable
+        OpMemberDecorate %buf_ty 1 Offset 4
+        OpMemberDecorate %buf_ty 2 Offset 8
+        OpMemberDecorate %buf_ty 2 NonWritable
+        OpMemberDecorate %inner_ty 0 Offset 0
+        OpDecorate %buf DescriptorSet 0
+        OpDecorate %buf Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %inner_ty = OpTypeStruct %u32
+        %buf_ty = OpTypeStruct %u32 %u32 %inner_ty
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %buf = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let json = crate::json::to_json(&entry);
+    let members = &json["Variables"]["Descriptors"][0]["Type"]["Members"];
+    assert_eq!(members[0]["AccessType"], "ReadOnly");
+    assert_eq!(members[1]["AccessType"], "ReadWrite");
+    assert_eq!(members[2]["AccessType"], "ReadOnly");
+    // Members of nested structs are restricted by the enclosing member.
+    let inner_members = &members[2]["MemberType"]["Members"];
+    assert_eq!(inner_members[0]["AccessType"], "ReadOnly");
+}
+#[test]
+fn test_constant_value_ext() {
+    use crate::value::ConstantValueExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability Int64
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionModeId %main LocalSizeId %x %one %one
+        OpDecorate %x SpecId 0
+        ; 64-bit literals are given in two words, low-order word first.
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %u64 = OpTypeInt 64 0
+        %x = OpSpecConstant %u64 8 0
+        %one = OpConstant %u32 1
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let local_size = entry.local_size().unwrap();
+    assert_eq!(local_size[0].value, ConstantValue::U64(8));
+    assert_eq!(local_size[0].value.display().to_string(), "8");
+    assert_eq!(entry.local_size_u32(), Some([8, 1, 1]));
+    #[cfg(feature = "serde")]
+    {
+        let json = crate::json::to_json(&entry);
+        assert_eq!(json["ExecutionModes"][0]["Operands"][0]["Value"], "8");
+    }
+
+    assert_eq!(ConstantValue::S16(-3).to_i64(), Some(-3));
+    assert_eq!(ConstantValue::S16(-3).to_u64(), None);
+    assert_eq!(ConstantValue::U64(u64::MAX).to_i64(), None);
+    assert_eq!(ConstantValue::U8(7).to_f64(), Some(7.0));
+    assert_eq!(ConstantValue::from(1.5f32).to_f64(), Some(1.5));
+    assert_eq!(ConstantValue::from(1.5f32).to_u64(), None);
+    assert_eq!(ConstantValue::Bool(true).to_u64(), Some(1));
+    assert_eq!(ConstantValue::S64(-12).display().to_string(), "-12");
+    assert_eq!(
+        ConstantValue::from([0x00u8, 0x00, 0x80, 0x3f])
+            .display()
+            .to_string(),
+        "0x3f800000"
+    );
+}
+#[test]
+fn test_reflect_merged() {
+    static RGEN: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability RayTracingKHR
+        OpExtension "SPV_KHR_ray_tracing"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint RayGenerationKHR %main "main"
+        OpDecorate %acc DescriptorSet 0
+        OpDecorate %acc Binding 0
+        OpDecorate %img DescriptorSet 0
+        OpDecorate %img Binding 1
+        OpDecorate %img NonReadable
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %acc_ty = OpTypeAccelerationStructureKHR
+        %ptr_acc = OpTypePointer UniformConstant %acc_ty
+        %acc = OpVariable %ptr_acc UniformConstant
+        %img_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba8
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %img = OpVariable %ptr_img UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        rgen,
+        spvasm,
+        vulkan1_2
+    );
+    static RCHIT: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability RayTracingKHR
+        OpExtension "SPV_KHR_ray_tracing"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint ClosestHitKHR %main "main"
+        OpDecorate %acc DescriptorSet 0
+        OpDecorate %acc Binding 0
+        OpDecorate %img DescriptorSet 0
+        OpDecorate %img Binding 1
+        OpDecorate %img NonWritable
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %acc_ty = OpTypeAccelerationStructureKHR
+        %ptr_acc = OpTypePointer UniformConstant %acc_ty
+        %acc = OpVariable %ptr_acc UniformConstant
+        %img_ty = OpTypeImage %f32 2D 0 0 0 2 Rgba8
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %img = OpVariable %ptr_img UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        rchit,
+        spvasm,
+        vulkan1_2
+    );
+    static RMISS: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability RayTracingKHR
+        OpExtension "SPV_KHR_ray_tracing"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint MissKHR %main "main"
+        OpDecorate %smp DescriptorSet 0
+        OpDecorate %smp Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %smp_ty = OpTypeSampler
+        %ptr_smp = OpTypePointer UniformConstant %smp_ty
+        %smp = OpVariable %ptr_smp UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        rmiss,
+        spvasm,
+        vulkan1_2
+    );
+    let mut cfg = ReflectConfig::new();
+    cfg.spv(RGEN).add_spv(RCHIT).ref_all_rscs(true);
+    let pipeline = cfg.reflect_merged().unwrap();
+    let exec_models = pipeline
+        .entry_points
+        .iter()
+        .map(|x| x.exec_model)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exec_models,
+        vec![
+            ExecutionModel::RayGenerationKHR,
+            ExecutionModel::ClosestHitKHR
+        ]
+    );
+    assert_eq!(pipeline.descriptors.len(), 2);
+    let acc = &pipeline.descriptors[0];
+    assert_eq!(acc.desc_bind, DescriptorBinding::new(0, 0));
+    assert_eq!(acc.desc_tys, vec![DescriptorType::AccelStruct()]);
+    assert_eq!(acc.exec_models, exec_models);
+    let img = &pipeline.descriptors[1];
+    assert_eq!(img.desc_bind, DescriptorBinding::new(0, 1));
+    assert_eq!(
+        img.desc_tys,
+        vec![DescriptorType::StorageImage(AccessType::ReadWrite)]
+    );
+
+    // The miss shader binds a sampler where the other stages bind a storage
+    // image, so the two alias the binding point.
+    cfg.add_spv(RMISS);
+    assert_eq!(cfg.reflect().unwrap().len(), 3);
+    let pipeline = cfg.reflect_merged().unwrap();
+    let img = &pipeline.descriptors[1];
+    assert_eq!(
+        img.desc_tys,
+        vec![
+            DescriptorType::StorageImage(AccessType::ReadWrite),
+            DescriptorType::Sampler()
+        ]
+    );
+    assert_eq!(img.exec_models.len(), 3);
+    // Module-wide information can't be given for multiple binaries.
+    let err = cfg.reflect_module().err().unwrap();
+    assert!(err.to_string().contains("add_spv"));
+}
+#[test]
+fn test_is_host_shareable() {
+    use crate::layout::TypeLayoutExt;
+    let member = |ty| ty::StructMember {
+        name: None,
+        offset: Some(0),
+        ty,
+        access_ty: AccessType::ReadWrite,
+    };
+    let struct_of = |tys: Vec<Type>| {
+        Type::Struct(ty::StructType {
+            name: None,
+            members: tys.into_iter().map(member).collect(),
+        })
+    };
+    let float = Type::Scalar(ty::ScalarType::f32());
+    let runtime_arr = Type::Array(ty::ArrayType {
+        element_ty: Box::new(float.clone()),
+        nelement: None,
+        stride: Some(4),
+    });
+    let sampler = Type::Sampler(ty::SamplerType {});
+    let accel = Type::AccelStruct(ty::AccelStructType {});
+
+    assert!(float.is_host_shareable());
+    assert!(runtime_arr.is_host_shareable());
+    assert!(struct_of(vec![float.clone(), runtime_arr.clone()]).is_host_shareable());
+    assert!(struct_of(vec![struct_of(vec![float.clone()])]).is_host_shareable());
+    assert!(!sampler.is_host_shareable());
+    assert!(!accel.is_host_shareable());
+    assert!(!struct_of(vec![float.clone(), sampler.clone()]).is_host_shareable());
+    let nested = struct_of(vec![float, struct_of(vec![accel])]);
+    assert!(!nested.is_host_shareable());
+    let nested_arr = Type::Array(ty::ArrayType {
+        element_ty: Box::new(nested),
+        nelement: Some(2),
+        stride: Some(16),
+    });
+    assert!(!nested_arr.is_host_shareable());
+}
+#[test]
+fn test_type_display_glsl() {
+    use crate::display::TypeDisplayExt;
+    use crate::spirv::{Dim, ImageFormat};
+    let vec4 = ty::VectorType {
+        scalar_ty: ty::ScalarType::f32(),
+        nscalar: 4,
+    };
+    let sampled_image_ty = |scalar_ty, dim, is_depth, is_array| ty::SampledImageType {
+        scalar_ty,
+        dim,
+        is_depth: Some(is_depth),
+        is_array,
+        is_multisampled: false,
+    };
+    let cases = [
+        (Type::Scalar(ty::ScalarType::u32()), "u32", "uint"),
+        (Type::Vector(vec4.clone()), "vec4<f32>", "vec4"),
+        (
+            Type::Vector(ty::VectorType {
+                scalar_ty: ty::ScalarType::i32(),
+                nscalar: 2,
+            }),
+            "vec2<i32>",
+            "ivec2",
+        ),
+        (
+            Type::Matrix(ty::MatrixType {
+                vector_ty: vec4.clone(),
+                nvector: 3,
+                axis_order: Some(ty::MatrixAxisOrder::ColumnMajor),
+                stride: Some(16),
+            }),
+            "mat4x3<f32,ColumnMajor,16>",
+            "mat3x4",
+        ),
+        (
+            Type::Matrix(ty::MatrixType {
+                vector_ty: vec4.clone(),
+                nvector: 4,
+                axis_order: Some(ty::MatrixAxisOrder::ColumnMajor),
+                stride: Some(16),
+            }),
+            "mat4x4<f32,ColumnMajor,16>",
+            "mat4",
+        ),
+        (
+            Type::CombinedImageSampler(ty::CombinedImageSamplerType {
+                sampled_image_ty: sampled_image_ty(ty::ScalarType::f32(), Dim::Dim2D, false, true),
+            }),
+            "CombinedImageSampler<SampledImage2DArray<f32,Color>>",
+            "sampler2DArray",
+        ),
+        (
+            Type::CombinedImageSampler(ty::CombinedImageSamplerType {
+                sampled_image_ty: sampled_image_ty(ty::ScalarType::u32(), Dim::Dim2D, false, false),
+            }),
+            "CombinedImageSampler<SampledImage2D<u32,Color>>",
+            "usampler2D",
+        ),
+        (
+            Type::CombinedImageSampler(ty::CombinedImageSamplerType {
+                sampled_image_ty: sampled_image_ty(
+                    ty::ScalarType::f32(),
+                    Dim::DimCube,
+                    true,
+                    false,
+                ),
+            }),
+            "CombinedImageSampler<SampledImageCube<f32,Depth>>",
+            "samplerCubeShadow",
+        ),
+        (
+            Type::StorageImage(ty::StorageImageType {
+                dim: Dim::Dim2D,
+                is_array: false,
+                is_multisampled: false,
+                fmt: ImageFormat::Rgba8,
+            }),
+            "StorageImage2D<Rgba8>",
+            "image2D",
+        ),
+        (
+            Type::StorageImage(ty::StorageImageType {
+                dim: Dim::Dim3D,
+                is_array: false,
+                is_multisampled: false,
+                fmt: ImageFormat::R32ui,
+            }),
+            "StorageImage3D<R32ui>",
+            "uimage3D",
+        ),
+        (
+            Type::Array(ty::ArrayType {
+                element_ty: Box::new(Type::Array(ty::ArrayType {
+                    element_ty: Box::new(Type::Vector(vec4)),
+                    nelement: Some(3),
+                    stride: Some(16),
+                })),
+                nelement: None,
+                stride: Some(48),
+            }),
+            "[[vec4<f32>; 3]]",
+            "vec4[][3]",
+        ),
+    ];
+    for (ty, default, glsl) in cases.iter() {
+        assert_eq!(format!("{}", ty), *default);
+        assert_eq!(format!("{}", ty.display()), *default);
+        assert_eq!(format!("{:#}", ty.display()), *glsl);
+    }
+}
+#[test]
+fn test_walk_depth_and_leaves() {
+    use crate::walk::Walk;
+    let vec_ty = |nscalar| {
+        Type::Vector(ty::VectorType {
+            scalar_ty: ty::ScalarType::f32(),
+            nscalar,
+        })
+    };
+    let member = |name: &str, offset, ty| ty::StructMember {
+        name: Some(name.to_owned()),
+        offset: Some(offset),
+        ty,
+        access_ty: AccessType::ReadWrite,
+    };
+    let inner = Type::Struct(ty::StructType {
+        name: None,
+        members: vec![
+            member("b", 0, vec_ty(2)),
+            member("c", 8, Type::Scalar(ty::ScalarType::f32())),
+        ],
+    });
+    let arr = Type::Array(ty::ArrayType {
+        element_ty: Box::new(vec_ty(4)),
+        nelement: Some(2),
+        stride: Some(16),
+    });
+    let ty = Type::Struct(ty::StructType {
+        name: None,
+        members: vec![
+            member("a", 0, vec_ty(4)),
+            member("inner", 16, inner),
+            member("arr", 32, arr),
+        ],
+    });
+    let routes = |walk: Walk| {
+        walk.map(|x| (format!("{:?}", x.sym), x.offset))
+            .collect::<Vec<_>>()
+    };
+    let route = |sym: &str, offset| (sym.to_owned(), offset);
+
+    assert_eq!(Walk::new(&ty).count(), 8);
+    assert_eq!(
+        routes(Walk::new(&ty).leaves_only()),
+        vec![
+            route("[a]", 0),
+            route("[inner, b]", 16),
+            route("[inner, c]", 24),
+            route("[arr, 0]", 32),
+            route("[arr, 1]", 48),
+        ]
+    );
+    assert!(Walk::new(&ty)
+        .leaves_only()
+        .all(|x| !x.ty.is_struct() && !x.ty.is_array()));
+    assert_eq!(
+        routes(Walk::new(&ty).with_max_depth(1)),
+        vec![
+            route("[a]", 0),
+            route("[inner]", 16),
+            route("[arr]", 32),
+            route("[]", 0),
+        ]
+    );
+    assert_eq!(
+        routes(Walk::new(&ty).with_max_depth(1).leaves_only()),
+        vec![route("[a]", 0), route("[inner]", 16), route("[arr]", 32)]
+    );
+    assert_eq!(
+        routes(Walk::new(&ty).with_max_depth(0).leaves_only()),
+        vec![route("[]", 0)]
+    );
+}
+#[test]
+fn test_member_names_of_unnamed_struct() {
+    // DXC output of a `cbuffer` whose block type doesn't have an `OpName`.
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpMemberName %Globals 0 "scale"
+        OpMemberName %Globals 1 "offset"
+        OpName %globals "Globals"
+        OpDecorate %globals DescriptorSet 0
+        OpDecorate %globals Binding 0
+        OpDecorate %Globals Block
+        OpMemberDecorate %Globals 0 Offset 0
+        OpMemberDecorate %Globals 1 Offset 16
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %Globals = OpTypeStruct %f32 %v4f32
+        %ptr_Globals = OpTypePointer Uniform %Globals
+        %globals = OpVariable %ptr_Globals Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let var = entry.descriptors().next().unwrap();
+    assert_eq!(var.name(), Some("Globals"));
+    let struct_ty = var.ty().as_struct().unwrap();
+    assert_eq!(struct_ty.name, None);
+    let names = struct_ty
+        .members
+        .iter()
+        .map(|x| x.name.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![Some("scale"), Some("offset")]);
+}
+#[test]
+fn test_spec_default() {
+    // layout(constant_id = 3) const int n = 7;
+    // layout(constant_id = 4) const bool flag = true;
+    // layout(constant_id = 5) const uint m = 2;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %n SpecId 3
+        OpDecorate %flag SpecId 4
+        OpDecorate %m SpecId 5
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %bool = OpTypeBool
+        %i32 = OpTypeInt 32 1
+        %u32 = OpTypeInt 32 0
+        %v2u32 = OpTypeVector %u32 2
+        %n = OpSpecConstant %i32 7
+        %flag = OpSpecConstantTrue %bool
+        %m = OpSpecConstant %u32 2
+        %mm = OpSpecConstantComposite %v2u32 %m %m
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .specialize(5, ConstantValue::U32(9))
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.spec_default(3), Some(&ConstantValue::S32(7)));
+    assert_eq!(entry.spec_default(4), Some(&ConstantValue::Bool(true)));
+    // Specialization doesn't change the compiled-in default.
+    assert_eq!(entry.spec_default(5), Some(&ConstantValue::U32(2)));
+    assert_eq!(entry.spec_default(6), None);
+    let spec_ids = entry
+        .info
+        .spec_defaults
+        .iter()
+        .map(|(spec_id, _)| *spec_id)
+        .collect::<Vec<_>>();
+    assert_eq!(spec_ids, vec![3, 4, 5]);
+}
+#[test]
+fn test_nonuniform_indexed() {
+    // layout(binding = 0) uniform sampler2D textures[];
+    // layout(binding = 1) uniform sampler2D uniform_textures[4];
+    // layout(location = 0) flat in int i;
+    // layout(location = 0) out vec4 color;
+    // void main() {
+    //     color = texture(textures[nonuniformEXT(i)], vec2(0.0)) +
+    //         texture(uniform_textures[0], vec2(0.0));
+    // }
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability ShaderNonUniform
+        OpCapability RuntimeDescriptorArray
+        OpCapability SampledImageArrayNonUniformIndexing
+        OpExtension "SPV_EXT_descriptor_indexing"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %i %color
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %textures DescriptorSet 0
+        OpDecorate %textures Binding 0
+        OpDecorate %uniform_textures DescriptorSet 0
+        OpDecorate %uniform_textures Binding 1
+        OpDecorate %i Flat
+        OpDecorate %i Location 0
+        OpDecorate %color Location 0
+        OpDecorate %idx NonUniform
+        OpDecorate %ac NonUniform
+        OpDecorate %tex NonUniform
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %i32 = OpTypeInt 32 1
+        %v2f32 = OpTypeVector %f32 2
+        %v4f32 = OpTypeVector %f32 4
+        %img = OpTypeImage %f32 2D 0 0 0 1 Unknown
+        %sampled_img = OpTypeSampledImage %img
+        %rt_arr = OpTypeRuntimeArray %sampled_img
+        %u32 = OpTypeInt 32 0
+        %c4 = OpConstant %u32 4
+        %arr = OpTypeArray %sampled_img %c4
+        %ptr_rt_arr = OpTypePointer UniformConstant %rt_arr
+        %ptr_arr = OpTypePointer UniformConstant %arr
+        %ptr_sampled_img = OpTypePointer UniformConstant %sampled_img
+        %ptr_in_i32 = OpTypePointer Input %i32
+        %ptr_out_v4f32 = OpTypePointer Output %v4f32
+        %textures = OpVariable %ptr_rt_arr UniformConstant
+        %uniform_textures = OpVariable %ptr_arr UniformConstant
+        %i = OpVariable %ptr_in_i32 Input
+        %color = OpVariable %ptr_out_v4f32 Output
+        %c0 = OpConstant %i32 0
+        %f0 = OpConstant %f32 0
+        %uv = OpConstantComposite %v2f32 %f0 %f0
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %i_value = OpLoad %i32 %i
+        %idx = OpCopyObject %i32 %i_value
+        %ac = OpAccessChain %ptr_sampled_img %textures %idx
+        %tex = OpLoad %sampled_img %ac
+        %a = OpImageSampleImplicitLod %v4f32 %tex %uv
+        %uniform_ac = OpAccessChain %ptr_sampled_img %uniform_textures %c0
+        %uniform_tex = OpLoad %sampled_img %uniform_ac
+        %b = OpImageSampleImplicitLod %v4f32 %uniform_tex %uv
+        %sum = OpFAdd %v4f32 %a %b
+        OpStore %color %sum
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let module = ReflectConfig::new().spv(SPV).reflect_module().unwrap();
+    assert!(module
+        .info
+        .has_capability(crate::spirv::Capability::RuntimeDescriptorArray));
+    let entry = &module.entry_points[0];
+    assert_eq!(
+        entry.info.nonuniform_indexed,
+        vec![DescriptorBinding::new(0, 0)]
+    );
+    let descs = entry.descriptors().collect::<Vec<_>>();
+    assert_eq!(descs.len(), 2);
+    assert!(entry.is_nonuniform_indexed(descs[0]));
+    assert!(!entry.is_nonuniform_indexed(descs[1]));
+    assert!(entry.is_runtime_binding_array(descs[0]));
+
+    // Samplers split from the textures are non-uniformly indexed too.
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .split_combined_img_samplers(true)
+        .split_sampler_bind_offset(8)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(
+        entry.info.nonuniform_indexed,
+        vec![DescriptorBinding::new(0, 0), DescriptorBinding::new(0, 8)]
+    );
+}
+#[test]
+fn test_interface_var_ids() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %uv %color %tex
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %uv Location 0
+        OpDecorate %color Location 0
+        OpDecorate %tex DescriptorSet 0
+        OpDecorate %tex Binding 0
+        OpDecorate %unused DescriptorSet 0
+        OpDecorate %unused Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v2f32 = OpTypeVector %f32 2
+        %v4f32 = OpTypeVector %f32 4
+        %img = OpTypeImage %f32 2D 0 0 0 1 Unknown
+        %sampled_img = OpTypeSampledImage %img
+        %ptr_sampled_img = OpTypePointer UniformConstant %sampled_img
+        %ptr_in_v2f32 = OpTypePointer Input %v2f32
+        %ptr_out_v4f32 = OpTypePointer Output %v4f32
+        %uv = OpVariable %ptr_in_v2f32 Input
+        %color = OpVariable %ptr_out_v4f32 Output
+        %tex = OpVariable %ptr_sampled_img UniformConstant
+        %unused = OpVariable %ptr_sampled_img UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %uv_value = OpLoad %v2f32 %uv
+        %tex_value = OpLoad %sampled_img %tex
+        %texel = OpImageSampleImplicitLod %v4f32 %tex_value %uv_value
+        OpStore %color %texel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    // Since SPIR-V 1.4, the interface lists all statically used variables,
+    // which are exactly those reflected without `ref_all_rscs`.
+    assert_eq!(entry.interface_var_ids().len(), 3);
+    assert_eq!(entry.interface_var_ids().len(), entry.vars.len());
+    let mut ids = entry.interface_var_ids().to_owned();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+}
+#[test]
+fn test_gen_unique_names_stable() {
+    // Debug names are chosen to collide with the generated names of the
+    // first few IDs.
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %Names "Names"
+        OpMemberName %Names 0 "type_1"
+        OpMemberName %Names 1 "type_2"
+        OpMemberName %Names 2 "type_3"
+        OpMemberName %Names 3 "type_4"
+        OpMemberName %Names 4 "type_5"
+        OpMemberName %Names 5 "type_6"
+        OpMemberName %Names 6 "type_7"
+        OpMemberName %Names 7 "type_8"
+        OpMemberName %Names 8 "type_9"
+        OpMemberName %Names 9 "type_10"
+        OpMemberName %Names 10 "type_11"
+        OpMemberName %Names 11 "type_12"
+        OpMemberName %Names 12 "type_13"
+        OpMemberName %Names 13 "type_14"
+        OpMemberName %Names 14 "type_15"
+        OpMemberName %Names 15 "type_16"
+        OpDecorate %Outer Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %Names = OpTypeStruct %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32 %f32
+        %Inner1 = OpTypeStruct %f32
+        %Inner2 = OpTypeStruct %f32
+        %Outer = OpTypeStruct %Inner1 %Inner2 %Names
+        %ptr_Outer = OpTypePointer Uniform %Outer
+        %data = OpVariable %ptr_Outer Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let names = || {
+        let entry = ReflectConfig::new()
+            .spv(SPV)
+            .ref_all_rscs(true)
+            .gen_unique_names(true)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap();
+        let var = entry.descriptors().next().unwrap();
+        let outer = var.ty().as_struct().unwrap();
+        let mut names = vec![var.name().unwrap().to_owned()];
+        names.push(outer.name.clone().unwrap());
+        for member in outer.members.iter() {
+            names.push(member.name.clone().unwrap());
+            names.push(member.ty.as_struct().unwrap().name.clone().unwrap());
+        }
+        names
+    };
+    let first = names();
+    assert_eq!(first, names());
+
+    let debug_names = (1..=16).map(|i| format!("type_{}", i)).collect::<Vec<_>>();
+    // Variable, outer struct, and the members and struct types of `Inner1`
+    // and `Inner2` are generated.
+    let generated = [0, 1, 2, 3, 4, 5]
+        .iter()
+        .map(|i| first[*i].as_str())
+        .collect::<Vec<_>>();
+    for name in generated.iter() {
+        assert!(!debug_names.iter().any(|x| x == name), "{} collides", name);
+    }
+    let mut deduped = generated.clone();
+    deduped.sort();
+    deduped.dedup();
+    assert_eq!(deduped.len(), generated.len());
+    assert_ne!(first[3], first[5]);
+    assert_eq!(first[7], "Names");
+}
+#[test]
+fn test_checked_header() {
+    use crate::binary::SpirvHeaderExt;
+    // SPIR-V 1.5 generated by glslang version 11.
+    let words = [0x07230203_u32, 0x00010500, 0x0008000b, 42, 0];
+    for bytes in [
+        words
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<u8>>(),
+        words
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<u8>>(),
+    ] {
+        let spv = SpirvBinary::try_from_bytes(&bytes).unwrap();
+        let header = spv.checked_header().unwrap();
+        assert_eq!(header.version_tuple(), (1, 5));
+        assert_eq!(header.generator_tool(), 8);
+        assert_eq!(header.generator_version(), 11);
+        assert_eq!(header.bound, 42);
+        assert_eq!(header.schema, 0);
+    }
+
+    let swapped = words.iter().map(|x| x.swap_bytes()).collect::<Vec<_>>();
+    let err = SpirvBinary::from(swapped).checked_header().err().unwrap();
+    assert!(err.to_string().contains("byte-swapped"), "{}", err);
+    let err = SpirvBinary::from(vec![0_u32; 5])
+        .checked_header()
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("magic number"), "{}", err);
+    let err = SpirvBinary::from(&words[..3])
+        .checked_header()
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("truncated"), "{}", err);
+}
+#[test]
+fn test_xfb_outputs() {
+    // layout(xfb_buffer = 0, xfb_stride = 32) out;
+    // layout(location = 0, xfb_offset = 16) out vec4 color;
+    // layout(location = 1, xfb_buffer = 1, xfb_stride = 24) out Block {
+    //     layout(xfb_offset = 8) vec2 uv;
+    //     layout(xfb_offset = 16) vec2 st;
+    // } block;
+    // layout(location = 3) out vec4 not_captured;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Geometry
+        OpCapability TransformFeedback
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Geometry %main "main" %color %block %not_captured
+        OpExecutionMode %main Xfb
+        OpExecutionMode %main InputPoints
+        OpExecutionMode %main Invocations 1
+        OpExecutionMode %main OutputPoints
+        OpExecutionMode %main OutputVertices 1
+        OpDecorate %color Location 0
+        OpDecorate %color XfbBuffer 0
+        OpDecorate %color XfbStride 32
+        OpDecorate %color Offset 16
+        OpDecorate %Block Block
+        OpMemberDecorate %Block 0 Offset 8
+        OpMemberDecorate %Block 1 Offset 16
+        OpDecorate %block Location 1
+        OpDecorate %block XfbBuffer 1
+        OpDecorate %block XfbStride 24
+        OpDecorate %not_captured Location 3
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v2f32 = OpTypeVector %f32 2
+        %v4f32 = OpTypeVector %f32 4
+        %Block = OpTypeStruct %v2f32 %v2f32
+        %ptr_out_v4f32 = OpTypePointer Output %v4f32
+        %ptr_out_Block = OpTypePointer Output %Block
+        %color = OpVariable %ptr_out_v4f32 Output
+        %block = OpVariable %ptr_out_Block Output
+        %not_captured = OpVariable %ptr_out_v4f32 Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        geom,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let xfb_of = |location| {
+        let var = entry
+            .vars
+            .iter()
+            .find(|x| Locator::from(*x) == Locator::Output(InterfaceLocation::new(location, 0)))
+            .unwrap();
+        entry.xfb(var)
+    };
+    use crate::entry_point::XfbInfo;
+    assert_eq!(
+        xfb_of(0),
+        Some(XfbInfo {
+            buffer: 0,
+            stride: 32,
+            offset: 16
+        })
+    );
+    assert_eq!(
+        xfb_of(1),
+        Some(XfbInfo {
+            buffer: 1,
+            stride: 24,
+            offset: 8
+        })
+    );
+    assert_eq!(xfb_of(3), None);
+    let block = entry
+        .vars
+        .iter()
+        .find(|x| Locator::from(*x) == Locator::Output(InterfaceLocation::new(1, 0)))
+        .unwrap();
+    let offsets = block
+        .ty()
+        .as_struct()
+        .unwrap()
+        .members
+        .iter()
+        .map(|x| x.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![Some(8), Some(16)]);
+}
+#[test]
+fn test_reflect_inspect_try_by() {
+    use std::ops::ControlFlow;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 8 4 1
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %Data = OpTypeStruct %u32
+        %ptr_Data = OpTypePointer StorageBuffer %Data
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %data = OpVariable %ptr_Data StorageBuffer
+        %c0 = OpConstant %u32 0
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %ptr = OpAccessChain %ptr_u32 %data %c0
+        OpStore %ptr %c0
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut ops = Vec::new();
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect_inspect_try_by(|_, instr| {
+            ops.push(instr.op());
+            if instr.op() == crate::spirv::Op::Function {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(ops, vec![crate::spirv::Op::Function]);
+    // Details from the global declarations are still reflected, but the
+    // unparsed function body is not.
+    assert_eq!(entry.name, "main");
+    assert_eq!(entry.local_size_u32(), Some([8, 4, 1]));
+    assert_eq!(entry.descriptors().count(), 0);
+
+    let mut nop = 0;
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect_inspect_try_by(|_, _| {
+            nop += 1;
+            ControlFlow::Continue(())
+        })
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(nop, 6);
+    assert_eq!(entry.descriptors().count(), 1);
+}
+
+#[test]
+fn test_descriptor_type_to_vk() {
+    use ty::AccessType;
+    let cases = [
+        (DescriptorType::Sampler(), 0),
+        (DescriptorType::CombinedImageSampler(), 1),
+        (DescriptorType::SampledImage(), 2),
+        (DescriptorType::StorageImage(AccessType::ReadOnly), 3),
+        (DescriptorType::StorageImage(AccessType::ReadWrite), 3),
+        (DescriptorType::UniformTexelBuffer(), 4),
+        (DescriptorType::StorageTexelBuffer(AccessType::WriteOnly), 5),
+        (DescriptorType::UniformBuffer(), 6),
+        (DescriptorType::StorageBuffer(AccessType::ReadOnly), 7),
+        (DescriptorType::StorageBuffer(AccessType::ReadWrite), 7),
+        (DescriptorType::InputAttachment(3), 10),
+        (DescriptorType::AccelStruct(), 1000150000),
+    ];
+    for (desc_ty, vk) in cases.iter() {
+        assert_eq!(desc_ty.to_vk(), *vk, "{:?}", desc_ty);
+    }
+}
+
+#[test]
+fn test_array_flatten() {
+    use crate::layout::ArrayTypeExt;
+    let arr_ty = |element_ty: Type, nelement: Option<u32>, stride: Option<usize>| ty::ArrayType {
+        element_ty: Box::new(element_ty),
+        nelement,
+        stride,
+    };
+    let vec4 = Type::Vector(ty::VectorType {
+        scalar_ty: ty::ScalarType::f32(),
+        nscalar: 4,
+    });
+    // `vec4 m[2][3]`.
+    let inner = arr_ty(vec4.clone(), Some(3), Some(16));
+    let outer = arr_ty(Type::Array(inner.clone()), Some(2), Some(48));
+    assert_eq!(outer.flatten(), Some((vec4.clone(), 6, 16)));
+    assert_eq!(inner.flatten(), Some((vec4.clone(), 3, 16)));
+    // Padded between the inner arrays.
+    let padded = arr_ty(Type::Array(inner.clone()), Some(2), Some(64));
+    assert_eq!(padded.flatten(), None);
+    // Runtime-sized.
+    let runtime = arr_ty(Type::Array(inner), None, Some(48));
+    assert_eq!(runtime.flatten(), None);
+    // No explicit stride.
+    let unstrided = arr_ty(
+        Type::Array(arr_ty(vec4.clone(), Some(3), None)),
+        Some(2),
+        Some(48),
+    );
+    assert_eq!(unstrided.flatten(), None);
+}
+
+#[test]
+fn test_subpass_data_ty() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability InputAttachment
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %out
+        OpExecutionMode %main OriginUpperLeft
+        OpName %color "color"
+        OpName %index "index"
+        OpName %layers "layers"
+        OpDecorate %color DescriptorSet 0
+        OpDecorate %color Binding 0
+        OpDecorate %color InputAttachmentIndex 0
+        OpDecorate %index DescriptorSet 0
+        OpDecorate %index Binding 1
+        OpDecorate %index InputAttachmentIndex 1
+        OpDecorate %layers DescriptorSet 0
+        OpDecorate %layers Binding 2
+        OpDecorate %layers InputAttachmentIndex 2
+        OpDecorate %out Location 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %i32 = OpTypeInt 32 1
+        %u32 = OpTypeInt 32 0
+        %v2i32 = OpTypeVector %i32 2
+        %v4f32 = OpTypeVector %f32 4
+        %v4u32 = OpTypeVector %u32 4
+        %zero = OpConstant %i32 0
+        %two = OpConstant %u32 2
+        %coord = OpConstantComposite %v2i32 %zero %zero
+        %subpass = OpTypeImage %f32 SubpassData 0 0 0 2 Unknown
+        %usubpass_ms = OpTypeImage %u32 SubpassData 0 0 1 2 Unknown
+        %subpass_arr = OpTypeArray %subpass %two
+        %ptr_subpass = OpTypePointer UniformConstant %subpass
+        %ptr_usubpass_ms = OpTypePointer UniformConstant %usubpass_ms
+        %ptr_subpass_arr = OpTypePointer UniformConstant %subpass_arr
+        %ptr_out = OpTypePointer Output %v4f32
+        %color = OpVariable %ptr_subpass UniformConstant
+        %index = OpVariable %ptr_usubpass_ms UniformConstant
+        %layers = OpVariable %ptr_subpass_arr UniformConstant
+        %out = OpVariable %ptr_out Output
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %color_img = OpLoad %subpass %color
+        %color_texel = OpImageRead %v4f32 %color_img %coord
+        %index_img = OpLoad %usubpass_ms %index
+        %index_texel = OpImageRead %v4u32 %index_img %coord Sample %zero
+        %layer_ptr = OpAccessChain %ptr_subpass %layers %zero
+        %layer_img = OpLoad %subpass %layer_ptr
+        %layer_texel = OpImageRead %v4f32 %layer_img %coord
+        %sum = OpFAdd %v4f32 %color_texel %layer_texel
+        OpStore %out %sum
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let subpass_data_ty = |scalar_ty: ty::ScalarType, is_multisampled: bool| {
+        Type::SubpassData(ty::SubpassDataType {
+            scalar_ty,
+            is_multisampled,
+        })
+    };
+    let descs = entry
+        .descriptors()
+        .map(|x| match x {
+            Variable::Descriptor {
+                desc_bind,
+                desc_ty,
+                ty,
+                nbind,
+                ..
+            } => (*desc_bind, desc_ty.clone(), ty.clone(), *nbind),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        descs,
+        vec![
+            (
+                DescriptorBinding::new(0, 0),
+                DescriptorType::InputAttachment(0),
+                subpass_data_ty(ty::ScalarType::f32(), false),
+                1,
+            ),
+            (
+                DescriptorBinding::new(0, 1),
+                DescriptorType::InputAttachment(1),
+                subpass_data_ty(ty::ScalarType::u32(), true),
+                1,
+            ),
+            (
+                DescriptorBinding::new(0, 2),
+                DescriptorType::InputAttachment(2),
+                subpass_data_ty(ty::ScalarType::f32(), false),
+                2,
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_reflect_with_usage() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %Data Block
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %used DescriptorSet 0
+        OpDecorate %used Binding 0
+        OpDecorate %unused DescriptorSet 0
+        OpDecorate %unused Binding 1
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %zero = OpConstant %u32 0
+        %Data = OpTypeStruct %u32
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %used = OpVariable %ptr_data StorageBuffer
+        %unused = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %main_label = OpLabel
+        %call = OpFunctionCall %void %store
+        OpReturn
+        OpFunctionEnd
+        %store = OpFunction %void None %fn
+        %store_label = OpLabel
+        %ptr = OpAccessChain %ptr_u32 %used %zero
+        OpStore %ptr %zero
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let (entry, usage) = ReflectConfig::new()
+        .spv(SPV)
+        .reflect_with_usage()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.descriptors().count(), 1);
+    assert_eq!(usage.descriptors.len(), 2);
+    let used = usage.usage(DescriptorBinding::new(0, 0)).unwrap();
+    assert!(used.is_reachable);
+    assert_eq!(used.func_ids.len(), 1);
+    let unused = usage.usage(DescriptorBinding::new(0, 1)).unwrap();
+    assert!(!unused.is_reachable);
+    assert!(unused.func_ids.is_empty());
+    assert!(usage.usage(DescriptorBinding::new(0, 2)).is_none());
+
+    // The usage is the same regardless of `ref_all_rscs`.
+    let (entry, usage2) = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect_with_usage()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.descriptors().count(), 2);
+    assert_eq!(usage, usage2);
+}
+
+#[test]
+fn test_image_texel() {
+    use crate::descriptor::ImageTexelExt;
+    use crate::display::TypeDisplayExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %a DescriptorSet 0
+        OpDecorate %a Binding 0
+        OpDecorate %b DescriptorSet 0
+        OpDecorate %b Binding 1
+        OpDecorate %c DescriptorSet 0
+        OpDecorate %c Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %i32 = OpTypeInt 32 1
+        %uimg = OpTypeImage %u32 2D 0 0 0 1 Unknown
+        %usampler = OpTypeSampledImage %uimg
+        %r32f = OpTypeImage %f32 2D 0 0 0 2 R32f
+        %rgba8i = OpTypeImage %i32 2D 0 0 0 2 Rgba8i
+        %ptr_usampler = OpTypePointer UniformConstant %usampler
+        %ptr_r32f = OpTypePointer UniformConstant %r32f
+        %ptr_rgba8i = OpTypePointer UniformConstant %rgba8i
+        %a = OpVariable %ptr_usampler UniformConstant
+        %b = OpVariable %ptr_r32f UniformConstant
+        %c = OpVariable %ptr_rgba8i UniformConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %a_ = OpLoad %usampler %a
+        %b_ = OpLoad %r32f %b
+        %c_ = OpLoad %rgba8i %c
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let ty_of = |binding: u32| {
+        entry
+            .descriptors()
+            .find_map(|x| match x {
+                Variable::Descriptor { desc_bind, ty, .. }
+                    if *desc_bind == DescriptorBinding::new(0, binding) =>
+                {
+                    Some(ty.clone())
+                }
+                _ => None,
+            })
+            .unwrap()
+    };
+    match ty_of(0) {
+        Type::CombinedImageSampler(x) => {
+            assert!(x.is_uint());
+            assert!(!x.is_sint());
+            assert!(!x.is_float());
+            assert_eq!(x.texel_component_count(), Some(4));
+        }
+        _ => panic!(),
+    }
+    match ty_of(1) {
+        Type::StorageImage(x) => {
+            assert!(x.is_float());
+            assert_eq!(x.texel_component_count(), Some(1));
+        }
+        _ => panic!(),
+    }
+    match ty_of(2) {
+        Type::StorageImage(x) => {
+            assert!(x.is_sint());
+            assert!(!x.is_uint());
+            assert_eq!(x.texel_component_count(), Some(4));
+        }
+        _ => panic!(),
+    }
+    let unknown = ty::StorageImageType {
+        dim: crate::spirv::Dim::Dim2D,
+        is_array: false,
+        is_multisampled: false,
+        fmt: crate::spirv::ImageFormat::Unknown,
+    };
+    assert!(!unknown.is_float());
+    assert!(!unknown.is_sint());
+    assert!(!unknown.is_uint());
+    assert_eq!(unknown.texel_component_count(), None);
+    let packed = ty::StorageImageType {
+        fmt: crate::spirv::ImageFormat::Rgb10a2ui,
+        ..unknown.clone()
+    };
+    assert!(packed.is_uint());
+    assert!(!packed.is_sint());
+    let packed = Type::StorageImage(packed);
+    assert_eq!(format!("{:#}", packed.display()), "uimage2D");
+    let r64 = ty::StorageImageType {
+        fmt: crate::spirv::ImageFormat::R64i,
+        ..unknown
+    };
+    assert!(r64.is_sint());
+    assert!(!r64.is_float());
+}
+
+#[test]
+fn test_builtin_members() {
+    use crate::spirv::{BuiltIn, StorageClass};
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability ClipDistance
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main" %per_vertex
+        OpName %PerVertex "gl_PerVertex"
+        OpMemberDecorate %PerVertex 0 BuiltIn Position
+        OpMemberDecorate %PerVertex 1 BuiltIn PointSize
+        OpMemberDecorate %PerVertex 2 BuiltIn ClipDistance
+        OpDecorate %PerVertex Block
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %v4f32 = OpTypeVector %f32 4
+        %one = OpConstant %u32 1
+        %zero = OpConstant %u32 0
+        %f32_0 = OpConstant %f32 0
+        %clip_arr = OpTypeArray %f32 %one
+        %PerVertex = OpTypeStruct %v4f32 %f32 %clip_arr
+        %ptr_per_vertex = OpTypePointer Output %PerVertex
+        %ptr_v4f32 = OpTypePointer Output %v4f32
+        %per_vertex = OpVariable %ptr_per_vertex Output
+        %pos = OpConstantComposite %v4f32 %f32_0 %f32_0 %f32_0 %f32_0
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %ptr = OpAccessChain %ptr_v4f32 %per_vertex %zero
+        OpStore %ptr %pos
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(
+        entry.info.builtin_members,
+        vec![
+            (StorageClass::Output, 0, BuiltIn::Position),
+            (StorageClass::Output, 1, BuiltIn::PointSize),
+            (StorageClass::Output, 2, BuiltIn::ClipDistance),
+        ]
+    );
+    assert_eq!(
+        entry.member_builtin(StorageClass::Output, 1),
+        Some(BuiltIn::PointSize)
+    );
+    assert_eq!(entry.member_builtin(StorageClass::Output, 3), None);
+    assert_eq!(entry.member_builtin(StorageClass::Input, 0), None);
+}
+
+#[test]
+fn test_vk_stage_flags() {
+    let cases = [
+        (ExecutionModel::Vertex, 0x1),
+        (ExecutionModel::TessellationControl, 0x2),
+        (ExecutionModel::TessellationEvaluation, 0x4),
+        (ExecutionModel::Geometry, 0x8),
+        (ExecutionModel::Fragment, 0x10),
+        (ExecutionModel::GLCompute, 0x20),
+        (ExecutionModel::TaskEXT, 0x40),
+        (ExecutionModel::TaskNV, 0x40),
+        (ExecutionModel::MeshEXT, 0x80),
+        (ExecutionModel::MeshNV, 0x80),
+        (ExecutionModel::RayGenerationKHR, 0x100),
+        (ExecutionModel::AnyHitKHR, 0x200),
+        (ExecutionModel::ClosestHitKHR, 0x400),
+        (ExecutionModel::MissKHR, 0x800),
+        (ExecutionModel::IntersectionKHR, 0x1000),
+        (ExecutionModel::CallableKHR, 0x2000),
+        (ExecutionModel::Kernel, 0),
+    ];
+    for (exec_model, flags) in cases.iter() {
+        assert_eq!(exec_model.vk_stage_flags(), *flags, "{:?}", exec_model);
+    }
+
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpEntryPoint Fragment %frag "frag"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpExecutionMode %frag OriginUpperLeft
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %main_label = OpLabel
+        OpReturn
+        OpFunctionEnd
+        %frag = OpFunction %void None %fn
+        %frag_label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut flags = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .iter()
+        .map(|x| (x.name.clone(), x.vk_stage_flags()))
+        .collect::<Vec<_>>();
+    flags.sort();
+    assert_eq!(
+        flags,
+        vec![("frag".to_owned(), 0x10), ("main".to_owned(), 0x20)]
+    );
+}
+
+#[test]
+fn test_composite_constant_value() {
+    use crate::value::CompositeValue;
+    // `layout(constant_id = 0) const int x = 3;`
+    // `const ivec2 myVec = ivec2(x, 5);`
+    // `float data[myVec.x];`
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %x SpecId 0
+        OpDecorate %arr ArrayStride 4
+        OpMemberDecorate %Data 0 Offset 0
+        OpDecorate %Data Block
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %i32 = OpTypeInt 32 1
+        %v2i32 = OpTypeVector %i32 2
+        %x = OpSpecConstant %i32 3
+        %five = OpConstant %i32 5
+        %my_vec = OpSpecConstantComposite %v2i32 %x %five
+        ; CompositeExtract.
+        %len = OpSpecConstantOp %i32 81 %my_vec 0
+        %arr = OpTypeArray %f32 %len
+        %Data = OpTypeStruct %arr
+        %ptr_data = OpTypePointer StorageBuffer %Data
+        %data = OpVariable %ptr_data StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut my_vec = None;
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .specialize(0, ConstantValue::S32(4))
+        .reflect_inspect_by(|itm, instr| {
+            if instr.op() == crate::spirv::Op::Function {
+                // `%my_vec` is the only composite.
+                my_vec = (1..64).find_map(|id| {
+                    itm.constant_value(id)
+                        .ok()
+                        .filter(|x| x.constituents().is_some())
+                });
+                assert!(itm.constant_value(1000).is_err());
+            }
+        })
+        .unwrap()
+        .pop()
+        .unwrap();
+    let my_vec = my_vec.unwrap();
+    assert_eq!(
+        my_vec,
+        CompositeValue::Composite(vec![
+            CompositeValue::Scalar(ConstantValue::S32(4)),
+            CompositeValue::Scalar(ConstantValue::S32(5)),
+        ])
+    );
+    assert_eq!(
+        my_vec.constituents().unwrap()[1].as_scalar(),
+        Some(&ConstantValue::S32(5))
+    );
+    assert_eq!(my_vec.as_scalar(), None);
+    let nelement = entry.descriptors().find_map(|x| match x.ty() {
+        Type::Struct(struct_ty) => match &struct_ty.members[0].ty {
+            Type::Array(arr_ty) => arr_ty.nelement,
+            _ => None,
+        },
+        _ => None,
+    });
+    assert_eq!(nelement, Some(4));
+}
+
+#[test]
+fn test_merge_push_constant_ranges() {
+    use crate::entry_point::PushConstantRange;
+    // `layout(push_constant) uniform Push { mat4 mvp; vec4 color; }` where
+    // each stage only declares the member it uses.
+    static VERT: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Vertex %main "main"
+        OpDecorate %Push Block
+        OpMemberDecorate %Push 0 Offset 0
+        OpMemberDecorate %Push 0 ColMajor
+        OpMemberDecorate %Push 0 MatrixStride 16
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %mat4 = OpTypeMatrix %v4f32 4
+        %Push = OpTypeStruct %mat4
+        %ptr_push = OpTypePointer PushConstant %Push
+        %push = OpVariable %ptr_push PushConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        vert,
+        spvasm,
+        vulkan1_2
+    );
+    static FRAG: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main"
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %Push Block
+        OpMemberDecorate %Push 0 Offset 64
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v4f32 = OpTypeVector %f32 4
+        %Push = OpTypeStruct %v4f32
+        %ptr_push = OpTypePointer PushConstant %Push
+        %push = OpVariable %ptr_push PushConstant
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        frag,
+        spvasm,
+        vulkan1_2
+    );
+    let pipeline = ReflectConfig::new()
+        .spv(VERT)
+        .add_spv(FRAG)
+        .ref_all_rscs(true)
+        .reflect_merged()
+        .unwrap();
+    assert_eq!(
+        pipeline.push_constant_ranges,
+        vec![
+            PushConstantRange {
+                stage_flags: 0x1,
+                offset: 0,
+                size: 64,
+            },
+            PushConstantRange {
+                stage_flags: 0x10,
+                offset: 64,
+                size: 16,
+            },
+        ]
+    );
+
+    // Entry points of a same stage are unioned.
+    let pipeline = ReflectConfig::new()
+        .spv(VERT)
+        .add_spv(VERT)
+        .ref_all_rscs(true)
+        .reflect_merged()
+        .unwrap();
+    assert_eq!(
+        pipeline.push_constant_ranges,
+        vec![PushConstantRange {
+            stage_flags: 0x1,
+            offset: 0,
+            size: 64,
+        }]
+    );
+}
+
+#[test]
+fn test_interface_location_ord() {
+    use std::collections::{BTreeMap, HashSet};
+    let mut locations = vec![
+        InterfaceLocation::new(2, 0),
+        InterfaceLocation::new(0, 3),
+        InterfaceLocation::new(1, 1),
+        InterfaceLocation::new(0, 0),
+        InterfaceLocation::new(1, 0),
+    ];
+    locations.sort();
+    assert_eq!(
+        locations,
+        vec![
+            InterfaceLocation::new(0, 0),
+            InterfaceLocation::new(0, 3),
+            InterfaceLocation::new(1, 0),
+            InterfaceLocation::new(1, 1),
+            InterfaceLocation::new(2, 0),
+        ]
+    );
+    let map = locations
+        .iter()
+        .rev()
+        .map(|x| (*x, x.loc() * 4 + x.comp()))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(
+        map.values().copied().collect::<Vec<_>>(),
+        vec![0, 3, 4, 5, 8]
+    );
+    let set = locations.iter().copied().collect::<HashSet<_>>();
+    assert!(set.contains(&InterfaceLocation::new(1, 1)));
+    assert!(!set.contains(&InterfaceLocation::new(1, 2)));
+
+    let mut locators = vec![
+        Locator::Output(InterfaceLocation::new(0, 0)),
+        Locator::Input(InterfaceLocation::new(1, 0)),
+        Locator::Input(InterfaceLocation::new(0, 2)),
+    ];
+    locators.sort();
+    assert_eq!(
+        locators,
+        vec![
+            Locator::Input(InterfaceLocation::new(0, 2)),
+            Locator::Input(InterfaceLocation::new(1, 0)),
+            Locator::Output(InterfaceLocation::new(0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_capability_error() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Addresses
+        OpCapability Kernel
+        OpMemoryModel Physical64 OpenCL
+        OpEntryPoint Kernel %main "main"
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let err = ReflectConfig::new().spv(SPV).reflect().err().unwrap();
+    assert!(err.to_string().contains("Kernel"), "{}", err);
+
+    static SPV_ADDR: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpCapability Addresses
+        OpMemoryModel Physical64 GLSL450
+        OpEntryPoint GLCompute %main "main"
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let err = ReflectConfig::new().spv(SPV_ADDR).reflect().err().unwrap();
+    assert!(err.to_string().contains("Physical64"), "{}", err);
+}
+
+#[test]
+fn test_remap_ids() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        %100 = OpExtInstImport "GLSL.std.450"
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %400 "main"
+        OpExecutionMode %400 LocalSize 1 1 1
+        OpName %400 "main"
+        OpName %300 "data"
+        OpDecorate %210 Block
+        OpMemberDecorate %210 0 Offset 0
+        OpMemberDecorate %210 1 Offset 4
+        OpDecorate %300 DescriptorSet 1
+        OpDecorate %300 Binding 2
+        %200 = OpTypeVoid
+        %201 = OpTypeFunction %200
+        %202 = OpTypeInt 32 0
+        %203 = OpTypeFloat 32
+        %204 = OpConstant %202 0
+        %205 = OpConstant %202 1
+        %210 = OpTypeStruct %202 %203
+        %211 = OpTypePointer StorageBuffer %210
+        %212 = OpTypePointer StorageBuffer %202
+        %213 = OpTypePointer StorageBuffer %203
+        %300 = OpVariable %211 StorageBuffer
+        %400 = OpFunction %200 None %201
+        %500 = OpLabel
+        %501 = OpAccessChain %212 %300 %204
+        %502 = OpLoad %202 %501 Aligned 4
+        OpSelectionMerge %600 None
+        OpSwitch %502 %600 1 %510 7 %510
+        %510 = OpLabel
+        %511 = OpAccessChain %213 %300 %205
+        %512 = OpLoad %203 %511
+        %513 = OpExtInst %203 %100 31 %512
+        OpStore %511 %513
+        OpBranch %600
+        %600 = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let spv = SpirvBinary::from(SPV);
+    let remapped = spv.remap_ids().unwrap();
+    assert!(remapped.header().unwrap().bound < spv.header().unwrap().bound);
+    assert_eq!(remapped.words().len(), SPV.len());
+    assert_eq!(remapped.words()[..3], SPV[..3]);
+    remapped.checked_instrs().unwrap();
+    assert_eq!(remapped.remap_ids().unwrap().words(), remapped.words());
+
+    let reflect = |spv: SpirvBinary| {
+        ReflectConfig::new()
+            .spv(spv)
+            .reflect()
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+    let entry = reflect(spv);
+    let remapped_entry = reflect(remapped);
+    assert!(crate::diff::diff(&entry, &remapped_entry).is_empty());
+    assert_eq!(entry.name, remapped_entry.name);
+    let desc = remapped_entry.descriptors().next().unwrap();
+    assert_eq!(desc.name(), Some("data"));
+    assert_eq!(
+        format!("{:?}", entry.descriptors().next().unwrap()),
+        format!("{:?}", desc)
+    );
+}
+
+#[test]
+fn test_scalar_leaves() {
+    use crate::layout::TypeLayoutExt;
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpMemberDecorate %buf_ty 1 Offset 12
+        OpMemberDecorate %buf_ty 2 Offset 16
+        OpMemberDecorate %buf_ty 2 ColMajor
+        OpMemberDecorate %buf_ty 2 MatrixStride 16
+        OpDecorate %buf DescriptorSet 0
+        OpDecorate %buf Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %v2f32 = OpTypeVector %f32 2
+        %v3f32 = OpTypeVector %f32 3
+        %mat2 = OpTypeMatrix %v2f32 2
+        %buf_ty = OpTypeStruct %v3f32 %f32 %mat2
+        %ptr_buf = OpTypePointer Uniform %buf_ty
+        %buf = OpVariable %ptr_buf Uniform
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .ref_all_rscs(true)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    let ty = match entry.descriptors().next().unwrap() {
+        Variable::Descriptor { ty, .. } => ty.clone(),
+        _ => unreachable!(),
+    };
+    let leaves = ty.scalar_leaves();
+    let offsets = leaves.iter().map(|(offset, _)| *offset).collect::<Vec<_>>();
+    assert_eq!(offsets, [0, 4, 8, 12, 16, 20, 32, 36]);
+    assert!(leaves
+        .iter()
+        .all(|(_, scalar_ty)| *scalar_ty == ty::ScalarType::Float { bits: 32 }));
+
+    let struct_ty = match &ty {
+        Type::Struct(struct_ty) => struct_ty,
+        _ => unreachable!(),
+    };
+    let prefix = Type::Struct(ty::StructType {
+        name: None,
+        members: struct_ty.members[..2].to_vec(),
+    });
+    assert_eq!(prefix.scalar_leaves().len(), 4);
+    assert!(Type::Sampler(ty::SamplerType {}).scalar_leaves().is_empty());
+    let mut unlaid = struct_ty.clone();
+    unlaid.members[1].offset = None;
+    assert!(Type::Struct(unlaid).scalar_leaves().is_empty());
+}
+
+#[test]
+fn test_specialize_bool() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %cond SpecId 3
+        OpDecorate %arr ArrayStride 4
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpDecorate %buf DescriptorSet 0
+        OpDecorate %buf Binding 0
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %bool = OpTypeBool
+        %u32 = OpTypeInt 32 0
+        %f32 = OpTypeFloat 32
+        %u32_4 = OpConstant %u32 4
+        %u32_8 = OpConstant %u32 8
+        %cond = OpSpecConstantTrue %bool
+        %not_cond = OpSpecConstantOp %bool 168 %cond
+        %len = OpSpecConstantOp %u32 169 %not_cond %u32_8 %u32_4
+        %arr = OpTypeArray %f32 %len
+        %buf_ty = OpTypeStruct %arr
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %buf = OpVariable %ptr_buf StorageBuffer
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let nelement = |cfg: &mut ReflectConfig| {
+        let entry = cfg.reflect().unwrap().pop().unwrap();
+        let nelement = match entry.descriptors().next().unwrap() {
+            Variable::Descriptor {
+                ty: Type::Struct(struct_ty),
+                ..
+            } => match &struct_ty.members[0].ty {
+                Type::Array(arr_ty) => arr_ty.nelement,
+                ty => panic!("unexpected member type {:?}", ty),
+            },
+            var => panic!("unexpected variable {:?}", var),
+        };
+        nelement
+    };
+    let mut cfg = ReflectConfig::new();
+    cfg.spv(SPV).ref_all_rscs(true);
+    assert_eq!(
+        nelement(cfg.clone().specialize(3, ConstantValue::Bool(true))),
+        Some(4)
+    );
+    assert_eq!(
+        nelement(cfg.clone().specialize(3, ConstantValue::Bool(false))),
+        Some(8)
+    );
+    assert_eq!(
+        nelement(cfg.clone().specialize(3, ConstantValue::from(&[0u32][..]))),
+        Some(8)
+    );
+    let err = cfg
+        .clone()
+        .specialize(3, ConstantValue::U32(1))
+        .reflect()
+        .unwrap_err();
+    assert!(err.to_string().contains("cannot be specialized"));
+}
+
+#[test]
+fn test_workgroup_size_spec_ids() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionModeId %main LocalSizeId %x %u32_1 %z
+        OpDecorate %x SpecId 5
+        OpDecorate %z SpecId 7
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %u32_1 = OpConstant %u32 1
+        %x = OpSpecConstant %u32 64
+        %z = OpSpecConstant %u32 2
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.workgroup_size_spec_ids(), [Some(5), None, Some(7)]);
+    assert_eq!(entry.local_size_u32(), Some([64, 1, 2]));
+
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .specialize(7, ConstantValue::U32(4))
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(entry.workgroup_size_spec_ids(), [Some(5), None, None]);
+    assert_eq!(entry.local_size_u32(), Some([64, 1, 4]));
+}
+
+#[test]
+fn test_atomic_accessed() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %buf_ty Block
+        OpMemberDecorate %buf_ty 0 Offset 0
+        OpMemberDecorate %buf_ty 1 Offset 4
+        OpDecorate %counter DescriptorSet 0
+        OpDecorate %counter Binding 0
+        OpDecorate %data DescriptorSet 0
+        OpDecorate %data Binding 1
+        OpDecorate %img DescriptorSet 0
+        OpDecorate %img Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %u32 = OpTypeInt 32 0
+        %s32 = OpTypeInt 32 1
+        %v2s32 = OpTypeVector %s32 2
+        %u32_0 = OpConstant %u32 0
+        %u32_1 = OpConstant %u32 1
+        %s32_0 = OpConstant %s32 0
+        %coord = OpConstantComposite %v2s32 %s32_0 %s32_0
+        %buf_ty = OpTypeStruct %u32 %u32
+        %ptr_buf = OpTypePointer StorageBuffer %buf_ty
+        %ptr_u32 = OpTypePointer StorageBuffer %u32
+        %counter = OpVariable %ptr_buf StorageBuffer
+        %data = OpVariable %ptr_buf StorageBuffer
+        %img_ty = OpTypeImage %u32 2D 0 0 0 2 R32ui
+        %ptr_img = OpTypePointer UniformConstant %img_ty
+        %img = OpVariable %ptr_img UniformConstant
+        %ptr_texel = OpTypePointer Image %u32
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %counter_ptr = OpAccessChain %ptr_u32 %counter %u32_1
+        %old = OpAtomicIAdd %u32 %counter_ptr %u32_1 %u32_0 %u32_1
+        %data_ptr = OpAccessChain %ptr_u32 %data %u32_0
+        OpStore %data_ptr %old
+        %texel = OpImageTexelPointer %ptr_texel %img %coord %u32_0
+        %old_texel = OpAtomicUMax %u32 %texel %u32_1 %u32_0 %old
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let entry = ReflectConfig::new()
+        .spv(SPV)
+        .reflect()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(
+        entry.info.atomic_accessed,
+        vec![DescriptorBinding::new(0, 0), DescriptorBinding::new(0, 2)]
+    );
+    let descs = entry.descriptors().collect::<Vec<_>>();
+    assert_eq!(descs.len(), 3);
+    for desc in descs {
+        let desc_bind = match desc {
+            Variable::Descriptor { desc_bind, .. } => *desc_bind,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            entry.is_atomic_accessed(desc),
+            desc_bind.bind() != 1,
+            "{:?}",
+            desc
+        );
+    }
+}
+
+#[test]
+fn test_walk_through_pointers() {
+    use crate::walk::Walk;
+    let member = |name: &str, offset, ty| ty::StructMember {
+        name: Some(name.to_owned()),
+        offset: Some(offset),
+        ty,
+        access_ty: AccessType::ReadWrite,
+    };
+    let ptr_to = |ty| {
+        Type::DevicePointer(ty::PointerType {
+            pointee_ty: Box::new(ty),
+            store_cls: crate::spirv::StorageClass::PhysicalStorageBuffer,
+        })
+    };
+    let node = |next| {
+        Type::Struct(ty::StructType {
+            name: Some("Node".to_owned()),
+            members: vec![
+                member("value", 0, Type::Scalar(ty::ScalarType::f32())),
+                member("next", 8, next),
+            ],
+        })
+    };
+    // A linked list node as is reflected, whose innermost link is a plain
+    // device address declared by `OpTypeForwardPointer`.
+    let ty = Type::Struct(ty::StructType {
+        name: Some("Buf".to_owned()),
+        members: vec![
+            member("count", 0, Type::Scalar(ty::ScalarType::u32())),
+            member(
+                "head",
+                8,
+                ptr_to(node(ptr_to(node(Type::DeviceAddress(
+                    ty::DeviceAddressType {},
+                ))))),
+            ),
+        ],
+    });
+    let routes = |walk: Walk| {
+        walk.map(|x| (format!("{:?}", x.sym), x.offset))
+            .collect::<Vec<_>>()
+    };
+    let route = |sym: &str, offset| (sym.to_owned(), offset);
+
+    assert_eq!(
+        routes(Walk::new(&ty).leaves_only()),
+        vec![route("[count]", 0), route("[head]", 8)]
+    );
+    assert_eq!(
+        routes(Walk::new(&ty).through_pointers().leaves_only()),
+        vec![
+            route("[count]", 0),
+            route("[head, value]", 0),
+            route("[head, next]", 8),
+        ]
+    );
+    let back_edge = Walk::new(&ty)
+        .through_pointers()
+        .leaves_only()
+        .last()
+        .unwrap();
+    assert!(back_edge.ty.is_device_pointer());
+    assert_eq!(back_edge.access_chain.len(), 2);
+    assert_eq!(
+        routes(Walk::new(&ty).through_pointers()),
+        vec![
+            route("[count]", 0),
+            route("[head, value]", 0),
+            route("[head, next]", 8),
+            route("[head]", 0),
+            route("[head]", 8),
+            route("[]", 0),
+        ]
+    );
+}
+#[test]
+fn test_scalar_type_ext() {
+    use crate::value::{ConstantValueExt, ScalarTypeExt};
+    let int = |bits, is_signed| ty::ScalarType::Integer { bits, is_signed };
+    let float = |bits| ty::ScalarType::Float { bits };
+
+    assert_eq!(int(8, true).matching_zero(), ConstantValue::S8(0));
+    assert_eq!(int(16, true).matching_zero(), ConstantValue::S16(0));
+    assert_eq!(int(32, true).matching_zero(), ConstantValue::S32(0));
+    assert_eq!(int(64, true).matching_zero(), ConstantValue::S64(0));
+    assert_eq!(int(8, false).matching_zero(), ConstantValue::U8(0));
+    assert_eq!(int(16, false).matching_zero(), ConstantValue::U16(0));
+    assert_eq!(int(32, false).matching_zero(), ConstantValue::U32(0));
+    assert_eq!(int(64, false).matching_zero(), ConstantValue::U64(0));
+    assert!(matches!(float(16).matching_zero(), ConstantValue::F16(x) if x.0.to_f64() == 0.0));
+    assert!(matches!(float(32).matching_zero(), ConstantValue::F32(x) if x.0 == 0.0));
+    assert!(matches!(float(64).matching_zero(), ConstantValue::F64(x) if x.0 == 0.0));
+    assert_eq!(
+        ty::ScalarType::Boolean.matching_zero(),
+        ConstantValue::Bool(false)
+    );
+    assert!(matches!(
+        ty::ScalarType::Void.matching_zero(),
+        ConstantValue::Typeless(x) if x.is_empty()
+    ));
+
+    assert_eq!(int(16, true).bit_width(), Some(16));
+    assert!(int(16, true).is_signed());
+    assert!(!int(16, false).is_signed());
+    assert!(!int(16, true).is_float());
+    assert_eq!(float(64).bit_width(), Some(64));
+    assert!(float(64).is_float());
+    assert_eq!(ty::ScalarType::Boolean.bit_width(), None);
+
+    let vec3 = Type::Vector(ty::VectorType {
+        scalar_ty: float(32),
+        nscalar: 3,
+    });
+    assert_eq!(vec3.bit_width(), Some(32));
+    assert!(vec3.is_float());
+    assert_eq!(vec3.matching_zero().to_f64(), Some(0.0));
+    let arr = Type::Array(ty::ArrayType {
+        element_ty: Box::new(vec3),
+        nelement: Some(2),
+        stride: Some(16),
+    });
+    assert_eq!(arr.bit_width(), None);
+    assert!(!arr.is_float());
+}
+#[test]
+fn test_inspect_resolve_type_constant() {
+    static SPV: &'static [u32] = inline_spirv!(
+        r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpDecorate %n SpecId 3
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %f32 = OpTypeFloat 32
+        %u32 = OpTypeInt 32 0
+        %v4f32 = OpTypeVector %f32 4
+        %ptr_v4f32 = OpTypePointer Function %v4f32
+        %ptr_u32 = OpTypePointer Function %u32
+        %n = OpSpecConstant %u32 7
+        %main = OpFunction %void None %fn
+        %label = OpLabel
+        %color = OpVariable %ptr_v4f32 Function
+        %count = OpVariable %ptr_u32 Function %n
+        OpReturn
+        OpFunctionEnd
+    "#,
+        comp,
+        spvasm,
+        vulkan1_2
+    );
+    let mut pointee_tys = Vec::new();
+    let mut init_values = Vec::new();
+    ReflectConfig::new()
+        .spv(SPV)
+        .specialize(3, ConstantValue::U32(4))
+        .reflect_inspect_by(|itm, instr| {
+            if instr.op() == crate::spirv::Op::Variable {
+                let ptr_ty_id = instr.as_ref()[1];
+                if let Some(Type::DevicePointer(ptr_ty)) = itm.resolve_type(ptr_ty_id) {
+                    pointee_tys.push((*ptr_ty.pointee_ty).clone());
+                }
+                if let Some(&init_id) = instr.as_ref().get(4) {
+                    init_values.push(itm.resolve_constant(init_id).cloned());
+                }
+                // Variables are not types or constants.
+                assert!(itm.resolve_type(instr.as_ref()[2]).is_none());
+                assert!(itm.resolve_constant(instr.as_ref()[2]).is_none());
+            }
+        })
+        .unwrap();
+    let vec4 = Type::Vector(ty::VectorType {
+        scalar_ty: ty::ScalarType::f32(),
+        nscalar: 4,
+    });
+    let u32_ty = Type::Scalar(ty::ScalarType::u32());
+    assert_eq!(pointee_tys, vec![vec4, u32_ty]);
+    assert_eq!(init_values, vec![Some(ConstantValue::U32(4))]);
+}
@@ -0,0 +1,198 @@
+//! Conversions and formatting of constant values of any width.
+use std::{convert::TryFrom, fmt};
+
+use crate::{
+    constant::ConstantValue,
+    ty::{ScalarType, Type},
+};
+
+/// Width-agnostic accessors of [`ConstantValue`]. Unlike `to_u32` and the
+/// like, values of all bit widths are accepted.
+pub trait ConstantValueExt {
+    /// Value of an integer or boolean as an unsigned integer. Returns `None`
+    /// for negative integers, floating-point numbers and typeless values.
+    fn to_u64(&self) -> Option<u64>;
+    /// Value of an integer or boolean as a signed integer. Returns `None` for
+    /// unsigned integers greater than `i64::MAX`, floating-point numbers and
+    /// typeless values.
+    fn to_i64(&self) -> Option<i64>;
+    /// Value of a number as a double-precision floating-point number. Large
+    /// 64-bit integers are rounded. Returns `None` for booleans and typeless
+    /// values.
+    fn to_f64(&self) -> Option<f64>;
+    /// Human-readable representation of the value. Numbers are printed as
+    /// is, and typeless values are printed as little-endian hexadecimals.
+    fn display(&self) -> DisplayConstantValue<'_>;
+}
+impl ConstantValueExt for ConstantValue {
+    fn to_u64(&self) -> Option<u64> {
+        match *self {
+            ConstantValue::Bool(x) => Some(x as u64),
+            ConstantValue::U8(x) => Some(x as u64),
+            ConstantValue::U16(x) => Some(x as u64),
+            ConstantValue::U32(x) => Some(x as u64),
+            ConstantValue::U64(x) => Some(x),
+            ConstantValue::S8(_)
+            | ConstantValue::S16(_)
+            | ConstantValue::S32(_)
+            | ConstantValue::S64(_) => u64::try_from(self.to_i64()?).ok(),
+            _ => None,
+        }
+    }
+    fn to_i64(&self) -> Option<i64> {
+        match *self {
+            ConstantValue::Bool(x) => Some(x as i64),
+            ConstantValue::S8(x) => Some(x as i64),
+            ConstantValue::S16(x) => Some(x as i64),
+            ConstantValue::S32(x) => Some(x as i64),
+            ConstantValue::S64(x) => Some(x),
+            ConstantValue::U8(_)
+            | ConstantValue::U16(_)
+            | ConstantValue::U32(_)
+            | ConstantValue::U64(_) => i64::try_from(self.to_u64()?).ok(),
+            _ => None,
+        }
+    }
+    fn to_f64(&self) -> Option<f64> {
+        match *self {
+            ConstantValue::F16(x) => Some(x.0.to_f64()),
+            ConstantValue::F32(x) => Some(x.0 as f64),
+            ConstantValue::F64(x) => Some(x.0),
+            ConstantValue::U64(x) => Some(x as f64),
+            ConstantValue::Bool(_) | ConstantValue::Typeless(_) => None,
+            _ => Some(self.to_i64()? as f64),
+        }
+    }
+    fn display(&self) -> DisplayConstantValue<'_> {
+        DisplayConstantValue(self)
+    }
+}
+
+/// Numeric properties of [`ScalarType`]s, and of the scalar components of
+/// vector and matrix [`Type`]s, for code generic over scalar types.
+pub trait ScalarTypeExt {
+    /// Number of bits of integers and floating-point numbers. Returns `None`
+    /// for booleans, whose width is implementation-defined, and void.
+    fn bit_width(&self) -> Option<u32>;
+    /// Whether the type is a signed integer.
+    fn is_signed(&self) -> bool;
+    /// Whether the type is a floating-point number.
+    fn is_float(&self) -> bool;
+    /// A zero of the same width and signedness, e.g., `ConstantValue::S16(0)`
+    /// for 16-bit signed integers, and `false` for booleans. Widths not
+    /// representable by [`ConstantValue`] give zeroed typeless values of the
+    /// width in bytes; void and non-numeric types give an empty typeless
+    /// value.
+    fn matching_zero(&self) -> ConstantValue;
+}
+impl ScalarTypeExt for ScalarType {
+    fn bit_width(&self) -> Option<u32> {
+        match self {
+            ScalarType::Integer { bits, .. } | ScalarType::Float { bits } => Some(*bits),
+            _ => None,
+        }
+    }
+    fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            ScalarType::Integer {
+                is_signed: true,
+                ..
+            }
+        )
+    }
+    fn is_float(&self) -> bool {
+        matches!(self, ScalarType::Float { .. })
+    }
+    fn matching_zero(&self) -> ConstantValue {
+        let nbyte = match self {
+            ScalarType::Boolean => 1,
+            _ => self.bit_width().unwrap_or_default() as usize / 8,
+        };
+        let zero = ConstantValue::Typeless(vec![0u8; nbyte].into());
+        zero.to_typed(&Type::Scalar(self.clone())).unwrap_or(zero)
+    }
+}
+fn scalar_component(ty: &Type) -> Option<&ScalarType> {
+    match ty {
+        Type::Scalar(scalar_ty) => Some(scalar_ty),
+        Type::Vector(vector_ty) => Some(&vector_ty.scalar_ty),
+        Type::Matrix(matrix_ty) => Some(&matrix_ty.vector_ty.scalar_ty),
+        _ => None,
+    }
+}
+impl ScalarTypeExt for Type {
+    fn bit_width(&self) -> Option<u32> {
+        scalar_component(self)?.bit_width()
+    }
+    fn is_signed(&self) -> bool {
+        scalar_component(self).is_some_and(ScalarTypeExt::is_signed)
+    }
+    fn is_float(&self) -> bool {
+        scalar_component(self).is_some_and(ScalarTypeExt::is_float)
+    }
+    fn matching_zero(&self) -> ConstantValue {
+        match scalar_component(self) {
+            Some(scalar_ty) => scalar_ty.matching_zero(),
+            None => ConstantValue::Typeless(Vec::new().into()),
+        }
+    }
+}
+
+/// Value of a constant which can be a composite, e.g., of
+/// `OpConstantComposite` and `OpSpecConstantComposite`. [`ConstantValue`]
+/// only represents scalars, so composites are built from their constituents
+/// here.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositeValue {
+    Scalar(ConstantValue),
+    /// Constituents of a vector, matrix, array or struct constant, in
+    /// declaration order.
+    Composite(Vec<CompositeValue>),
+}
+impl CompositeValue {
+    /// The scalar value, or `None` if the value is a composite.
+    pub fn as_scalar(&self) -> Option<&ConstantValue> {
+        match self {
+            CompositeValue::Scalar(x) => Some(x),
+            CompositeValue::Composite(_) => None,
+        }
+    }
+    /// The constituents, or `None` if the value is a scalar.
+    pub fn constituents(&self) -> Option<&[CompositeValue]> {
+        match self {
+            CompositeValue::Scalar(_) => None,
+            CompositeValue::Composite(x) => Some(x),
+        }
+    }
+}
+
+/// Formatter of a [`ConstantValue`], as is returned by
+/// [`ConstantValueExt::display`].
+pub struct DisplayConstantValue<'a>(pub &'a ConstantValue);
+impl fmt::Display for DisplayConstantValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            ConstantValue::Typeless(bytes) => {
+                write!(f, "0x")?;
+                for byte in bytes.iter().rev() {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+            ConstantValue::Bool(x) => write!(f, "{}", x),
+            ConstantValue::S8(x) => write!(f, "{}", x),
+            ConstantValue::S16(x) => write!(f, "{}", x),
+            ConstantValue::S32(x) => write!(f, "{}", x),
+            ConstantValue::S64(x) => write!(f, "{}", x),
+            ConstantValue::U8(x) => write!(f, "{}", x),
+            ConstantValue::U16(x) => write!(f, "{}", x),
+            ConstantValue::U32(x) => write!(f, "{}", x),
+            ConstantValue::U64(x) => write!(f, "{}", x),
+            ConstantValue::F16(x) => write!(f, "{}", x),
+            ConstantValue::F32(x) => write!(f, "{}", x),
+            ConstantValue::F64(x) => write!(f, "{}", x),
+            x => write!(f, "{:?}", x),
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Type walker that keeps track of the access chain to each route.
+//!
+//! This is a superset of [`ty::Walk`](crate::ty::Walk). In addition to the
+//! symbolic path, each route carries the sequence of member and element indices
+//! from the root type, so it can be fed directly into `OpAccessChain`-style
+//! addressing without parsing the symbol.
+use crate::ty::Type;
+
+pub use crate::ty::walk::Seg;
+
+/// One step in an access chain from a root type to a route.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessChainSegment {
+    /// Index of a struct member.
+    Member(usize),
+    /// Index of an array element.
+    Index(u32),
+}
+impl AccessChainSegment {
+    /// Literal index of the segment as used in `OpAccessChain`.
+    pub fn index(&self) -> u32 {
+        match self {
+            AccessChainSegment::Member(i) => *i as u32,
+            AccessChainSegment::Index(i) => *i,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MemberVariableRouting<'a> {
+    pub sym: Vec<Seg<'a>>,
+    pub access_chain: Vec<AccessChainSegment>,
+    /// Integer indices of `access_chain`, in the order they should be passed
+    /// to `OpAccessChain`.
+    pub indices: Vec<u32>,
+    pub offset: usize,
+    pub ty: &'a Type,
+}
+
+struct WalkFrame<'a> {
+    sym_stem: Vec<Seg<'a>>,
+    access_chain_stem: Vec<AccessChainSegment>,
+    base_offset: usize,
+    ty: &'a Type,
+    i: usize,
+}
+/// Walk through all the members and elements of a type in the same order as
+/// [`ty::Walk`](crate::ty::Walk) does.
+pub struct Walk<'a> {
+    inner: Vec<WalkFrame<'a>>,
+    max_depth: Option<usize>,
+    leaves_only: bool,
+    through_pointers: bool,
+}
+impl<'a> Walk<'a> {
+    pub fn new(ty: &'a Type) -> Walk<'a> {
+        let frame = WalkFrame {
+            sym_stem: Vec::new(),
+            access_chain_stem: Vec::new(),
+            base_offset: 0,
+            ty,
+            i: 0,
+        };
+        Walk {
+            inner: vec![frame],
+            max_depth: None,
+            leaves_only: false,
+            through_pointers: false,
+        }
+    }
+    /// Don't step into structs and arrays `depth` levels below the root
+    /// type, i.e., no route has an access chain longer than `depth`. Such
+    /// structs and arrays are reported as leaves. By default, the walk is
+    /// unbounded.
+    pub fn with_max_depth(mut self, depth: usize) -> Walk<'a> {
+        self.max_depth = Some(depth);
+        self
+    }
+    /// Only report leaf routes, i.e., scalars, vectors, matrices, opaque
+    /// types and structs and arrays not stepped into because of
+    /// [`Walk::with_max_depth`]. The routes to the structs and arrays stepped
+    /// into, including the root type, are skipped.
+    pub fn leaves_only(mut self) -> Walk<'a> {
+        self.leaves_only = true;
+        self
+    }
+    /// Step into the pointees of [`Type::DevicePointer`]s, e.g., GLSL
+    /// `buffer_reference` blocks. Routes in a pointee continue the symbolic
+    /// path and the access chain of the pointer, which has to be loaded
+    /// before indexing into, and their offsets are relative to the pointee.
+    /// A pointer back to a struct being walked through, like the link of a
+    /// linked list, is reported as a leaf and not stepped into, so that the
+    /// walk always terminates.
+    pub fn through_pointers(mut self) -> Walk<'a> {
+        self.through_pointers = true;
+        self
+    }
+    /// Whether `ty` is an ancestor of the frames being walked through. Named
+    /// structs are identified by names since the reflected pointee of a
+    /// self-referential pointer is a copy rather than the struct itself.
+    fn is_back_edge(&self, ty: &Type) -> bool {
+        self.inner.iter().any(|frame| match (frame.ty, ty) {
+            (Type::Struct(a), Type::Struct(b)) if a.name.is_some() => a.name == b.name,
+            _ => frame.ty == ty,
+        })
+    }
+    /// Whether the walk steps into `ty` on top of the current frames.
+    fn is_walkable(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Struct(_) | Type::Array(_) => true,
+            Type::DevicePointer(ptr_ty) => {
+                self.through_pointers && !self.is_back_edge(&ptr_ty.pointee_ty)
+            }
+            _ => false,
+        }
+    }
+}
+impl<'a> Iterator for Walk<'a> {
+    type Item = MemberVariableRouting<'a>;
+    fn next(&mut self) -> Option<MemberVariableRouting<'a>> {
+        /// Child of a type, its offset and the segments to it. Pointees have
+        /// no segment.
+        type Child<'a> = (&'a Type, usize, Option<(Seg<'a>, AccessChainSegment)>);
+        fn get_child<'a>(ty: &'a Type, i: usize) -> Option<Child<'a>> {
+            match ty {
+                Type::Struct(struct_ty) => {
+                    let member = struct_ty.members.get(i)?;
+                    let seg = if let Some(name) = &member.name {
+                        Seg::NamedIndex(i, name)
+                    } else {
+                        Seg::Index(i)
+                    };
+                    let offset = member.offset.unwrap_or_default();
+                    let segs = (seg, AccessChainSegment::Member(i));
+                    Some((&member.ty, offset, Some(segs)))
+                }
+                Type::Array(arr_ty) => {
+                    // Unsized buffer are treated as 0-sized.
+                    if i < arr_ty.nelement.unwrap_or_default() as usize {
+                        let offset = arr_ty.stride.unwrap_or_default() * i;
+                        let seg = Seg::Index(i);
+                        let ac_seg = AccessChainSegment::Index(i as u32);
+                        Some((&arr_ty.element_ty, offset, Some((seg, ac_seg))))
+                    } else {
+                        None
+                    }
+                }
+                Type::DevicePointer(ptr_ty) if i == 0 => Some((&ptr_ty.pointee_ty, 0, None)),
+                _ => None,
+            }
+        }
+        loop {
+            let frame = self.inner.last_mut()?;
+            let is_bounded = self
+                .max_depth
+                .is_some_and(|depth| frame.access_chain_stem.len() >= depth);
+            let child = if is_bounded || (frame.ty.is_device_pointer() && !self.through_pointers) {
+                None
+            } else {
+                get_child(frame.ty, frame.i)
+            };
+            if let Some((child_ty, offset, segs)) = child {
+                frame.i += 1;
+                let mut sym = frame.sym_stem.clone();
+                let mut access_chain = frame.access_chain_stem.clone();
+                let offset = if let Some((seg, ac_seg)) = segs {
+                    sym.push(seg);
+                    access_chain.push(ac_seg);
+                    frame.base_offset + offset
+                } else {
+                    offset
+                };
+                let is_child_bounded = self
+                    .max_depth
+                    .is_some_and(|depth| access_chain.len() >= depth);
+                if self.is_walkable(child_ty) && !is_child_bounded {
+                    // Found composite type, step into it.
+                    self.inner.push(WalkFrame {
+                        sym_stem: sym,
+                        access_chain_stem: access_chain,
+                        base_offset: offset,
+                        ty: child_ty,
+                        i: 0,
+                    });
+                } else {
+                    let indices = access_chain.iter().map(|x| x.index()).collect();
+                    return Some(MemberVariableRouting {
+                        sym,
+                        access_chain,
+                        indices,
+                        offset,
+                        ty: child_ty,
+                    });
+                }
+            } else {
+                // The composite type's elements has been exhausted, or the
+                // type is not a composite at all. Report the type itself.
+                let frame = self.inner.pop()?;
+                let is_leaf = is_bounded || !self.is_walkable(frame.ty);
+                if self.leaves_only && !is_leaf {
+                    continue;
+                }
+                let indices = frame.access_chain_stem.iter().map(|x| x.index()).collect();
+                return Some(MemberVariableRouting {
+                    sym: frame.sym_stem,
+                    access_chain: frame.access_chain_stem,
+                    indices,
+                    offset: frame.base_offset,
+                    ty: frame.ty,
+                });
+            }
+        }
+    }
+}